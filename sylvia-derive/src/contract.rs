@@ -1,6 +1,7 @@
 use communication::api::Api;
 use communication::enum_msg::EnumMessage;
 use communication::executor::Executor;
+use communication::ibc_packet_sender::IbcPacketSender;
 use communication::instantiate_builder::InstantiateBuilder;
 use communication::querier::Querier;
 use communication::reply::Reply;
@@ -8,18 +9,22 @@ use communication::struct_msg::StructMessage;
 use communication::wrapper_msg::GlueMessage;
 use mt::MtHelpers;
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{GenericParam, ItemImpl};
+use quote::{quote, ToTokens};
+use syn::{GenericParam, ItemImpl, Type};
 
+use crate::crate_module;
 use crate::parser::attributes::features::SylviaFeatures;
 use crate::parser::attributes::msg::MsgType;
+use crate::parser::attributes::version::ContractVersionAttr;
+use crate::parser::check_generics::GetPath;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{
     assert_new_method_defined, ContractErrorAttr, Custom, OverrideEntryPoint,
     ParsedSylviaAttributes,
 };
 use crate::types::interfaces::Interfaces;
-use crate::types::msg_variant::MsgVariants;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
+use crate::utils::emit_bracketed_generics;
 
 mod communication;
 mod mt;
@@ -36,9 +41,11 @@ mod mt;
 ///         - ContractExecMsg
 ///         - ContractQueryMsg
 ///         - ContractSudoMsg
+///         - IbcPacketMsg if any `sv::msg(ibc_packet)` method is defined
 ///     - [MultiTest](https://cosmwasm-docs.vercel.app/sylvia/macros/generated-types/multitest) helpers
 ///     - [Querier](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
 ///     - [Executor](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
+///     - IbcPacketSender trait implementation
 ///     - Api trait implementation
 pub struct ContractInput<'a> {
     item: &'a ItemImpl,
@@ -48,6 +55,7 @@ pub struct ContractInput<'a> {
     override_entry_points: Vec<OverrideEntryPoint>,
     interfaces: Interfaces,
     sv_features: SylviaFeatures,
+    version: Option<ContractVersionAttr>,
 }
 
 impl<'a> ContractInput<'a> {
@@ -60,6 +68,7 @@ impl<'a> ContractInput<'a> {
         let custom = parsed_attrs.custom_attr.unwrap_or_default();
         let sv_features = parsed_attrs.sv_features;
         let override_entry_points = parsed_attrs.override_entry_point_attrs;
+        let version = parsed_attrs.version_attr;
         let interfaces = Interfaces::new(item);
 
         Self {
@@ -70,6 +79,7 @@ impl<'a> ContractInput<'a> {
             override_entry_points,
             interfaces,
             sv_features,
+            version,
         }
     }
 
@@ -79,20 +89,40 @@ impl<'a> ContractInput<'a> {
             item,
             generics,
             custom,
+            version,
             ..
         } = self;
+        let version_consts = version.as_ref().map(ContractVersionAttr::emit_consts);
+        let funds_policies = self.emit_funds_policies();
+        let message_events = self.emit_message_events();
+        let interface_ids = self.interfaces.emit_interface_ids(
+            self.sv_features.supported_interfaces,
+            self.sv_features.pausable,
+        );
+        let typescript_api = self.emit_typescript_api();
         let multitest_helpers = self.emit_multitest_helpers();
         let messages = self.emit_messages();
-        let contract_api = Api::new(item, generics, custom).emit();
+        let contract_api = Api::new(item, generics, custom, &self.interfaces).emit();
         let querier = self.emit_querier();
         let executor = self.emit_executor();
+        let ibc_packet_sender = self.emit_ibc_packet_sender();
         let reply = self.emit_reply();
         let instantiate_builder = self.emit_instantiate_builder_trait();
 
         quote! {
+            // Generated code refers to the messages marked with `sv::deprecated`.
+            #[allow(deprecated)]
             pub mod sv {
                 use super::*;
 
+                #version_consts
+
+                #funds_policies
+
+                #message_events
+
+                #interface_ids
+
                 #messages
 
                 #multitest_helpers
@@ -101,10 +131,14 @@ impl<'a> ContractInput<'a> {
 
                 #executor
 
+                #ibc_packet_sender
+
                 #reply
 
                 #contract_api
 
+                #typescript_api
+
                 #instantiate_builder
             }
         }
@@ -116,6 +150,7 @@ impl<'a> ContractInput<'a> {
         let exec_impl = self.emit_enum_msg(MsgType::Exec);
         let query_impl = self.emit_enum_msg(MsgType::Query);
         let sudo_impl = self.emit_enum_msg(MsgType::Sudo);
+        let ibc_packet_impl = self.emit_ibc_packet_msg();
         let exec = self.emit_glue_msg(MsgType::Exec);
         let query = self.emit_glue_msg(MsgType::Query);
         let sudo = self.emit_glue_msg(MsgType::Sudo);
@@ -129,6 +164,8 @@ impl<'a> ContractInput<'a> {
 
             #sudo_impl
 
+            #ibc_packet_impl
+
             #migrate
 
             #exec
@@ -148,6 +185,142 @@ impl<'a> ContractInput<'a> {
         EnumMessage::new(self.item, msg_ty, &self.generics, &self.error, &self.custom).emit()
     }
 
+    fn emit_ibc_packet_msg(&self) -> TokenStream {
+        let variants = MsgVariants::<GenericParam>::new(
+            self.item.as_variants(),
+            MsgType::IbcPacket,
+            &[],
+            &None,
+        );
+
+        match variants.variants().count() {
+            0 => quote! {},
+            _ => self.emit_enum_msg(MsgType::IbcPacket),
+        }
+    }
+
+    /// Emits `funds_policies` function returning the funds policy
+    /// of every `instantiate` and `exec` method which sets one.
+    fn emit_funds_policies(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let instantiate = MsgVariants::<GenericParam>::new(
+            self.item.as_variants(),
+            MsgType::Instantiate,
+            &[],
+            &None,
+        );
+        let exec =
+            MsgVariants::<GenericParam>::new(self.item.as_variants(), MsgType::Exec, &[], &None);
+
+        let (names, policies): (Vec<_>, Vec<_>) = instantiate
+            .variants()
+            .chain(exec.variants())
+            .filter_map(|variant| {
+                variant
+                    .funds()
+                    .map(|funds| (variant.function_name().to_string(), funds.emit()))
+            })
+            .unzip();
+        let policies_cnt = policies.len();
+
+        quote! {
+            pub const fn funds_policies() -> [(&'static str, #sylvia ::types::FundsPolicy); #policies_cnt] {
+                [#((#names, #policies),)*]
+            }
+        }
+    }
+
+    /// Emits `message_events` listing the events declared with `sv::events` by the messages.
+    fn emit_message_events(&self) -> TokenStream {
+        let entries: Vec<_> = [
+            MsgType::Instantiate,
+            MsgType::Exec,
+            MsgType::Migrate,
+            MsgType::Sudo,
+        ]
+        .into_iter()
+        .flat_map(|msg_ty| {
+            let variants =
+                MsgVariants::<GenericParam>::new(self.item.as_variants(), msg_ty, &[], &None);
+            variants
+                .variants()
+                .filter_map(MsgVariant::emit_events_entry)
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+        quote! {
+            /// Returns names of the messages paired with the types of the events they may emit.
+            pub fn message_events() -> Vec<(&'static str, Vec<&'static str>)> {
+                vec![#(#entries,)*]
+            }
+        }
+    }
+
+    /// Emits `TypeScriptApi` implementation describing the clients of the contract
+    /// and of the interfaces it implements.
+    fn emit_typescript_api(&self) -> TokenStream {
+        if !cfg!(feature = "typescript") {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let Self {
+            item,
+            generics,
+            interfaces,
+            ..
+        } = self;
+
+        let contract = &item.self_ty;
+        let contract_name = match contract.as_ref() {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+                .unwrap_or_default(),
+            contract => contract.to_token_stream().to_string(),
+        };
+        let where_predicates = item
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter());
+        let schema_predicates = generics
+            .iter()
+            .filter_map(|generic| generic.get_path())
+            .map(|generic| quote! { #generic: #sylvia ::schemars::JsonSchema });
+        let bracketed_generics = emit_bracketed_generics(generics);
+        let interfaces_clients = interfaces.emit_ts_clients();
+
+        quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            impl #bracketed_generics #sylvia ::typescript::TypeScriptApi for #contract
+            where
+                #(#where_predicates,)*
+                #(#schema_predicates,)*
+            {
+                const NAME: &'static str = #contract_name;
+
+                fn ts_instantiate(ts_gen: &mut #sylvia ::typescript::TsGenerator) -> String {
+                    ts_gen.ts_type::< <Self as #sylvia ::types::ContractApi>::Instantiate >()
+                }
+
+                fn ts_clients(ts_gen: &mut #sylvia ::typescript::TsGenerator) -> Vec<#sylvia ::typescript::TsClient> {
+                    vec![
+                        #sylvia ::typescript::TsClient::new(
+                            #contract_name,
+                            < <Self as #sylvia ::types::ContractApi>::Query >::ts_methods(ts_gen),
+                            < <Self as #sylvia ::types::ContractApi>::Exec >::ts_methods(ts_gen),
+                        ),
+                        #(#interfaces_clients,)*
+                    ]
+                }
+            }
+        }
+    }
+
     fn emit_glue_msg(&self, msg_ty: MsgType) -> TokenStream {
         GlueMessage::new(
             self.item,
@@ -155,6 +328,7 @@ impl<'a> ContractInput<'a> {
             &self.error,
             &self.custom,
             &self.interfaces,
+            &self.sv_features,
         )
         .emit()
     }
@@ -181,6 +355,14 @@ impl<'a> ContractInput<'a> {
 
         Executor::new(item.generics.clone(), *item.self_ty.clone(), variants).emit()
     }
+
+    fn emit_ibc_packet_sender(&self) -> TokenStream {
+        let item = self.item;
+        let variants = MsgVariants::new(item.as_variants(), MsgType::IbcPacket, &[], &None);
+
+        IbcPacketSender::new(item.generics.clone(), *item.self_ty.clone(), variants).emit()
+    }
+
     fn emit_querier(&self) -> TokenStream {
         let item = self.item;
         let variants = MsgVariants::new(item.as_variants(), MsgType::Query, &[], &None);
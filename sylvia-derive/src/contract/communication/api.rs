@@ -1,6 +1,7 @@
 use crate::crate_module;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{Custom, MsgType};
+use crate::types::interfaces::Interfaces;
 use crate::types::msg_variant::MsgVariants;
 use crate::utils::emit_bracketed_generics;
 use proc_macro2::TokenStream;
@@ -14,12 +15,19 @@ pub struct Api<'a> {
     instantiate_variants: MsgVariants<'a, GenericParam>,
     migrate_variants: MsgVariants<'a, GenericParam>,
     sudo_variants: MsgVariants<'a, GenericParam>,
+    ibc_packet_variants: MsgVariants<'a, GenericParam>,
     generics: &'a [&'a GenericParam],
     custom: &'a Custom,
+    interfaces: &'a Interfaces,
 }
 
 impl<'a> Api<'a> {
-    pub fn new(source: &'a ItemImpl, generics: &'a [&'a GenericParam], custom: &'a Custom) -> Self {
+    pub fn new(
+        source: &'a ItemImpl,
+        generics: &'a [&'a GenericParam],
+        custom: &'a Custom,
+        interfaces: &'a Interfaces,
+    ) -> Self {
         let exec_variants = MsgVariants::new(
             source.as_variants(),
             MsgType::Exec,
@@ -55,6 +63,13 @@ impl<'a> Api<'a> {
             &source.generics.where_clause,
         );
 
+        let ibc_packet_variants = MsgVariants::new(
+            source.as_variants(),
+            MsgType::IbcPacket,
+            generics,
+            &source.generics.where_clause,
+        );
+
         Self {
             source,
             exec_variants,
@@ -62,8 +77,10 @@ impl<'a> Api<'a> {
             instantiate_variants,
             migrate_variants,
             sudo_variants,
+            ibc_packet_variants,
             generics,
             custom,
+            interfaces,
         }
     }
 
@@ -76,8 +93,10 @@ impl<'a> Api<'a> {
             instantiate_variants,
             migrate_variants,
             sudo_variants,
+            ibc_packet_variants,
             generics,
             custom,
+            interfaces,
         } = self;
 
         let where_clause = &source.generics.where_clause;
@@ -87,6 +106,7 @@ impl<'a> Api<'a> {
         let instantiate_generics = &instantiate_variants.used_generics();
         let migrate_generics = &migrate_variants.used_generics();
         let sudo_generics = &sudo_variants.used_generics();
+        let ibc_packet_generics = &ibc_packet_variants.used_generics();
 
         let bracket_generics = emit_bracketed_generics(generics);
         let exec_bracketed_generics = emit_bracketed_generics(exec_generics);
@@ -94,12 +114,23 @@ impl<'a> Api<'a> {
         let sudo_bracketed_generics = emit_bracketed_generics(sudo_generics);
         let instantiate_bracketed_generics = emit_bracketed_generics(instantiate_generics);
         let migrate_bracketed_generics = emit_bracketed_generics(migrate_generics);
+        let ibc_packet_bracketed_generics = emit_bracketed_generics(ibc_packet_generics);
 
-        let migrate_type = if migrate_variants.variants().count() != 0 {
+        let has_migrate = migrate_variants.variants().count() != 0;
+        let migrate_type = if has_migrate {
             quote! { type Migrate = MigrateMsg #migrate_bracketed_generics; }
         } else {
             quote! { type Migrate = #sylvia ::cw_std::Empty; }
         };
+        // The `sudo` message is composed of the contract and the interfaces messages.
+        let has_sudo = sudo_variants.variants().count() != 0;
+        let interfaces_modules = interfaces.variants_modules();
+        let has_sudo = quote! { #has_sudo #(|| #interfaces_modules ::sv::HAS_SUDO)* };
+        let ibc_packet_type = if ibc_packet_variants.variants().count() != 0 {
+            quote! { type IbcPacket = IbcPacketMsg #ibc_packet_bracketed_generics; }
+        } else {
+            quote! { type IbcPacket = #sylvia ::cw_std::Empty; }
+        };
         let custom_msg = custom.msg_or_default();
         let custom_query = custom.query_or_default();
 
@@ -117,6 +148,12 @@ impl<'a> Api<'a> {
                 type Querier<'querier> = #sylvia ::types::BoundQuerier<'querier, #custom_query, Self >;
                 type CustomMsg = #custom_msg;
                 type CustomQuery = #custom_query;
+                const HAS_MIGRATE: bool = #has_migrate;
+                const HAS_SUDO: bool = #has_sudo;
+            }
+
+            impl #bracket_generics #sylvia ::types::IbcPacketApi for #contract_name #where_clause {
+                #ibc_packet_type
             }
         }
     }
@@ -18,6 +18,7 @@ pub struct EnumMessage<'a> {
     custom: &'a Custom,
     where_clause: &'a Option<WhereClause>,
     msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+    pausable: bool,
 }
 
 impl<'a> EnumMessage<'a> {
@@ -30,7 +31,9 @@ impl<'a> EnumMessage<'a> {
     ) -> Self {
         let where_clause = &source.generics.where_clause;
         let variants = MsgVariants::new(source.as_variants(), msg_ty, generics, where_clause);
-        let msg_attrs_to_forward = ParsedSylviaAttributes::new(source.attrs.iter())
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let pausable = parsed_attrs.sv_features.pausable;
+        let msg_attrs_to_forward = parsed_attrs
             .msg_attrs_forward
             .into_iter()
             .filter(|attr| attr.msg_type == msg_ty)
@@ -44,6 +47,7 @@ impl<'a> EnumMessage<'a> {
             custom,
             where_clause,
             msg_attrs_to_forward,
+            pausable,
         }
     }
 
@@ -58,7 +62,7 @@ impl<'a> EnumMessage<'a> {
             custom,
             where_clause,
             msg_attrs_to_forward,
-            ..
+            pausable,
         } = self;
 
         let enum_name = msg_ty.emit_msg_name();
@@ -72,6 +76,10 @@ impl<'a> EnumMessage<'a> {
         variant_names.sort();
         let variants_cnt = variant_names.len();
         let variants_constructors = variants.emit_constructors();
+        let allowed_when_paused = pausable.then(|| variants.emit_allowed_when_paused(&enum_name));
+        let ts_methods = variants.emit_ts_methods(&enum_name);
+        let arbitrary_impl = variants.emit_arbitrary_impl(&enum_name);
+        let exec_funds = variants.emit_exec_funds(&enum_name);
         let variants = variants.emit();
 
         let ctx_type = msg_ty.emit_ctx_type(&custom.query_or_default());
@@ -112,6 +120,14 @@ impl<'a> EnumMessage<'a> {
                 #(#variants_constructors)*
             }
 
+            #allowed_when_paused
+
+            #ts_methods
+
+            #arbitrary_impl
+
+            #exec_funds
+
             pub const fn #messages_fn_name () -> [&'static str; #variants_cnt] {
                 [#(#variant_names,)*]
             }
@@ -0,0 +1,120 @@
+use convert_case::Case;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{GenericParam, Generics, Type};
+
+use crate::crate_module;
+use crate::parser::attributes::msg::MsgType;
+use crate::types::associated_types::EmitAssociated;
+use crate::types::msg_field::MsgField;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
+use crate::utils::SvCasing;
+
+/// Emits IBC packet sender helper.
+///
+/// Generates trait containing methods for each `ibc_packet` message variant and implements it on
+/// `sylvia::types::IbcPacketBuilder<Contract>`.
+pub struct IbcPacketSender<'a> {
+    generics: Generics,
+    self_ty: Type,
+    variants: MsgVariants<'a, GenericParam>,
+}
+
+impl<'a> IbcPacketSender<'a> {
+    pub fn new(generics: Generics, self_ty: Type, variants: MsgVariants<'a, GenericParam>) -> Self {
+        Self {
+            generics,
+            self_ty,
+            variants,
+        }
+    }
+
+    pub fn emit(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            generics,
+            self_ty,
+            variants,
+        } = self;
+
+        if variants.variants().count() == 0 {
+            return quote! {};
+        }
+
+        let where_clause = &generics.where_clause;
+        let generics: Vec<_> = generics.params.iter().collect();
+        let contract = &self_ty;
+
+        let accessor = MsgType::IbcPacket.as_accessor_name();
+        let packet_api_path = quote! { < #contract as #sylvia ::types::IbcPacketApi>:: #accessor };
+
+        let sender_methods_impl = variants
+            .variants()
+            .map(|variant| variant.emit_ibc_packet_sender_impl(&packet_api_path));
+
+        let sender_methods_declaration = variants
+            .variants()
+            .map(|variant| variant.emit_ibc_packet_sender_method_declaration());
+
+        let types_declaration = where_clause
+            .as_ref()
+            .map(EmitAssociated::emit_declaration)
+            .unwrap_or(vec![]);
+
+        let types_implementation = where_clause
+            .as_ref()
+            .map(EmitAssociated::emit_implementation)
+            .unwrap_or(vec![]);
+
+        quote! {
+            pub trait IbcPacketSender<#(#generics,)*> #where_clause {
+                #(#types_declaration)*
+                #(#sender_methods_declaration)*
+            }
+
+            impl <#(#generics,)*> IbcPacketSender<#(#generics,)*>
+                for #sylvia ::types::IbcPacketBuilder< #contract > #where_clause {
+                #(#types_implementation)*
+                #(#sender_methods_impl)*
+            }
+        }
+    }
+}
+
+trait EmitIbcPacketSenderMethod {
+    fn emit_ibc_packet_sender_impl(&self, api_path: &TokenStream) -> TokenStream;
+    fn emit_ibc_packet_sender_method_declaration(&self) -> TokenStream;
+}
+
+impl EmitIbcPacketSenderMethod for MsgVariant<'_> {
+    fn emit_ibc_packet_sender_impl(&self, api_path: &TokenStream) -> TokenStream {
+        let name = self.name();
+        let fields = self.fields();
+        let sylvia = crate_module();
+
+        let parameters = fields.iter().map(MsgField::emit_method_field_folded);
+        let fields_names = fields.iter().map(MsgField::name);
+        let variant_name = name.to_case(Case::Snake);
+
+        quote! {
+            fn #variant_name(self, #(#parameters),*) -> Result< #sylvia ::cw_std::IbcMsg, #sylvia ::cw_std::StdError> {
+                self.build(& #api_path :: #variant_name (#(#fields_names),*) )
+            }
+        }
+    }
+
+    fn emit_ibc_packet_sender_method_declaration(&self) -> TokenStream {
+        let name = self.name();
+        let sylvia = crate_module();
+
+        let parameters = self
+            .fields()
+            .iter()
+            .map(|field| field.emit_method_field_folded());
+        let variant_name = name.to_case(Case::Snake);
+
+        quote! {
+            fn #variant_name(self, #(#parameters),*) -> Result< #sylvia ::cw_std::IbcMsg, #sylvia ::cw_std::StdError>;
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod api;
 pub mod enum_msg;
 pub mod executor;
+pub mod ibc_packet_sender;
 pub mod instantiate_builder;
 pub mod querier;
 pub mod reply;
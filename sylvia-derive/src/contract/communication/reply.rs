@@ -2,6 +2,7 @@
 //!
 //! Based on methods marked with the `#[sv::msg(reply)]` attribute, this module generates:
 //!     - reply ids for every unique handler,
+//!     - const assertions guarding the uniqueness of the reply ids,
 //!     - dispatch method that matches over every generated `ReplyId` and dispatches depending on the `ReplyOn`,
 //!     - `SubMsgMethods` trait with method for every reply id.
 
@@ -9,14 +10,16 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
 use quote::quote;
+use syn::visit::Visit;
 use syn::{parse_quote, GenericParam, Ident, ItemImpl, Type};
 
 use crate::crate_module;
-use crate::parser::attributes::msg::ReplyOn;
+use crate::parser::attributes::msg::{PinnedReplyId, ReplyOn};
+use crate::parser::check_generics::CheckGenerics;
 use crate::parser::{MsgType, ParsedSylviaAttributes};
-use crate::types::msg_field::MsgField;
+use crate::types::msg_field::{option_inner, MsgField};
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::emit_turbofish;
+use crate::utils::{emit_turbofish, type_to_string};
 
 const NUMBER_OF_ALLOWED_DATA_FIELDS: usize = 1;
 const NUMBER_OF_ALLOWED_RAW_PAYLOAD_FIELDS: usize = 1;
@@ -49,6 +52,29 @@ fn assert_no_redundant_params(payload: &[&MsgField]) {
     }
 }
 
+/// Make sure that no two handlers share the same reply id value.
+///
+/// Hashed reply ids are computed by the `sylvia::utils::reply_id_hash` in the generated code,
+/// so their collisions are reported by the generated compile time assertion.
+fn assert_no_reply_ids_collision(reply_data: &[ReplyData]) {
+    reply_data.iter().enumerate().for_each(|(index, data)| {
+        let Some(id) = data.id_value(index) else {
+            return;
+        };
+        let previous = reply_data[..index]
+            .iter()
+            .enumerate()
+            .find(|(previous_index, previous)| previous.id_value(*previous_index) == Some(id));
+
+        if let Some((_, previous)) = previous {
+            emit_error!(data.handler_id.span(), "Reply id collision.";
+                note = previous.handler_id.span() => format!("Handler `{}` uses the same reply id `{}`.", previous.handler_id, id);
+                note = "Pin a different value with the `id` parameter of the `#[sv::msg(reply, ..)]` attribute."
+            );
+        }
+    });
+}
+
 pub struct Reply<'a> {
     source: &'a ItemImpl,
     generics: &'a [&'a GenericParam],
@@ -63,6 +89,7 @@ impl<'a> Reply<'a> {
         variants: &'a MsgVariants<'a, GenericParam>,
     ) -> Self {
         let reply_data = variants.as_reply_data();
+        assert_no_reply_ids_collision(&reply_data);
         let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
         let error = parsed_attrs.error_attrs.unwrap_or_default().error;
 
@@ -76,12 +103,15 @@ impl<'a> Reply<'a> {
 
     pub fn emit(&self) -> TokenStream {
         let unique_handlers: Vec<_> = self.emit_reply_ids().collect();
+        let reply_ids_assertions = self.emit_reply_ids_assertions();
         let dispatch = self.emit_dispatch();
         let sub_msg_trait = self.emit_sub_msg_trait();
 
         quote! {
             #(#unique_handlers)*
 
+            #reply_ids_assertions
+
             #dispatch
 
             #sub_msg_trait
@@ -137,9 +167,12 @@ impl<'a> Reply<'a> {
     /// [`reply_handler`](crate::parser::attributes::msg::MsgAttr::reply_handlers) and
     /// [`function_name`](crate::types::msg_variant::MsgVariant::function_name) not anotated with
     /// the `#[sv::msg(..)]` attribute with the `handlers` parameter.
+    ///
+    /// Value of the `ReplyId` is either pinned with the `id` parameter or equal to the position
+    /// of the handler.
     fn emit_reply_ids(&'a self) -> impl Iterator<Item = TokenStream> + 'a {
-        self.reply_data.iter().enumerate().map(|(id, data)| {
-            let id = id as u64;
+        self.reply_data.iter().enumerate().map(|(index, data)| {
+            let id = data.emit_id_value(index);
             let reply_id = &data.reply_id;
             quote! {
                 pub const #reply_id : u64 = #id ;
@@ -147,18 +180,53 @@ impl<'a> Reply<'a> {
         })
     }
 
+    /// Generates `reply_ids` method returning every `ReplyId` with the name of its handler
+    /// and compile time assertion that the `ReplyId`s are unique.
+    fn emit_reply_ids_assertions(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let reply_data = &self.reply_data;
+
+        let reply_ids: Vec<_> = reply_data.iter().map(|data| &data.reply_id).collect();
+        let handler_ids = reply_data.iter().map(|data| data.handler_id.to_string());
+        let reply_ids_cnt = reply_data.len();
+
+        quote! {
+            pub const fn reply_ids() -> [(&'static str, u64); #reply_ids_cnt] {
+                [#((#handler_ids, #reply_ids),)*]
+            }
+
+            const _: () = {
+                #sylvia ::utils::assert_unique_reply_ids(&[#(#reply_ids),*]);
+            };
+        }
+    }
+
     /// Generates `SubMsgMethods` trait with method for every reply id.
+    ///
+    /// The trait is also implemented on the `sylvia::types::ExecutorBuilder`, which accepts only
+    /// the handlers decoding the data declared with `#[sv::msg(exec, data = ...)]`.
     fn emit_sub_msg_trait(&self) -> TokenStream {
-        let Self { reply_data, .. } = self;
+        let Self {
+            reply_data,
+            generics,
+            ..
+        } = self;
 
         let sylvia = crate_module();
 
-        let methods_declaration = reply_data.iter().map(ReplyData::emit_submsg_trait_method);
-        let submsg_reply_setters = reply_data.iter().map(ReplyData::emit_submsg_setter);
+        let methods_declaration = reply_data
+            .iter()
+            .map(|data| data.emit_submsg_trait_method(generics));
+        let submsg_reply_setters = reply_data
+            .iter()
+            .map(|data| data.emit_submsg_setter(generics));
         let submsg_converters: Vec<_> = reply_data
             .iter()
-            .map(ReplyData::emit_submsg_converter)
+            .map(|data| data.emit_submsg_converter(generics, quote! { self.into() }))
             .collect();
+        let executor_converters = reply_data
+            .iter()
+            .map(|data| data.emit_submsg_converter(generics, quote! { self.build().into() }));
 
         quote! {
             pub trait SubMsgMethods<CustomMsgT> {
@@ -176,6 +244,10 @@ impl<'a> Reply<'a> {
             impl<CustomMsgT> SubMsgMethods<CustomMsgT> for #sylvia ::cw_std::CosmosMsg<CustomMsgT> {
                 #(#submsg_converters)*
             }
+
+            impl<CustomMsgT, Data> SubMsgMethods<CustomMsgT> for #sylvia ::types::ExecutorBuilder< #sylvia ::types::ReadyExecutorBuilderState<Data>> {
+                #(#executor_converters)*
+            }
         }
     }
 }
@@ -183,11 +255,11 @@ impl<'a> Reply<'a> {
 trait ReplyVariants<'a> {
     /// Maps to [Vec<ReplyData>].
     /// Validation for duplicates and overlaps should be done in this method.
-    fn as_reply_data(&self) -> Vec<ReplyData>;
+    fn as_reply_data(&self) -> Vec<ReplyData<'_>>;
 }
 
 impl<'a> ReplyVariants<'a> for MsgVariants<'a, GenericParam> {
-    fn as_reply_data(&self) -> Vec<ReplyData> {
+    fn as_reply_data(&self) -> Vec<ReplyData<'_>> {
         let mut reply_data: Vec<ReplyData> = vec![];
 
         self.variants()
@@ -236,6 +308,8 @@ struct ReplyData<'a> {
     pub data: Option<&'a MsgField<'a>>,
     /// Payload parameters associated with the handlers.
     pub payload: Vec<&'a MsgField<'a>>,
+    /// Value of the reply id pinned with the `id` parameter.
+    pub pinned_id: Option<PinnedReplyId>,
 }
 
 impl<'a> ReplyData<'a> {
@@ -260,6 +334,7 @@ impl<'a> ReplyData<'a> {
         assert_no_redundant_params(&payload);
         let method_name = variant.function_name();
         let reply_on = variant.msg_attr().reply_on();
+        let pinned_id = variant.msg_attr().reply_id();
 
         Self {
             reply_id,
@@ -267,6 +342,32 @@ impl<'a> ReplyData<'a> {
             handlers: vec![(method_name, reply_on)],
             data,
             payload,
+            pinned_id,
+        }
+    }
+
+    /// Value of the reply id. Unless pinned, it's equal to the position of the handler.
+    ///
+    /// Returns `None` for the hashed reply id, which is computed only in the generated code.
+    pub fn id_value(&self, index: usize) -> Option<u64> {
+        match self.pinned_id {
+            Some(PinnedReplyId::Value(value)) => Some(value),
+            Some(PinnedReplyId::Hash) => None,
+            None => Some(index as u64),
+        }
+    }
+
+    /// Emits the value of the reply id, hashing the handler name with the
+    /// `sylvia::utils::reply_id_hash` if requested.
+    fn emit_id_value(&self, index: usize) -> TokenStream {
+        let sylvia = crate_module();
+
+        match self.id_value(index) {
+            Some(value) => quote! { #value },
+            None => {
+                let handler_id = self.handler_id.to_string();
+                quote! { #sylvia ::utils::reply_id_hash( #handler_id ) }
+            }
         }
     }
 
@@ -299,6 +400,16 @@ impl<'a> ReplyData<'a> {
             }
         });
 
+        match (self.pinned_id, new_reply_data.pinned_id) {
+            (Some(current_id), Some(new_id)) if current_id != new_id => {
+                emit_error!(new_handler.function_name().span(), "Mismatched reply id.";
+                    note = current_method_name.span() => format!("Previous definition of the `{}` handler pins a different id.", self.handler_id)
+                );
+            }
+            (None, new_id) => self.pinned_id = new_id,
+            _ => (),
+        }
+
         let new_function_name = new_handler.function_name();
         let new_reply_on = new_handler.msg_attr().reply_on();
         self.handlers.push((new_function_name, new_reply_on));
@@ -347,8 +458,25 @@ impl<'a> ReplyData<'a> {
         }
     }
 
+    /// Emits bound accepting only the messages, which set the data decoded by the handler.
+    ///
+    /// Skipped if the type of the data depends on the generics of the contract, as they are
+    /// not in the scope of the `SubMsgMethods` trait.
+    fn emit_exec_data_bound(&self, generics: &[&GenericParam]) -> Option<TokenStream> {
+        let sylvia = crate_module();
+        let data_type = self.data?.exec_data_type()?;
+
+        let mut generics_checker = CheckGenerics::new(generics);
+        generics_checker.visit_type(&data_type);
+        if !generics_checker.used().is_empty() {
+            return None;
+        }
+
+        Some(quote! { where Self: #sylvia ::types::ExecReplyData< #data_type > })
+    }
+
     /// Emits method setting reply related fields on the `cosmwasm_std::SubMsg`.
-    fn emit_submsg_setter(&self) -> TokenStream {
+    fn emit_submsg_setter(&self, generics: &[&GenericParam]) -> TokenStream {
         let sylvia = crate_module();
         let Self {
             reply_id,
@@ -361,9 +489,10 @@ impl<'a> ReplyData<'a> {
         let reply_on = self.emit_cw_reply_on();
         let payload_parameters = payload.iter().map(|field| field.emit_method_field());
         let payload_serialization = payload.emit_payload_serialization();
+        let data_bound = self.emit_exec_data_bound(generics);
 
         quote! {
-            fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>> {
+            fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>> #data_bound {
                 #payload_serialization
 
                 Ok( #sylvia ::cw_std::SubMsg {
@@ -376,8 +505,8 @@ impl<'a> ReplyData<'a> {
         }
     }
 
-    /// Emits method for converting `WasmMsg` or `CosmosMsg` to `SubMsg`.
-    fn emit_submsg_converter(&self) -> TokenStream {
+    /// Emits method for converting `WasmMsg`, `CosmosMsg` or `ExecutorBuilder` to `SubMsg`.
+    fn emit_submsg_converter(&self, generics: &[&GenericParam], msg: TokenStream) -> TokenStream {
         let sylvia = crate_module();
         let Self {
             reply_id,
@@ -390,15 +519,16 @@ impl<'a> ReplyData<'a> {
         let reply_on = self.emit_cw_reply_on();
         let payload_parameters = payload.iter().map(|field| field.emit_method_field());
         let payload_serialization = payload.emit_payload_serialization();
+        let data_bound = self.emit_exec_data_bound(generics);
 
         quote! {
-            fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>> {
+            fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>> #data_bound {
                 #payload_serialization
 
                 Ok( #sylvia ::cw_std::SubMsg {
                     reply_on: #reply_on ,
                     id: #reply_id ,
-                    msg: self.into(),
+                    msg: #msg,
                     payload,
                     gas_limit: None,
                 })
@@ -406,13 +536,14 @@ impl<'a> ReplyData<'a> {
         }
     }
 
-    fn emit_submsg_trait_method(&self) -> TokenStream {
+    fn emit_submsg_trait_method(&self, generics: &[&GenericParam]) -> TokenStream {
         let sylvia = crate_module();
         let method_name = &self.handler_id;
         let payload_parameters = self.payload.iter().map(|field| field.emit_method_field());
+        let data_bound = self.emit_exec_data_bound(generics);
 
         quote! {
-            fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>>;
+            fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>> #data_bound;
         }
     }
 
@@ -579,9 +710,26 @@ impl<'a> ReplyVariant<'a> for MsgVariant<'a> {
 
 pub trait DataField {
     fn emit_data_deserialization(&self) -> TokenStream;
+
+    /// Type of the data set by the executed contract, if the field decodes the reply to
+    /// `WasmMsg::Execute`.
+    fn exec_data_type(&self) -> Option<Type>;
 }
 
 impl DataField for MsgField<'_> {
+    fn exec_data_type(&self) -> Option<Type> {
+        let data = ParsedSylviaAttributes::new(self.attrs().iter()).data?;
+        if data.raw || data.instantiate {
+            return None;
+        }
+
+        match data.inner_type {
+            Some(inner_type) => Some(inner_type),
+            None if data.opt => option_inner(self.ty()).cloned(),
+            None => Some(self.ty().clone()),
+        }
+    }
+
     fn emit_data_deserialization(&self) -> TokenStream {
         let sylvia = crate_module();
         let data = ParsedSylviaAttributes::new(self.attrs().iter()).data;
@@ -599,6 +747,10 @@ impl DataField for MsgField<'_> {
                 #transaction_id,
                 err}
         };
+        let inner_type = data
+            .as_ref()
+            .and_then(|data| data.inner_type.as_ref())
+            .map(|inner_type| quote! { :: < #inner_type > });
         let execute_data_deserialization = quote! {
             let deserialized_data =
                 #sylvia ::cw_utils::parse_execute_response_data(data.as_slice())
@@ -606,7 +758,7 @@ impl DataField for MsgField<'_> {
                         format!("Failed deserializing protobuf data: {}", err)
                     ))?;
             let deserialized_data = match deserialized_data.data {
-                Some(data) => #sylvia ::cw_std::from_json(&data).map_err(|err| #sylvia ::cw_std::StdError::generic_err( #invalid_reply_data_err ))?,
+                Some(data) => #sylvia ::cw_std::from_json #inner_type (&data).map_err(|err| #sylvia ::cw_std::StdError::generic_err( #invalid_reply_data_err ))?,
                 None => return Err(Into::into( #sylvia ::cw_std::StdError::generic_err( #missing_data_err ))),
             };
         };
@@ -618,6 +770,38 @@ impl DataField for MsgField<'_> {
                         format!("Failed deserializing protobuf data: {}", err)
                     ))?;
         };
+        let instantiate_data_deserialization = match &inner_type {
+            Some(inner_type) => quote! {
+                #instantiate_data_deserialization
+
+                let deserialized_data = #sylvia ::types::InstantiateReplyData {
+                    contract_address: #sylvia ::cw_std::Addr::unchecked(deserialized_data.contract_address),
+                    data: match deserialized_data.data {
+                        Some(data) => #sylvia ::cw_std::from_json #inner_type (&data).map_err(|err| #sylvia ::cw_std::StdError::generic_err( #invalid_reply_data_err ))?,
+                        None => return Err(Into::into( #sylvia ::cw_std::StdError::generic_err( #missing_data_err ))),
+                    },
+                };
+            },
+            None => instantiate_data_deserialization,
+        };
+
+        // Since CosmWasm 2.0 the data of the submessage might be present only in the `msg_responses`.
+        let data_from_msg_responses = |type_urls: &[&str]| {
+            quote! {
+                let data = data.or_else(|| {
+                    msg_responses
+                        .iter()
+                        .find(|msg_response| [#(#type_urls),*].contains(&msg_response.type_url.as_str()))
+                        .map(|msg_response| msg_response.value.clone())
+                });
+            }
+        };
+        let instantiate_data = data_from_msg_responses(&[
+            "/cosmwasm.wasm.v1.MsgInstantiateContractResponse",
+            "/cosmwasm.wasm.v1.MsgInstantiateContract2Response",
+        ]);
+        let execute_data =
+            data_from_msg_responses(&["/cosmwasm.wasm.v1.MsgExecuteContractResponse"]);
 
         match data {
             Some(data) if data.raw && data.opt => quote! {},
@@ -628,6 +812,8 @@ impl DataField for MsgField<'_> {
                 };
             },
             Some(data) if data.instantiate && data.opt => quote! {
+                #instantiate_data
+
                 let data = match data {
                     Some(data) => {
                         #instantiate_data_deserialization
@@ -638,6 +824,8 @@ impl DataField for MsgField<'_> {
                 };
             },
             Some(data) if data.instantiate => quote! {
+                #instantiate_data
+
                 let data = match data {
                     Some(data) => {
                         #instantiate_data_deserialization
@@ -648,6 +836,8 @@ impl DataField for MsgField<'_> {
                 };
             },
             Some(data) if data.opt => quote! {
+                #execute_data
+
                 let data = match data {
                     Some(data) => {
                         #execute_data_deserialization
@@ -658,6 +848,8 @@ impl DataField for MsgField<'_> {
                 };
             },
             _ => quote! {
+                #execute_data
+
                 let data = match data {
                     Some(data) => {
                         #execute_data_deserialization
@@ -689,8 +881,36 @@ impl PayloadFields for Vec<&MsgField<'_>> {
         }
 
         let deserialized_payload_names = self.iter().map(|field| field.name());
+        let deserialized_payload_type = match self.as_slice() {
+            [field] => {
+                let ty = field.ty();
+                quote! { #ty }
+            }
+            fields => {
+                let types = fields.iter().map(|field| field.ty());
+                quote! { ( #(#types),* ) }
+            }
+        };
+        let payload_type_name = match self.as_slice() {
+            [field] => type_to_string(field.ty()),
+            fields => {
+                let types: Vec<_> = fields
+                    .iter()
+                    .map(|field| type_to_string(field.ty()))
+                    .collect();
+                format!("({})", types.join(", "))
+            }
+        };
         quote! {
-            let ( #(#deserialized_payload_names),* ) = #sylvia ::cw_std::from_json(&payload)?;
+            let ( #(#deserialized_payload_names),* ) = #sylvia ::cw_std::from_json::< #deserialized_payload_type >(&payload)
+                .map_err(|err| {
+                    #sylvia ::cw_std::StdError::generic_err(format!(
+                        "Invalid reply payload for reply id: {}. Expected payload of type `{}`.\nSerde error while deserializing {}",
+                        id,
+                        #payload_type_name,
+                        err
+                    ))
+                })?;
         }
     }
 
@@ -1,4 +1,5 @@
-use crate::parser::attributes::MsgAttrForwarding;
+use crate::crate_module;
+use crate::parser::attributes::{ContractVersionAttr, FundsPolicyAttr, MsgAttrForwarding};
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{ContractErrorAttr, Custom, MsgType, ParsedSylviaAttributes};
 use crate::types::msg_field::MsgField;
@@ -19,6 +20,7 @@ pub struct StructMessage<'a> {
     error: &'a ContractErrorAttr,
     custom: &'a Custom,
     msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+    version: Option<ContractVersionAttr>,
 }
 
 impl<'a> StructMessage<'a> {
@@ -55,7 +57,9 @@ impl<'a> StructMessage<'a> {
             return None;
         }
 
-        let msg_attrs_to_forward = ParsedSylviaAttributes::new(source.attrs.iter())
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let version = parsed_attrs.version_attr;
+        let msg_attrs_to_forward = parsed_attrs
             .msg_attrs_forward
             .into_iter()
             .filter(|attr| attr.msg_type == msg_ty)
@@ -69,6 +73,7 @@ impl<'a> StructMessage<'a> {
             error,
             custom,
             msg_attrs_to_forward,
+            version,
         })
     }
 
@@ -81,6 +86,7 @@ impl<'a> StructMessage<'a> {
             error,
             custom,
             msg_attrs_to_forward,
+            version,
         } = self;
 
         let Some(variant) = variants.get_only_variant() else {
@@ -109,15 +115,24 @@ impl<'a> StructMessage<'a> {
             .msg_type()
             .emit_ctx_type(&custom.query_or_default());
         let fields_names: Vec<_> = variant.fields().iter().map(MsgField::name).collect();
+        let checks = variant.emit_checks(&fields_names);
         let parameters = variant.fields().iter().map(MsgField::emit_method_field);
         let fields = variant.fields().iter().map(MsgField::emit_pub);
 
         let msg_attrs_to_forward = msg_attrs_to_forward.iter().map(|attr| &attr.attrs);
         let derive_call = variant.msg_attr().msg_type().emit_derive_call();
+        let version_check = version
+            .as_ref()
+            .map(|_| emit_version_check(variants.msg_ty()));
+        let funds_doc = variant.funds().map(FundsPolicyAttr::emit_doc);
+        let events_doc = variant.emit_events_doc();
+        let arbitrary_impl = variants.emit_arbitrary_impl(&name);
 
         quote! {
             #[allow(clippy::derive_partial_eq_without_eq)]
             #derive_call
+            #funds_doc
+            #events_doc
             #( #[ #msg_attrs_to_forward ] )*
             #[serde(rename_all="snake_case")]
             pub struct #name #bracketed_used_generics {
@@ -132,9 +147,31 @@ impl<'a> StructMessage<'a> {
                 pub fn dispatch #bracketed_unused_generics (self, contract: &#contract_type, ctx: #ctx_type) -> #ret_type #full_where
                 {
                     let Self { #(#fields_names,)* } = self;
+                    #checks
+                    #version_check
                     contract.#function_name(Into::into(ctx), #(#fields_names,)*).map_err(Into::into)
                 }
             }
+
+            #arbitrary_impl
         }
     }
 }
+
+/// Emits the cw2 bookkeeping done before calling the user defined method.
+///
+/// Instantiation stores the contract version, while migration ensures
+/// that the contract is not downgraded nor replaced with a different one.
+fn emit_version_check(msg_ty: MsgType) -> TokenStream {
+    let sylvia = crate_module();
+
+    match msg_ty {
+        MsgType::Instantiate => quote! {
+            #sylvia ::cw2::set_contract_version(ctx.0.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        },
+        MsgType::Migrate => quote! {
+            #sylvia ::cw2::ensure_from_older_version(ctx.0.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        },
+        _ => quote! {},
+    }
+}
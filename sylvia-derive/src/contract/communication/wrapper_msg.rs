@@ -1,5 +1,6 @@
 use crate::crate_module;
 use crate::fold::StripGenerics;
+use crate::parser::attributes::features::SylviaFeatures;
 use crate::parser::{ContractErrorAttr, Custom, MsgType};
 use crate::types::interfaces::Interfaces;
 use crate::utils::emit_bracketed_generics;
@@ -22,6 +23,7 @@ pub struct GlueMessage<'a> {
     error: &'a ContractErrorAttr,
     custom: &'a Custom,
     interfaces: &'a Interfaces,
+    sv_features: &'a SylviaFeatures,
 }
 
 impl<'a> GlueMessage<'a> {
@@ -31,6 +33,7 @@ impl<'a> GlueMessage<'a> {
         error: &'a ContractErrorAttr,
         custom: &'a Custom,
         interfaces: &'a Interfaces,
+        sv_features: &'a SylviaFeatures,
     ) -> Self {
         GlueMessage {
             source,
@@ -39,6 +42,7 @@ impl<'a> GlueMessage<'a> {
             error,
             custom,
             interfaces,
+            sv_features,
         }
     }
 
@@ -51,7 +55,7 @@ impl<'a> GlueMessage<'a> {
             error,
             custom,
             interfaces,
-            ..
+            sv_features,
         } = self;
 
         let generics: Vec<_> = source.generics.params.iter().collect();
@@ -62,8 +66,8 @@ impl<'a> GlueMessage<'a> {
         let enum_accessor = msg_ty.as_accessor_name();
         let contract_name = StripGenerics.fold_type((*contract).clone());
 
-        let variants = interfaces.emit_glue_message_variants(msg_ty, contract);
-        let types = interfaces.emit_glue_message_types(msg_ty, contract);
+        let mut variants = interfaces.emit_glue_message_variants(msg_ty, contract);
+        let mut types = interfaces.emit_glue_message_types(msg_ty, contract);
 
         let ep_name = msg_ty.emit_ep_name();
         let messages_fn_name = Ident::new(&format!("{}_messages", ep_name), contract.span());
@@ -71,14 +75,59 @@ impl<'a> GlueMessage<'a> {
         let mut messages_call = interfaces.emit_messages_call(msg_ty);
         messages_call.push(quote! { &#messages_fn_name() });
 
-        let variants_cnt = messages_call.len();
+        let mut dispatch_arms = interfaces.emit_dispatch_arms(msg_ty);
+        let mut interfaces_deserialization_attempts =
+            interfaces.emit_deserialization_attempts(msg_ty);
+        let mut response_schemas_calls = interfaces.emit_response_schemas_calls(msg_ty, contract);
+
+        // Contract using the `supported_interfaces` feature exposes the query listing the ids
+        // of the implemented interfaces.
+        if MsgType::Query == *msg_ty && sv_features.supported_interfaces {
+            let supported_interfaces = quote! { #sylvia ::types::SupportedInterfacesQuery };
+            variants.push(quote! { SupportedInterfaces ( #supported_interfaces ) });
+            types.push(supported_interfaces.clone());
+            messages_call.push(quote! { &#supported_interfaces ::messages() });
+            dispatch_arms.push(quote! {
+                #contract_enum_name :: SupportedInterfaces (_) => #sylvia ::cw_std::to_json_binary(&supported_interfaces()).map_err(Into::into)
+            });
+            interfaces_deserialization_attempts.push(quote! {
+                let msgs = &#supported_interfaces ::messages();
+                if msgs.into_iter().any(|msg| msg == &recv_msg_name) {
+                    match val.deserialize_into() {
+                        Ok(msg) => return Ok(Self:: SupportedInterfaces (msg)),
+                        Err(err) => return Err(D::Error::custom(err)).map(Self:: SupportedInterfaces),
+                    };
+                }
+            });
+            response_schemas_calls.push(quote! { #supported_interfaces ::response_schemas_impl() });
+        }
 
-        let dispatch_arms = interfaces.emit_dispatch_arms(msg_ty);
+        let variants_cnt = messages_call.len();
 
         let dispatch_arm =
             quote! {#contract_enum_name :: #contract_name (msg) => msg.dispatch(contract, ctx)};
 
-        let interfaces_deserialization_attempts = interfaces.emit_deserialization_attempts(msg_ty);
+        let (allowed_when_paused, pause_check) = match msg_ty {
+            MsgType::Exec if sv_features.pausable => {
+                let variants_names = interfaces.variants_names();
+                let allowed_when_paused = quote! {
+                    /// Checks if the message can be dispatched while the contract is paused.
+                    pub fn allowed_when_paused(&self) -> bool {
+                        match self {
+                            #(#contract_enum_name :: #variants_names (msg) => #sylvia ::pausable::AllowWhenPaused::allowed_when_paused(msg),)*
+                            #contract_enum_name :: #contract_name (msg) => #sylvia ::pausable::AllowWhenPaused::allowed_when_paused(msg),
+                        }
+                    }
+                };
+                let pause_check = quote! {
+                    if !self.allowed_when_paused() {
+                        #sylvia ::pausable::ensure_not_paused(ctx.0.storage)?;
+                    }
+                };
+                (allowed_when_paused, pause_check)
+            }
+            _ => (quote! {}, quote! {}),
+        };
 
         let contract_deserialization_attempt = quote! {
             let msgs = &#messages_fn_name();
@@ -93,7 +142,6 @@ impl<'a> GlueMessage<'a> {
         let ctx_type = msg_ty.emit_ctx_type(&custom.query_or_default());
         let ret_type = msg_ty.emit_result_type(&custom.msg_or_default(), &error.error);
 
-        let mut response_schemas_calls = interfaces.emit_response_schemas_calls(msg_ty, contract);
         response_schemas_calls
             .push(quote! {<#contract as #sylvia ::types::ContractApi> :: #enum_accessor ::response_schemas_impl()});
 
@@ -114,6 +162,65 @@ impl<'a> GlueMessage<'a> {
             }
         };
 
+        let arbitrary_impl = match cfg!(feature = "arbitrary") && generics.is_empty() {
+            true => {
+                let mut variants_names: Vec<_> = interfaces
+                    .variants_names()
+                    .map(|name| quote! { #name })
+                    .collect();
+                if MsgType::Query == *msg_ty && sv_features.supported_interfaces {
+                    variants_names.push(quote! { SupportedInterfaces });
+                }
+                variants_names.push(quote! { #contract_name });
+                let contract_msg =
+                    quote! { <#contract as #sylvia ::types::ContractApi> :: #enum_accessor };
+                let arms = variants_names
+                    .iter()
+                    .zip(types.iter().chain([&contract_msg]))
+                    .enumerate()
+                    .map(|(idx, (name, ty))| {
+                        quote! { #idx => Ok(Self:: #name (<#ty as #sylvia ::arbitrary::Arbitrary>::arbitrary(u)?)) }
+                    });
+                let bounds = types
+                    .iter()
+                    .chain([&contract_msg])
+                    .map(|ty| quote! { #ty: #sylvia ::arbitrary::Arbitrary<'sv_arbitrary> });
+                let variants_cnt = variants_names.len();
+                let exec_funds = (MsgType::Exec == *msg_ty).then(|| {
+                    let variants_names = interfaces.variants_names();
+                    quote! {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        impl #sylvia ::fuzz::ExecFunds for #contract_enum_name {
+                            fn funds_policy(&self) -> #sylvia ::types::FundsPolicy {
+                                match self {
+                                    #(#contract_enum_name :: #variants_names (msg) => #sylvia ::fuzz::ExecFunds::funds_policy(msg),)*
+                                    #contract_enum_name :: #contract_name (msg) => #sylvia ::fuzz::ExecFunds::funds_policy(msg),
+                                }
+                            }
+                        }
+                    }
+                });
+
+                quote! {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    impl<'sv_arbitrary> #sylvia ::arbitrary::Arbitrary<'sv_arbitrary> for #contract_enum_name
+                    where
+                        #(#bounds,)*
+                    {
+                        fn arbitrary(u: &mut #sylvia ::arbitrary::Unstructured<'sv_arbitrary>) -> #sylvia ::arbitrary::Result<Self> {
+                            match u.choose_index(#variants_cnt)? {
+                                #(#arms,)*
+                                _ => unreachable!(),
+                            }
+                        }
+                    }
+
+                    #exec_funds
+                }
+            }
+            false => quote! {},
+        };
+
         let modules_names = interfaces.variants_modules();
         let variants_names = interfaces.variants_names();
         let serde = quote! { #sylvia:: serde }.to_string();
@@ -179,15 +286,21 @@ impl<'a> GlueMessage<'a> {
                         #sylvia ::utils::assert_no_intersection(msgs);
                     };
 
+                    #pause_check
+
                     match self {
                         #(#dispatch_arms,)*
                         #dispatch_arm
                     }
                 }
+
+                #allowed_when_paused
             }
 
             #response_schemas
 
+            #arbitrary_impl
+
             impl<'sv_de, #(#generics,)* > #sylvia ::serde::Deserialize<'sv_de> for #contract_enum_name #bracketed_wrapper_generics #full_where_clause {
                 fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
                     where D: #sylvia ::serde::Deserializer<'sv_de>,
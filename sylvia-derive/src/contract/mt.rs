@@ -18,6 +18,8 @@ use crate::utils::{emit_bracketed_generics, get_ident_from_type, SvCasing};
 /// More info here: [MultiTest helpers](https://cosmwasm-docs.vercel.app/sylvia/macros/generated-types/multitest).
 pub struct MtHelpers<'a> {
     error_type: Type,
+    /// Whether the errors are returned together with their codes.
+    error_codes: bool,
     contract_name: &'a Type,
     source: &'a ItemImpl,
     generic_params: &'a [&'a GenericParam],
@@ -79,7 +81,9 @@ impl<'a> MtHelpers<'a> {
         );
 
         let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
-        let error_type = parsed_attrs.error_attrs.unwrap_or_default().error;
+        let error_attrs = parsed_attrs.error_attrs.unwrap_or_default();
+        let error_codes = error_attrs.codes;
+        let error_type = error_attrs.error;
         let error_type = parse_quote! { #error_type };
         let sv_features = parsed_attrs.sv_features;
 
@@ -87,6 +91,7 @@ impl<'a> MtHelpers<'a> {
 
         Self {
             error_type,
+            error_codes,
             source,
             generic_params,
             where_clause,
@@ -106,6 +111,7 @@ impl<'a> MtHelpers<'a> {
     pub fn emit(&self) -> TokenStream {
         let Self {
             error_type,
+            error_codes,
             contract_name,
             custom,
             exec_variants,
@@ -143,16 +149,16 @@ impl<'a> MtHelpers<'a> {
         let trait_name = Ident::new(&format!("{}Proxy", contract_ident), contract_ident.span());
 
         let exec_methods = exec_variants.variants().map(|variant| {
-            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, &api)
+            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, *error_codes, &api)
         });
         let query_methods = query_variants.variants().map(|variant| {
-            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, &api)
+            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, *error_codes, &api)
         });
         let sudo_methods = sudo_variants.variants().map(|variant| {
-            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, &api)
+            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, *error_codes, &api)
         });
         let migrate_methods = migrate_variants.variants().map(|variant| {
-            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, &api)
+            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, *error_codes, &api)
         });
 
         let exec_methods_declarations = exec_variants
@@ -298,10 +304,33 @@ impl<'a> MtHelpers<'a> {
             quote! {}
         };
 
+        let (gas_log_field, gas_log_init, wasm_methods) = if cfg!(feature = "vm") {
+            (
+                quote! { gas_log: #sylvia ::vm::GasLog, },
+                quote! { gas_log: Default::default(), },
+                quote! {
+                    pub fn store_wasm(app: &'app #sylvia ::multitest::App< #mt_app >, wasm: &[u8]) -> Self {
+                        let contract = #sylvia ::vm::WasmContract::new(wasm)
+                            .unwrap_or_else(|err| panic!("Failed to store the wasm code: {err}"));
+                        let gas_log = contract.gas_log();
+                        let code_id = app.store_code(Box::new(contract));
+                        Self { code_id, app, gas_log, _phantom: std::marker::PhantomData::default() }
+                    }
+
+                    pub fn gas_log(&self) -> #sylvia ::vm::GasLog {
+                        self.gas_log.clone()
+                    }
+                },
+            )
+        } else {
+            (quote! {}, quote! {}, quote! {})
+        };
+
         quote! {
             pub struct CodeId<'app, Contract, MtApp> {
                 code_id: u64,
                 app: &'app #sylvia ::multitest::App<MtApp>,
+                #gas_log_field
                 _phantom: std::marker::PhantomData<Contract>,
 
             }
@@ -320,11 +349,12 @@ impl<'a> MtHelpers<'a> {
             {
                 pub fn store_code(app: &'app #sylvia ::multitest::App< #mt_app >) -> Self {
                     let code_id = app
-                        .app_mut()
                         .store_code(Box::new( #contract_ident:: < #(#generic_params_lifetimes_replaced),* > ::new() ));
-                    Self { code_id, app, _phantom: std::marker::PhantomData::default() }
+                    Self { code_id, app, #gas_log_init _phantom: std::marker::PhantomData::default() }
                 }
 
+                #wasm_methods
+
                 pub fn code_id(&self) -> u64 {
                     self.code_id
                 }
@@ -369,6 +399,17 @@ impl<'a> MtHelpers<'a> {
         let custom_msg = self.custom.msg_or_default();
 
         let instantiate2_body = self.emit_instantiate2_body();
+        let nonpayable_check = instantiate_variant
+            .get_only_variant()
+            .and_then(MsgVariant::funds)
+            .filter(|funds| funds.is_nonpayable())
+            .map(|_| {
+                quote! {
+                    if !funds.is_empty() {
+                        panic!("Message {:?} is nonpayable, but funds {:?} were sent with it", msg, funds);
+                    }
+                }
+            });
 
         quote! {
             pub struct InstantiateProxy<'proxy, 'app, #(#generic_params,)* MtApp> {
@@ -406,6 +447,7 @@ impl<'a> MtHelpers<'a> {
                 #[track_caller]
                 pub fn call(self, sender: &#sylvia ::cw_std::Addr ) -> Result<#sylvia ::multitest::Proxy<'app, MtApp, #contract_name >, #error_type> {
                     let Self {code_id, funds, label, admin, salt, msg} = self;
+                    #nonpayable_check
 
                     match salt {
                         Some(salt) => {
@@ -421,7 +463,7 @@ impl<'a> MtHelpers<'a> {
                                 label,
                                 admin,
                             )
-                            .map_err(|err| err.downcast().unwrap())
+                            .map_err(#sylvia ::multitest::contract_error)
                             .map(|addr| #sylvia ::multitest::Proxy {
                                 contract_addr: addr,
                                 app: code_id.app,
@@ -454,7 +496,7 @@ impl<'a> MtHelpers<'a> {
                 let app_response = (*code_id.app)
                     .app_mut()
                     .execute(sender.clone(), msg.into())
-                    .map_err(|err| err.downcast::< #error_type >().unwrap())?;
+                    .map_err(#sylvia ::multitest::contract_error::< #error_type >)?;
 
                 #sylvia:: cw_utils::parse_instantiate_response_data(app_response.data.unwrap().as_slice())
                     .map_err(|err| Into::into( #sylvia ::cw_std::StdError::generic_err(err.to_string())))
@@ -477,6 +519,7 @@ impl<'a> MtHelpers<'a> {
     fn emit_impl_contract(&self) -> TokenStream {
         let Self {
             source,
+            error_codes,
             contract_name,
             custom,
             override_entry_points,
@@ -494,27 +537,29 @@ impl<'a> MtHelpers<'a> {
         let instantiate_body = override_entry_points
             .get_entry_point(MsgType::Instantiate)
             .map(OverrideEntryPoint::emit_multitest_dispatch)
-            .unwrap_or_else(|| emit_default_dispatch(&MsgType::Instantiate, contract_name));
+            .unwrap_or_else(|| {
+                emit_default_dispatch(&MsgType::Instantiate, contract_name, *error_codes)
+            });
 
         let exec_body = override_entry_points
             .get_entry_point(MsgType::Exec)
             .map(OverrideEntryPoint::emit_multitest_dispatch)
-            .unwrap_or_else(|| emit_default_dispatch(&MsgType::Exec, contract_name));
+            .unwrap_or_else(|| emit_default_dispatch(&MsgType::Exec, contract_name, *error_codes));
 
         let query_body = override_entry_points
             .get_entry_point(MsgType::Query)
             .map(OverrideEntryPoint::emit_multitest_dispatch)
-            .unwrap_or_else(|| emit_default_dispatch(&MsgType::Query, contract_name));
+            .unwrap_or_else(|| emit_default_dispatch(&MsgType::Query, contract_name, *error_codes));
 
         let sudo_body = override_entry_points
             .get_entry_point(MsgType::Sudo)
             .map(OverrideEntryPoint::emit_multitest_dispatch)
-            .unwrap_or_else(|| emit_default_dispatch(&MsgType::Sudo, contract_name));
+            .unwrap_or_else(|| emit_default_dispatch(&MsgType::Sudo, contract_name, *error_codes));
 
         let migrate_body = match override_entry_points.get_entry_point(MsgType::Migrate) {
             Some(entry_point) => entry_point.emit_multitest_dispatch(),
             None if migrate_variants.get_only_variant().is_some() => {
-                emit_default_dispatch(&MsgType::Migrate, contract_name)
+                emit_default_dispatch(&MsgType::Migrate, contract_name, *error_codes)
             }
             None => quote! { #sylvia ::anyhow::bail!("migrate not implemented for contract") },
         };
@@ -532,15 +577,16 @@ impl<'a> MtHelpers<'a> {
                         quote! { #contract_ident }
                     };
 
+                    let map_err = emit_map_err(*error_codes);
                     if sv_features.replies {
                         quote! {
                             let contract = #contract_turbofish ::new();
-                            dispatch_reply(deps, env, msg, contract).map_err(Into::into)
+                            dispatch_reply(deps, env, msg, contract) #map_err
                         }
                     } else {
                         let reply_name = _reply.name().to_case(Case::Snake);
                         quote! {
-                            self. #reply_name ((deps, env).into(), msg).map_err(Into::into)
+                            self. #reply_name ((deps, env).into(), msg) #map_err
                         }
                     }
                 })
@@ -616,17 +662,30 @@ impl<'a> MtHelpers<'a> {
     }
 }
 
-fn emit_default_dispatch(msg_ty: &MsgType, contract_name: &Type) -> TokenStream {
+fn emit_default_dispatch(msg_ty: &MsgType, contract_name: &Type, error_codes: bool) -> TokenStream {
     let sylvia = crate_module();
 
     let values = msg_ty.emit_ctx_values();
     let msg_name = msg_ty.as_accessor_wrapper_name();
     let api_msg = quote! { < #contract_name as #sylvia ::types::ContractApi> :: #msg_name };
 
+    let map_err = emit_map_err(error_codes);
+
     quote! {
         #sylvia ::cw_std::from_json::< #api_msg >(&msg)?
             .dispatch(self, ( #values ))
-            .map_err(Into::into)
+            #map_err
+    }
+}
+
+/// Emits conversion of the contract error into the `anyhow::Error`, displayed together with
+/// the error code if the contract declares `#[sv::error(ContractError, codes)]`.
+fn emit_map_err(error_codes: bool) -> TokenStream {
+    let sylvia = crate_module();
+
+    match error_codes {
+        true => quote! { .map_err(|err| #sylvia ::errors::CodedError::new(err).into_anyhow()) },
+        false => quote! { .map_err(Into::into) },
     }
 }
 
@@ -636,6 +695,7 @@ trait EmitMethods {
         custom_msg: &Type,
         mt_app: &Type,
         error_type: &Type,
+        error_codes: bool,
         api: &TokenStream,
     ) -> TokenStream;
 
@@ -653,6 +713,7 @@ impl EmitMethods for MsgVariant<'_> {
         custom_msg: &Type,
         mt_app: &Type,
         error_type: &Type,
+        error_codes: bool,
         api: &TokenStream,
     ) -> TokenStream {
         let sylvia = crate_module();
@@ -668,17 +729,48 @@ impl EmitMethods for MsgVariant<'_> {
         let arguments = self.as_fields_names();
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
+        let data_type = self
+            .msg_attr()
+            .data_type()
+            .map(|data_type| quote! { , #data_type });
+        let with_data = data_type.as_ref().map(|_| quote! { .with_data() });
+        let funds_policy = self.funds().map(|funds| {
+            let funds = funds.emit();
+            quote! { .with_funds_policy(#funds) }
+        });
+        let error_codes = error_codes.then(|| quote! { .with_error_codes() });
 
         match self.msg_attr().msg_type() {
             MsgType::Exec => quote! {
                 #[track_caller]
-                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api :: #type_name, #mt_app, #custom_msg> {
+                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api :: #type_name, #mt_app, #custom_msg #data_type > {
                     let msg = #api :: #type_name :: #name ( #(#arguments),* );
 
                     #sylvia ::multitest::ExecProxy::new(&self.contract_addr, msg, &self.app)
+                        #with_data
+                        #funds_policy
+                        #error_codes
                 }
             },
             MsgType::Query => {
+                let page_iter = self
+                    .emit_page_iter_signature(&quote! { #error_type })
+                    .map(|signature| {
+                        let arguments = self.emit_page_iter_arguments();
+                        quote! {
+                            #signature {
+                                #sylvia ::types::PageIter::new(move |start_after| {
+                                    let msg = #api :: #type_name :: #name ( #(#arguments),* );
+
+                                    (*self.app)
+                                        .querier()
+                                        .query_wasm_smart::< #return_type >(self.contract_addr.clone(), &msg)
+                                        .map_err(Into::into)
+                                })
+                            }
+                        }
+                    });
+
                 quote! {
                     fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type> {
                         let msg = #api :: #type_name :: #name ( #(#arguments),* );
@@ -688,6 +780,8 @@ impl EmitMethods for MsgVariant<'_> {
                             .query_wasm_smart(self.contract_addr.clone(), &msg)
                             .map_err(Into::into)
                     }
+
+                    #page_iter
                 }
             }
             MsgType::Sudo => quote! {
@@ -697,7 +791,7 @@ impl EmitMethods for MsgVariant<'_> {
                     (*self.app)
                         .app_mut()
                         .wasm_sudo(self.contract_addr.clone(), &msg)
-                        .map_err(|err| err.downcast().unwrap())
+                        .map_err(#sylvia ::multitest::contract_error)
                 }
             },
             MsgType::Migrate => quote! {
@@ -730,14 +824,33 @@ impl EmitMethods for MsgVariant<'_> {
             .collect();
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
+        let data_type = self
+            .msg_attr()
+            .data_type()
+            .map(|data_type| quote! { , #data_type });
 
-        match self.msg_attr().msg_type() {
+        let deprecated = self.emit_deprecated();
+
+        let declaration = match self.msg_attr().msg_type() {
             MsgType::Exec => quote! {
-                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api:: #type_name, MtApp, #custom_msg>;
-            },
-            MsgType::Query => quote! {
-                fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
+                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api:: #type_name, MtApp, #custom_msg #data_type >;
             },
+            MsgType::Query => {
+                let page_iter =
+                    self.emit_page_iter_signature(&quote! { #error_type })
+                        .map(|signature| {
+                            quote! {
+                                #deprecated
+                                #signature;
+                            }
+                        });
+
+                quote! {
+                    fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
+
+                    #page_iter
+                }
+            }
             MsgType::Sudo => quote! {
                 fn #name (&self, #(#params,)* ) -> Result< #sylvia ::cw_multi_test::AppResponse, #error_type>;
             },
@@ -745,7 +858,12 @@ impl EmitMethods for MsgVariant<'_> {
                 #[track_caller]
                 fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::MigrateProxy::< #error_type, #api :: #type_name, MtApp, #custom_msg>;
             },
-            _ => quote! {},
+            _ => return quote! {},
+        };
+
+        quote! {
+            #deprecated
+            #declaration
         }
     }
 }
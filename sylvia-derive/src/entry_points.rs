@@ -19,6 +19,8 @@ use crate::types::msg_variant::MsgVariants;
 /// Generates `entry_points` module containing:
 ///     - instantiate, execute, query and sudo entry points by default
 ///     - migrate and reply entry points if respective messages are defined
+///     - ibc entry points for every defined `ibc_*` handler
+///     - `ibc_packet_receive` entry point if any `ibc_packet` message is defined
 pub struct EntryPointInput<'a> {
     item: &'a ItemImpl,
     args: EntryPointArgs,
@@ -45,6 +47,37 @@ impl<'a> EntryPointInput<'a> {
             );
         }
 
+        MsgType::ibc_types().into_iter().for_each(|msg_ty| {
+            let handlers = MsgVariants::<GenericParam>::new(item.as_variants(), msg_ty, &[], &None);
+            let mut handlers = handlers.variants();
+
+            if let (Some(first), Some(second)) = (handlers.next(), handlers.next()) {
+                emit_error!(
+                    second.function_name().span(), "More than one `{}` handler.", msg_ty.emit_ep_name();
+                    note = first.function_name().span() => "Previous definition of the handler."
+                );
+            }
+        });
+
+        let ibc_packets =
+            MsgVariants::<GenericParam>::new(item.as_variants(), MsgType::IbcPacket, &[], &None);
+        let packet_receive = MsgVariants::<GenericParam>::new(
+            item.as_variants(),
+            MsgType::IbcPacketReceive,
+            &[],
+            &None,
+        );
+
+        if let (Some(packet), Some(handler)) = (
+            ibc_packets.variants().next(),
+            packet_receive.variants().next(),
+        ) {
+            emit_error!(
+                handler.function_name().span(), "The `ibc_packet_receive` handler cannot be defined alongside `ibc_packet` messages.";
+                note = packet.function_name().span() => "The `ibc_packet_receive` entry point is generated to dispatch the `ibc_packet` messages.";
+            );
+        }
+
         Self { item, args }
     }
 
@@ -59,12 +92,18 @@ impl<'a> EntryPointInput<'a> {
 /// Defines logic for generating entry points.
 ///
 /// By default generates entry points for `instantiate`, `execute`, `query` and `sudo` messages.
-/// Generates `reply` and `migrate` entry points if respective messages are defined.
+/// Generates `reply`, `migrate` and `ibc_*` entry points if respective messages are defined.
+/// Generates `ibc_packet_receive` entry point dispatching the `IbcPacketMsg` if any `ibc_packet`
+/// message is defined.
 pub struct EntryPoints<'a> {
     source: &'a ItemImpl,
     name: Type,
     error: Type,
+    /// Whether the errors are returned together with their codes.
+    error_codes: bool,
     reply: Option<Ident>,
+    ibc_handlers: Vec<(MsgType, Ident)>,
+    ibc_packets: bool,
     override_entry_points: Vec<OverrideEntryPoint>,
     generics: Vec<&'a GenericParam>,
     where_clause: &'a Option<WhereClause>,
@@ -79,7 +118,9 @@ impl<'a> EntryPoints<'a> {
         let override_entry_points = parsed_attrs.override_entry_point_attrs;
         let sv_features = parsed_attrs.sv_features;
 
-        let error = parsed_attrs.error_attrs.unwrap_or_default().error;
+        let error_attrs = parsed_attrs.error_attrs.unwrap_or_default();
+        let error = error_attrs.error;
+        let error_codes = error_attrs.codes;
 
         let generics: Vec<_> = source.generics.params.iter().collect();
         let where_clause = &source.generics.where_clause;
@@ -90,11 +131,30 @@ impl<'a> EntryPoints<'a> {
                 .map(|variant| variant.function_name().clone())
                 .next();
 
+        let ibc_handlers = MsgType::ibc_types()
+            .into_iter()
+            .filter_map(|msg_ty| {
+                MsgVariants::<GenericParam>::new(source.as_variants(), msg_ty, &[], &None)
+                    .variants()
+                    .map(|variant| (msg_ty, variant.function_name().clone()))
+                    .next()
+            })
+            .collect();
+
+        let ibc_packets =
+            MsgVariants::<GenericParam>::new(source.as_variants(), MsgType::IbcPacket, &[], &None)
+                .variants()
+                .next()
+                .is_some();
+
         Self {
             source,
             name,
             error,
+            error_codes,
             reply,
+            ibc_handlers,
+            ibc_packets,
             override_entry_points,
             generics,
             where_clause,
@@ -107,6 +167,8 @@ impl<'a> EntryPoints<'a> {
         let Self {
             source,
             reply,
+            ibc_handlers,
+            ibc_packets,
             override_entry_points,
             generics,
             where_clause,
@@ -157,6 +219,13 @@ impl<'a> EntryPoints<'a> {
                 }
             });
 
+        let ibc_eps = ibc_handlers
+            .iter()
+            .map(|(msg_ty, _)| *msg_ty)
+            .chain(ibc_packets.then_some(MsgType::IbcPacketReceive))
+            .filter(|msg_ty| override_entry_points.get_entry_point(*msg_ty).is_none())
+            .map(|msg_ty| self.emit_default_entry_point(msg_ty));
+
         quote! {
             pub mod entry_points {
                 use super::*;
@@ -166,6 +235,8 @@ impl<'a> EntryPoints<'a> {
                 #migrate
 
                 #reply_ep
+
+                #(#ibc_eps)*
             }
         }
     }
@@ -174,8 +245,11 @@ impl<'a> EntryPoints<'a> {
         let Self {
             name,
             error,
+            error_codes,
             attrs,
             reply,
+            ibc_handlers,
+            ibc_packets,
             sv_features,
             ..
         } = self;
@@ -201,9 +275,12 @@ impl<'a> EntryPoints<'a> {
         let values = msg_ty.emit_ctx_values();
         let ep_name = msg_ty.emit_ep_name();
         let associated_name = msg_ty.as_accessor_wrapper_name();
-        let msg = match msg_ty {
-            MsgType::Reply => quote! { msg: #sylvia ::cw_std::Reply },
-            _ => quote! { msg: < #contract as #sylvia ::types::ContractApi> :: #associated_name },
+        let msg = match msg_ty.emit_ibc_msg_type() {
+            Some(ibc_msg) => quote! { msg: #ibc_msg },
+            None if msg_ty == MsgType::Reply => quote! { msg: #sylvia ::cw_std::Reply },
+            None => {
+                quote! { msg: < #contract as #sylvia ::types::ContractApi> :: #associated_name }
+            }
         };
         let dispatch = match msg_ty {
             MsgType::Reply if sv_features.replies => quote! {
@@ -213,11 +290,44 @@ impl<'a> EntryPoints<'a> {
             MsgType::Reply => quote! {
                 #contract_turbofish ::new(). #reply((deps, env).into(), msg).map_err(Into::into)
             },
+            MsgType::IbcPacketReceive if *ibc_packets => quote! {
+                let packet = match #sylvia ::cw_std::from_json::< < #contract as #sylvia ::types::IbcPacketApi> :: IbcPacket >(&msg.packet.data) {
+                    Ok(packet) => packet,
+                    Err(err) => {
+                        let ack = #sylvia ::types::PacketAck::<()>::error(err.to_string()).to_binary()?;
+                        return Ok(#sylvia ::cw_std::IbcReceiveResponse::new(ack));
+                    }
+                };
+                packet.dispatch(& #contract_turbofish ::new(), (deps, env, msg)).map_err(Into::into)
+            },
+            msg_ty if msg_ty.is_ibc() => {
+                let handler = ibc_handlers
+                    .iter()
+                    .find(|(ibc_msg_ty, _)| *ibc_msg_ty == msg_ty)
+                    .map(|(_, handler)| handler);
+                quote! {
+                    #contract_turbofish ::new(). #handler((deps, env).into(), msg).map_err(Into::into)
+                }
+            }
             _ => quote! {
                 msg.dispatch(& #contract_turbofish ::new() , ( #values )).map_err(Into::into)
             },
         };
 
+        let (result, dispatch) = match error_codes {
+            true => {
+                let coded_error = parse_quote! { #sylvia ::errors::CodedError< #error > };
+                let coded_result = msg_ty.emit_result_type(&custom_msg, &coded_error);
+                let dispatch = quote! {
+                    #[allow(clippy::redundant_closure_call)]
+                    let result: #result = (move || { #dispatch })();
+                    result.map_err(#sylvia ::errors::CodedError::new)
+                };
+                (coded_result, dispatch)
+            }
+            false => (result, dispatch),
+        };
+
         let cw_std = quote! { #sylvia ::cw_std }.to_string();
 
         quote! {
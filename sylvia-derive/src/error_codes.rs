@@ -0,0 +1,306 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Fields, Ident, ItemEnum, Type, Variant};
+
+use crate::crate_module;
+use crate::parser::attributes::CodeAttr;
+use crate::parser::SylviaAttribute;
+
+/// Preprocessed [`error_codes`](crate::error_codes) macro input.
+///
+/// Generates:
+///     - `ErrorCodes` implementation assigning the codes to the variants
+///     - the enum without the `sv::code` attributes
+pub struct ErrorCodesInput<'a> {
+    item: &'a ItemEnum,
+    variants: Vec<ErrorVariant<'a>>,
+}
+
+impl<'a> ErrorCodesInput<'a> {
+    pub fn new(item: &'a ItemEnum) -> Self {
+        let variants: Vec<_> = item.variants.iter().map(ErrorVariant::new).collect();
+
+        // Collisions with the hashed codes are checked at compile time by the generated code.
+        for (idx, variant) in variants.iter().enumerate() {
+            let Some(code) = variant.code else {
+                continue;
+            };
+            if let Some(previous) = variants[..idx]
+                .iter()
+                .find(|previous| previous.code == Some(code))
+            {
+                emit_error!(
+                    variant.variant.ident.span(), "Error code `{}` is already used by the variant `{}`", code, previous.variant.ident;
+                    note = "Assign the unique code with the `#[sv::code(..)]` attribute"
+                );
+            }
+        }
+
+        Self { item, variants }
+    }
+
+    /// Processes the input and generates the `ErrorCodes` implementation.
+    pub fn process(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self { item, variants } = self;
+        let name = &item.ident;
+        let error_name = name.to_string();
+        let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+        let mut stripped = (*item).clone();
+        stripped.variants.iter_mut().for_each(|variant| {
+            variant
+                .attrs
+                .retain(|attr| SylviaAttribute::new(attr) != Some(SylviaAttribute::Code))
+        });
+
+        let codes: Vec<_> = variants.iter().map(ErrorVariant::emit_code).collect();
+        let named_codes = variants.iter().zip(&codes).map(|(variant, code)| {
+            let name = variant.variant.ident.to_string();
+            quote! { (#name, #code) }
+        });
+        let code_arms = variants.iter().map(ErrorVariant::emit_code_arm);
+        let encode_arms = variants.iter().map(ErrorVariant::emit_encode_arm);
+        let decode_arms = variants.iter().map(ErrorVariant::emit_decode_arm);
+
+        quote! {
+            #stripped
+
+            const _: () = #sylvia ::utils::assert_unique_error_codes(&[#(#codes),*]);
+
+            impl #impl_generics #sylvia ::errors::ErrorCodes for #name #ty_generics #where_clause {
+                const ERROR_NAME: &'static str = #error_name;
+
+                const CODES: &'static [(&'static str, u32)] = &[#(#named_codes),*];
+
+                fn code(&self) -> u32 {
+                    match *self {
+                        #(#code_arms)*
+                    }
+                }
+
+                fn encode_data(&self) -> Option<String> {
+                    match *self {
+                        #(#encode_arms)*
+                    }
+                }
+
+                #[allow(unused_variables)]
+                fn decode_data(code: u32, data: Option<&str>) -> Option<Self> {
+                    match code {
+                        #(#decode_arms)*
+                        _ => None,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Variant of the error enum with its code.
+struct ErrorVariant<'a> {
+    variant: &'a Variant,
+    /// Code assigned with the `sv::code` attribute.
+    code: Option<u32>,
+    fields: Vec<ErrorField>,
+}
+
+/// Field of the error variant and the way it's encoded.
+struct ErrorField {
+    binding: Ident,
+    kind: ErrorFieldKind,
+}
+
+enum ErrorFieldKind {
+    /// Field serialized to JSON.
+    Serde,
+    /// `StdError` stored as its message.
+    StdError,
+    /// Source error of other type which cannot be decoded.
+    Opaque,
+}
+
+impl<'a> ErrorVariant<'a> {
+    fn new(variant: &'a Variant) -> Self {
+        let mut code = None;
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Code))
+        {
+            let Ok(meta) = attr.meta.require_list() else {
+                emit_error!(
+                    attr.path().span(), "Missing parameters for `sv::code`";
+                    note = "Expected `#[sv::code(42)]`"
+                );
+                continue;
+            };
+            if code.is_some() {
+                emit_error!(
+                    meta, "The attribute `sv::code` is redefined";
+                    note = "Only one `sv::code` attribute can exist on a single variant"
+                );
+            } else if let Ok(attr) = CodeAttr::new(meta) {
+                code = Some(attr.code);
+            }
+        }
+
+        let fields = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                let binding = field
+                    .ident
+                    .clone()
+                    .unwrap_or_else(|| format_ident!("field{}", idx));
+                let is_source = field
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path().is_ident("from") || attr.path().is_ident("source"));
+                let kind = match &field.ty {
+                    ty if is_std_error(ty) => ErrorFieldKind::StdError,
+                    _ if is_source => ErrorFieldKind::Opaque,
+                    _ => ErrorFieldKind::Serde,
+                };
+                ErrorField { binding, kind }
+            })
+            .collect();
+
+        Self {
+            variant,
+            code,
+            fields,
+        }
+    }
+
+    fn emit_pattern(&self) -> TokenStream {
+        let name = &self.variant.ident;
+        let bindings = self.fields.iter().map(|field| &field.binding);
+        match self.variant.fields {
+            Fields::Named(_) => quote! { Self:: #name { #(ref #bindings),* } },
+            Fields::Unnamed(_) => quote! { Self:: #name ( #(ref #bindings),* ) },
+            Fields::Unit => quote! { Self:: #name },
+        }
+    }
+
+    fn is_opaque(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| matches!(field.kind, ErrorFieldKind::Opaque))
+    }
+
+    /// Pattern matching the variant without binding its fields.
+    fn emit_wildcard_pattern(&self) -> TokenStream {
+        let name = &self.variant.ident;
+        match self.variant.fields {
+            Fields::Named(_) => quote! { Self:: #name { .. } },
+            Fields::Unnamed(_) => quote! { Self:: #name ( .. ) },
+            Fields::Unit => quote! { Self:: #name },
+        }
+    }
+
+    /// Emits the code of the variant, by default the `sylvia::utils::error_code_hash` of its name.
+    ///
+    /// Unlike the position of the variant, the name doesn't change when the variants are reordered.
+    fn emit_code(&self) -> TokenStream {
+        let sylvia = crate_module();
+        match self.code {
+            Some(code) => quote! { #code },
+            None => {
+                let name = self.variant.ident.to_string();
+                quote! { #sylvia ::utils::error_code_hash(#name) }
+            }
+        }
+    }
+
+    fn emit_code_arm(&self) -> TokenStream {
+        let pattern = self.emit_wildcard_pattern();
+        let code = self.emit_code();
+        quote! { #pattern => #code, }
+    }
+
+    fn emit_encode_arm(&self) -> TokenStream {
+        let sylvia = crate_module();
+
+        if self.fields.is_empty() || self.is_opaque() {
+            let pattern = self.emit_wildcard_pattern();
+            return quote! { #pattern => None, };
+        }
+
+        let pattern = self.emit_pattern();
+        let values = self.fields.iter().map(|field| {
+            let binding = &field.binding;
+            match field.kind {
+                ErrorFieldKind::StdError => {
+                    quote! { #sylvia ::errors::std_error_message(#binding) }
+                }
+                _ => quote! { #binding },
+            }
+        });
+
+        quote! {
+            #pattern => #sylvia ::cw_std::to_json_string(&( #(#values,)* )).ok(),
+        }
+    }
+
+    fn emit_decode_arm(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let name = &self.variant.ident;
+        let code = self.emit_code();
+        let code = quote! { code if code == #code };
+
+        // Variants with the source errors other than `StdError` are not decoded.
+        if self.is_opaque() {
+            return quote! { #code => None, };
+        }
+
+        let bindings: Vec<_> = self.fields.iter().map(|field| &field.binding).collect();
+        let types = self.fields.iter().map(|field| match field.kind {
+            ErrorFieldKind::StdError => quote! { String },
+            _ => quote! { _ },
+        });
+        let named = matches!(self.variant.fields, Fields::Named(_));
+        let values = self.fields.iter().map(|field| {
+            let binding = &field.binding;
+            match (&field.kind, named) {
+                (ErrorFieldKind::StdError, true) => {
+                    quote! { #binding: #sylvia ::cw_std::StdError::generic_err(#binding) }
+                }
+                (ErrorFieldKind::StdError, false) => {
+                    quote! { #sylvia ::cw_std::StdError::generic_err(#binding) }
+                }
+                _ => quote! { #binding },
+            }
+        });
+
+        let variant = match self.variant.fields {
+            Fields::Named(_) => quote! { Self:: #name { #(#values),* } },
+            Fields::Unnamed(_) => quote! { Self:: #name ( #(#values),* ) },
+            Fields::Unit => quote! { Self:: #name },
+        };
+
+        match self.fields.is_empty() {
+            true => quote! { #code => Some(#variant), },
+            false => quote! {
+                #code => {
+                    let ( #(#bindings,)* ): ( #(#types,)* ) = #sylvia ::cw_std::from_json(data?).ok()?;
+                    Some(#variant)
+                }
+            },
+        }
+    }
+}
+
+fn is_std_error(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "StdError")
+}
@@ -0,0 +1,132 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Fields, ItemStruct, LitStr};
+
+use crate::crate_module;
+use crate::parser::EventArgs;
+
+/// Preprocessed [`event`](crate::event) macro input.
+///
+/// Generates:
+///     - `TypedEvent` implementation converting the structure from and into the `Event`
+///     - `From` implementation converting the structure into the `Event`
+pub struct EventInput<'a> {
+    item: &'a ItemStruct,
+    event_type: LitStr,
+}
+
+impl<'a> EventInput<'a> {
+    pub fn new(item: &'a ItemStruct, args: EventArgs) -> Self {
+        let event_type = args.name.unwrap_or_else(|| {
+            let name = item.ident.to_string().to_case(Case::Snake);
+            LitStr::new(&name, item.ident.span())
+        });
+
+        if event_type.value().chars().count() < 2 {
+            emit_error!(
+                event_type.span(), "Event type must be at least 2 characters long.";
+                note = "Event types shorter than 2 characters are rejected by the chain."
+            );
+        }
+
+        if event_type.value().starts_with("wasm-") {
+            emit_error!(
+                event_type.span(), "Event type cannot start with the `wasm-` prefix.";
+                note = "The `wasm-` prefix is added to the events emitted by the contract by the chain."
+            );
+        }
+
+        match &item.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .filter(|field| field.unraw().to_string().starts_with('_'))
+                .for_each(|field| {
+                    emit_error!(
+                        field.span(), "Event attribute cannot start with `_`.";
+                        note = "Attributes starting with `_` are reserved for the chain."
+                    )
+                }),
+            Fields::Unit => (),
+            Fields::Unnamed(fields) => emit_error!(
+                fields.span(), "Tuple structures are not supported as events.";
+                note = "Fields of the event are stored as attributes under the field names."
+            ),
+        }
+
+        Self { item, event_type }
+    }
+
+    /// Processes the input and generates the event implementations.
+    pub fn process(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self { item, event_type } = self;
+        let name = &item.ident;
+        let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+        let fields: Vec<_> = item
+            .fields
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .collect();
+        let keys: Vec<_> = fields
+            .iter()
+            .map(|field| field.unraw().to_string())
+            .collect();
+
+        // Events without fields don't read the attributes.
+        let (event, attribute) = match fields.is_empty() {
+            true => (quote! { _event }, None),
+            false => (
+                quote! { event },
+                Some(quote! {
+                    let attribute = |key: &str| {
+                        event
+                            .attributes
+                            .iter()
+                            .find(|attr| attr.key == key)
+                            .map(|attr| attr.value.as_str())
+                    };
+                }),
+            ),
+        };
+
+        quote! {
+            impl #impl_generics #sylvia ::events::TypedEvent for #name #ty_generics #where_clause {
+                const EVENT_TYPE: &'static str = #event_type;
+
+                fn into_event(self) -> #sylvia ::cw_std::Event {
+                    let event = #sylvia ::cw_std::Event::new(Self::EVENT_TYPE);
+                    #(
+                        let event = match #sylvia ::events::EventAttribute::to_attribute(&self.#fields) {
+                            Some(value) => event.add_attribute(#keys, value),
+                            None => event,
+                        };
+                    )*
+                    event
+                }
+
+                fn from_event(#event: &#sylvia ::cw_std::Event) -> #sylvia ::cw_std::StdResult<Self> {
+                    #attribute
+
+                    Ok(Self {
+                        #(
+                            #fields: #sylvia ::events::EventAttribute::from_attribute(#keys, attribute(#keys))?,
+                        )*
+                    })
+                }
+            }
+
+            impl #impl_generics From<#name #ty_generics> for #sylvia ::cw_std::Event #where_clause {
+                fn from(event: #name #ty_generics) -> Self {
+                    #sylvia ::events::TypedEvent::into_event(event)
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use syn::fold::{self, Fold};
 use syn::punctuated::Punctuated;
 use syn::{
     FnArg, ImplItemFn, ItemImpl, ItemTrait, PatType, Path, Receiver, Signature, Token, TraitItemFn,
+    Type,
 };
 
 use crate::parser::SylviaAttribute;
@@ -100,3 +101,22 @@ impl Fold for StripSelfPath {
         syn::fold::fold_path(self, Path { segments, ..path })
     }
 }
+
+/// Utility for replacing the `Self::Error` with the concrete error type
+pub struct ReplaceSelfError(pub Type);
+
+impl Fold for ReplaceSelfError {
+    fn fold_type(&mut self, ty: Type) -> Type {
+        match &ty {
+            Type::Path(type_path)
+                if type_path.qself.is_none()
+                    && type_path.path.segments.len() == 2
+                    && type_path.path.segments[0].ident == "Self"
+                    && type_path.path.segments[1].ident == "Error" =>
+            {
+                self.0.clone()
+            }
+            _ => fold::fold_type(self, ty),
+        }
+    }
+}
@@ -12,7 +12,7 @@ use crate::parser::attributes::msg::MsgType;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{Custom, ParsedSylviaAttributes};
 use crate::types::associated_types::{AssociatedTypes, ItemType, EXEC_TYPE, QUERY_TYPE};
-use crate::types::msg_variant::MsgVariants;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
 
 mod communication;
 mod mt;
@@ -116,8 +116,11 @@ impl<'a> InterfaceInput<'a> {
         let interface_messages = Api::new(item, custom, associated_types).emit();
 
         let multitest_helpers = self.emit_multitest_helpers();
+        let message_events = self.emit_message_events();
 
         quote! {
+            // Generated code refers to the messages marked with `sv::deprecated`.
+            #[allow(deprecated)]
             pub mod sv {
                 use super::*;
                 #messages
@@ -128,6 +131,8 @@ impl<'a> InterfaceInput<'a> {
 
                 #interface_messages
 
+                #message_events
+
                 #multitest_helpers
             }
         }
@@ -175,6 +180,28 @@ impl<'a> InterfaceInput<'a> {
         }
     }
 
+    /// Emits `message_events` listing the events declared with `sv::events` by the messages.
+    fn emit_message_events(&self) -> TokenStream {
+        let entries: Vec<_> = [MsgType::Exec, MsgType::Sudo]
+            .into_iter()
+            .flat_map(|msg_ty| {
+                let variants =
+                    MsgVariants::new(self.item.as_variants(), msg_ty, &[] as &[&Ident], &None);
+                variants
+                    .variants()
+                    .filter_map(MsgVariant::emit_events_entry)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        quote! {
+            /// Returns names of the messages paired with the types of the events they may emit.
+            pub fn message_events() -> Vec<(&'static str, Vec<&'static str>)> {
+                vec![#(#entries,)*]
+            }
+        }
+    }
+
     fn emit_msg(&self, msg_ty: MsgType) -> TokenStream {
         let where_clause = &self.associated_types.as_where_clause();
         let associated_names: Vec<_> = self
@@ -204,9 +231,12 @@ impl<'a> InterfaceInput<'a> {
             return quote! {};
         }
 
-        let Self { item, .. } = self;
-        let associated_types = &self.associated_types;
+        let Self {
+            item,
+            custom,
+            associated_types,
+        } = self;
 
-        MtHelpers::new(item, associated_types).emit()
+        MtHelpers::new(item, custom, associated_types).emit()
     }
 }
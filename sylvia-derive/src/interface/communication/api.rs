@@ -65,6 +65,17 @@ impl<'a> Api<'a> {
             &source.generics.where_clause,
         );
 
+        let mut signatures: Vec<_> = exec_variants
+            .as_signatures()
+            .into_iter()
+            .chain(query_variants.as_signatures())
+            .chain(sudo_variants.as_signatures())
+            .collect();
+        signatures.sort();
+        let signature = signatures.join("; ");
+        let interface_name_str = interface_name.to_string();
+        let has_sudo = sudo_variants.variants().count() != 0;
+
         let exec_generics = &exec_variants.used_generics();
         let query_generics = &query_variants.used_generics();
         let sudo_generics = &sudo_variants.used_generics();
@@ -99,6 +110,16 @@ impl<'a> Api<'a> {
                 type Querier<'querier> = #sylvia ::types::BoundQuerier<'querier, #custom_query, Self >;
             }
 
+            /// Stable identifier of the interface composed of its name and the hash of its messages.
+            pub const INTERFACE_ID: #sylvia ::types::InterfaceId = #sylvia ::types::InterfaceId::new(#interface_name_str, #signature);
+
+            /// `true` if the interface defines any `sv::msg(sudo)` method.
+            pub const HAS_SUDO: bool = #has_sudo;
+
+            impl<'sv_iface_id, Error, #(#generics),*> #sylvia ::types::Interface for dyn #interface_name < Error = Error, #(#generics = #generics,)* > + 'sv_iface_id {
+                const ID: #sylvia ::types::InterfaceId = INTERFACE_ID;
+            }
+
             pub struct Api < #(#generics,)* > {
                 #phantom
             }
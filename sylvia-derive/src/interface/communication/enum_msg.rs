@@ -1,3 +1,4 @@
+use crate::crate_module;
 use crate::parser::attributes::MsgAttrForwarding;
 use crate::parser::{Custom, MsgType, ParsedSylviaAttributes};
 use crate::types::associated_types::{AssociatedTypes, ItemType, EXEC_TYPE, QUERY_TYPE};
@@ -62,6 +63,7 @@ impl<'a> EnumMessage<'a> {
     }
 
     pub fn emit(&self) -> TokenStream {
+        let sylvia = crate_module();
         let Self {
             source,
             variants,
@@ -82,6 +84,10 @@ impl<'a> EnumMessage<'a> {
         msgs.sort();
         let msgs_cnt = msgs.len();
         let variants_constructors = variants.emit_constructors();
+        let allowed_when_paused = variants.emit_allowed_when_paused(&unique_enum_name);
+        let ts_methods = variants.emit_ts_methods(&unique_enum_name);
+        let arbitrary_impl = variants.emit_arbitrary_impl(&unique_enum_name);
+        let exec_funds = variants.emit_exec_funds(&unique_enum_name);
         let msg_variants = variants.emit();
 
         let ctx_type = msg_ty.emit_ctx_type(query_type);
@@ -94,6 +100,9 @@ impl<'a> EnumMessage<'a> {
             .map(ItemType::as_where_predicate);
         let where_clause = variants.where_clause();
         let contract_predicate = associated_types.emit_contract_predicate(trait_name);
+        let invalid_addr_predicate = variants
+            .has_addr_fields()
+            .then(|| quote! { ContractT::Error: From<#sylvia ::types::InvalidAddr>, });
 
         let phantom_variant = variants.emit_phantom_variant();
         let phatom_match_arm = variants.emit_phantom_match_arm();
@@ -120,6 +129,7 @@ impl<'a> EnumMessage<'a> {
                     -> #dispatch_type
                 where
                     #(#where_predicates,)*
+                    #invalid_addr_predicate
                     #contract_predicate
                 {
                     use #unique_enum_name::*;
@@ -132,6 +142,14 @@ impl<'a> EnumMessage<'a> {
                 #(#variants_constructors)*
             }
 
+            #allowed_when_paused
+
+            #ts_methods
+
+            #arbitrary_impl
+
+            #exec_funds
+
             pub const fn #messages_fn_name () -> [&'static str; #msgs_cnt] {
                 [#(#msgs,)*]
             }
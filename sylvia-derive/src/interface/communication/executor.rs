@@ -99,10 +99,11 @@ impl EmitExecutorMethod for MsgVariant<'_> {
         let parameters = fields.iter().map(MsgField::emit_method_field_folded);
         let fields_names = fields.iter().map(MsgField::name);
         let variant_name = name.to_case(Case::Snake);
+        let data_type = self.emit_executor_data_type();
 
         quote! {
-            fn #variant_name(self, #(#parameters),*) -> Result<#sylvia ::types::ExecutorBuilder< #sylvia ::types::ReadyExecutorBuilderState >, #sylvia ::cw_std::StdError> {
-                Ok(#sylvia ::types::ExecutorBuilder::<#sylvia ::types::ReadyExecutorBuilderState>::new(
+            fn #variant_name(self, #(#parameters),*) -> Result<#sylvia ::types::ExecutorBuilder< #sylvia ::types::ReadyExecutorBuilderState #data_type >, #sylvia ::cw_std::StdError> {
+                Ok(#sylvia ::types::ExecutorBuilder::<#sylvia ::types::ReadyExecutorBuilderState #data_type>::new(
                     self.contract().to_owned(),
                     self.funds().to_owned(),
                     #sylvia ::cw_std::to_json_binary( & #api_path :: #variant_name (#(#fields_names),*) )?,
@@ -121,8 +122,13 @@ impl EmitExecutorMethod for MsgVariant<'_> {
             .map(|field| field.emit_method_field_folded());
         let variant_name = name.to_case(Case::Snake);
 
+        let data_type = self.emit_executor_data_type();
+
+        let deprecated = self.emit_deprecated();
+
         quote! {
-            fn #variant_name(self, #(#parameters),*) -> Result< #sylvia ::types::ExecutorBuilder<#sylvia ::types::ReadyExecutorBuilderState>, #sylvia ::cw_std::StdError>;
+            #deprecated
+            fn #variant_name(self, #(#parameters),*) -> Result< #sylvia ::types::ExecutorBuilder<#sylvia ::types::ReadyExecutorBuilderState #data_type>, #sylvia ::cw_std::StdError>;
         }
     }
 }
@@ -102,11 +102,26 @@ impl EmitQuerierMethod for MsgVariant<'_> {
         let fields_names = fields.iter().map(MsgField::name);
         let variant_name = name.to_case(Case::Snake);
 
+        let error_type = quote! { #sylvia ::cw_std::StdError };
+        let page_iter = self.emit_page_iter_signature(&error_type).map(|signature| {
+            let arguments = self.emit_page_iter_arguments();
+            quote! {
+                #signature {
+                    #sylvia ::types::PageIter::new(move |start_after| {
+                        let query = #api_path :: #variant_name (#(#arguments),*);
+                        self.querier().query_wasm_smart::< #return_type >(self.contract(), &query)
+                    })
+                }
+            }
+        });
+
         quote! {
             fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError> {
                 let query = #api_path :: #variant_name (#(#fields_names),*);
                 self.querier().query_wasm_smart(self.contract(), &query)
             }
+
+            #page_iter
         }
     }
 
@@ -121,8 +136,21 @@ impl EmitQuerierMethod for MsgVariant<'_> {
             .map(|field| field.emit_method_field_folded());
         let variant_name = name.to_case(Case::Snake);
 
+        let deprecated = self.emit_deprecated();
+        let page_iter = self
+            .emit_page_iter_signature(&quote! { #sylvia ::cw_std::StdError })
+            .map(|signature| {
+                quote! {
+                    #deprecated
+                    #signature;
+                }
+            });
+
         quote! {
+            #deprecated
             fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError>;
+
+            #page_iter
         }
     }
 }
@@ -1,11 +1,17 @@
 use convert_case::Case;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::{parse_quote, GenericParam, ItemTrait, TraitItem, Type};
+use quote::{format_ident, quote};
+use syn::fold::Fold;
+use syn::{
+    parse_quote, FnArg, GenericArgument, GenericParam, ItemTrait, Pat, PathArguments, Signature,
+    TraitItem, Type, TypeParamBound,
+};
 
 use crate::crate_module;
+use crate::fold::ReplaceSelfError;
 use crate::parser::attributes::msg::MsgType;
 use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::Custom;
 use crate::types::associated_types::AssociatedTypes;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
 use crate::utils::SvCasing;
@@ -14,6 +20,7 @@ use crate::utils::SvCasing;
 pub struct MtHelpers<'a> {
     source: &'a ItemTrait,
     error_type: Type,
+    custom: &'a Custom,
     associated_types: &'a AssociatedTypes<'a>,
     exec_variants: MsgVariants<'a, GenericParam>,
     query_variants: MsgVariants<'a, GenericParam>,
@@ -22,7 +29,11 @@ pub struct MtHelpers<'a> {
 }
 
 impl<'a> MtHelpers<'a> {
-    pub fn new(source: &'a ItemTrait, associated_types: &'a AssociatedTypes) -> Self {
+    pub fn new(
+        source: &'a ItemTrait,
+        custom: &'a Custom,
+        associated_types: &'a AssociatedTypes,
+    ) -> Self {
         let where_clause = &source.generics.where_clause;
         let exec_variants =
             MsgVariants::new(source.as_variants(), MsgType::Exec, &[], where_clause);
@@ -43,6 +54,7 @@ impl<'a> MtHelpers<'a> {
         Self {
             error_type,
             source,
+            custom,
             associated_types,
             where_clause,
             exec_variants,
@@ -60,6 +72,7 @@ impl<'a> MtHelpers<'a> {
             exec_variants,
             query_variants,
             sudo_variants,
+            ..
         } = self;
 
         let sylvia = crate_module();
@@ -119,6 +132,8 @@ impl<'a> MtHelpers<'a> {
             .as_ref()
             .map(|where_clause| &where_clause.predicates);
 
+        let mock = self.emit_mock();
+
         quote! {
             pub mod mt {
                 use super::*;
@@ -158,6 +173,409 @@ impl<'a> MtHelpers<'a> {
                     #(#exec_methods)*
                     #(#sudo_methods)*
                 }
+
+                #mock
+            }
+        }
+    }
+
+    /// Emits the mock implementing the interface with the programmable responses and recording
+    /// the calls.
+    ///
+    /// The mock is emitted only for the interfaces it can implement: without the associated types
+    /// other than `Error`, which is set to `StdError`, without supertraits and without methods
+    /// other than the messages or the provided ones.
+    fn emit_mock(&self) -> TokenStream {
+        let Self {
+            source,
+            custom,
+            exec_variants,
+            query_variants,
+            sudo_variants,
+            ..
+        } = self;
+
+        if !self.is_mockable() {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let interface_name = &source.ident;
+        let mock_name = format_ident!("{}Mock", interface_name);
+        let state_name = format_ident!("{}MockState", interface_name);
+        let custom_msg = custom.msg_or_default();
+        let custom_query = custom.query_or_default();
+
+        let methods: Vec<_> = exec_variants
+            .variants()
+            .chain(query_variants.variants())
+            .chain(sudo_variants.variants())
+            .filter_map(|variant| {
+                let sig = self.signature(variant.function_name())?;
+                Some(MockMethod::new(variant, sig))
+            })
+            .collect();
+
+        let state_fields = methods.iter().map(MockMethod::emit_state_field);
+        let mock_methods = methods.iter().map(MockMethod::emit_mock_methods);
+        let trait_methods = methods.iter().map(MockMethod::emit_trait_method);
+
+        let mock_doc = format!(
+            "Mock of the [{interface_name}](super::{interface_name}) interface for MultiTest.\n\n\
+            Clones of the mock share the programmed responses and the recorded calls. \
+            Store the mock with [CodeId::store_code] and keep a clone to program and inspect it."
+        );
+
+        let mt_app = quote! {
+            #sylvia ::cw_multi_test::App<
+                BankT,
+                ApiT,
+                StorageT,
+                CustomT,
+                #sylvia ::cw_multi_test::WasmKeeper< #custom_msg , #custom_query >,
+                StakingT,
+                DistrT,
+                IbcT,
+                GovT,
+            >
+        };
+
+        quote! {
+            #[doc = #mock_doc]
+            #[derive(Clone, Default)]
+            pub struct #mock_name {
+                state: std::rc::Rc<std::cell::RefCell< #state_name >>,
+            }
+
+            #[derive(Default)]
+            struct #state_name {
+                #(#state_fields,)*
+            }
+
+            impl #mock_name {
+                pub fn new() -> Self {
+                    Self::default()
+                }
+
+                #(#mock_methods)*
+            }
+
+            impl super:: #interface_name for #mock_name {
+                type Error = #sylvia ::cw_std::StdError;
+
+                #(#trait_methods)*
+            }
+
+            impl #sylvia ::cw_multi_test::Contract< #custom_msg, #custom_query > for #mock_name {
+                fn execute(
+                    &self,
+                    deps: #sylvia ::cw_std::DepsMut< #custom_query >,
+                    env: #sylvia ::cw_std::Env,
+                    info: #sylvia ::cw_std::MessageInfo,
+                    msg: Vec<u8>,
+                ) -> #sylvia ::anyhow::Result< #sylvia ::cw_std::Response< #custom_msg >> {
+                    #sylvia ::cw_std::from_json::<ExecMsg>(&msg)?
+                        .dispatch(self, (deps, env, info))
+                        .map_err(Into::into)
+                }
+
+                fn instantiate(
+                    &self,
+                    _deps: #sylvia ::cw_std::DepsMut< #custom_query >,
+                    _env: #sylvia ::cw_std::Env,
+                    _info: #sylvia ::cw_std::MessageInfo,
+                    _msg: Vec<u8>,
+                ) -> #sylvia ::anyhow::Result< #sylvia ::cw_std::Response< #custom_msg >> {
+                    Ok(#sylvia ::cw_std::Response::new())
+                }
+
+                fn query(
+                    &self,
+                    deps: #sylvia ::cw_std::Deps< #custom_query >,
+                    env: #sylvia ::cw_std::Env,
+                    msg: Vec<u8>,
+                ) -> #sylvia ::anyhow::Result< #sylvia ::cw_std::Binary> {
+                    #sylvia ::cw_std::from_json::<QueryMsg>(&msg)?
+                        .dispatch(self, (deps, env))
+                        .map_err(Into::into)
+                }
+
+                fn sudo(
+                    &self,
+                    deps: #sylvia ::cw_std::DepsMut< #custom_query >,
+                    env: #sylvia ::cw_std::Env,
+                    msg: Vec<u8>,
+                ) -> #sylvia ::anyhow::Result< #sylvia ::cw_std::Response< #custom_msg >> {
+                    #sylvia ::cw_std::from_json::<SudoMsg>(&msg)?
+                        .dispatch(self, (deps, env))
+                        .map_err(Into::into)
+                }
+
+                fn reply(
+                    &self,
+                    _deps: #sylvia ::cw_std::DepsMut< #custom_query >,
+                    _env: #sylvia ::cw_std::Env,
+                    _msg: #sylvia ::cw_std::Reply,
+                ) -> #sylvia ::anyhow::Result< #sylvia ::cw_std::Response< #custom_msg >> {
+                    #sylvia ::anyhow::bail!("reply not implemented for the mock")
+                }
+
+                fn migrate(
+                    &self,
+                    _deps: #sylvia ::cw_std::DepsMut< #custom_query >,
+                    _env: #sylvia ::cw_std::Env,
+                    _msg: Vec<u8>,
+                ) -> #sylvia ::anyhow::Result< #sylvia ::cw_std::Response< #custom_msg >> {
+                    #sylvia ::anyhow::bail!("migrate not implemented for the mock")
+                }
+            }
+
+            pub struct CodeId<'app, MtApp> {
+                code_id: u64,
+                app: &'app #sylvia ::multitest::App<MtApp>,
+            }
+
+            impl<'app, BankT, ApiT, StorageT, CustomT, StakingT, DistrT, IbcT, GovT> CodeId<'app, #mt_app >
+                where
+                    BankT: #sylvia ::cw_multi_test::Bank,
+                    ApiT: #sylvia ::cw_std::Api,
+                    StorageT: #sylvia ::cw_std::Storage,
+                    CustomT: #sylvia ::cw_multi_test::Module<ExecT = #custom_msg, QueryT = #custom_query >,
+                    StakingT: #sylvia ::cw_multi_test::Staking,
+                    DistrT: #sylvia ::cw_multi_test::Distribution,
+                    IbcT: #sylvia ::cw_multi_test::Ibc,
+                    GovT: #sylvia ::cw_multi_test::Gov,
+            {
+                /// Stores the clone of the `mock` in the `app`.
+                pub fn store_code(app: &'app #sylvia ::multitest::App< #mt_app >, mock: & #mock_name) -> Self {
+                    let code_id = app.store_code(Box::new(mock.clone()));
+                    Self { code_id, app }
+                }
+
+                pub fn code_id(&self) -> u64 {
+                    self.code_id
+                }
+
+                /// Instantiates the mock. It accepts any instantiate message and doesn't touch the storage.
+                pub fn instantiate(&self) -> InstantiateProxy<'_, 'app, #mt_app > {
+                    InstantiateProxy {
+                        code_id: self,
+                        funds: &[],
+                        label: "Mock",
+                        admin: None,
+                    }
+                }
+            }
+
+            pub struct InstantiateProxy<'proxy, 'app, MtApp> {
+                code_id: &'proxy CodeId<'app, MtApp>,
+                funds: &'proxy [#sylvia ::cw_std::Coin],
+                label: &'proxy str,
+                admin: Option<String>,
+            }
+
+            impl<'proxy, 'app, MtApp> InstantiateProxy<'proxy, 'app, MtApp>
+                where
+                    MtApp: #sylvia ::cw_multi_test::Executor< #custom_msg >,
+            {
+                pub fn with_funds(self, funds: &'proxy [#sylvia ::cw_std::Coin]) -> Self {
+                    Self { funds, ..self }
+                }
+
+                pub fn with_label(self, label: &'proxy str) -> Self {
+                    Self { label, ..self }
+                }
+
+                pub fn with_admin<'sv_admins_lifetime>(self, admin: impl Into<Option<&'sv_admins_lifetime str>>) -> Self {
+                    let admin = admin.into().map(str::to_owned);
+                    Self { admin, ..self }
+                }
+
+                #[track_caller]
+                pub fn call(self, sender: &#sylvia ::cw_std::Addr) -> Result<#sylvia ::multitest::Proxy<'app, MtApp, #mock_name >, #sylvia ::cw_std::StdError> {
+                    let Self { code_id, funds, label, admin } = self;
+                    (*code_id.app)
+                        .app_mut()
+                        .instantiate_contract(
+                            code_id.code_id,
+                            sender.clone(),
+                            & #sylvia ::cw_std::Empty {},
+                            funds,
+                            label,
+                            admin,
+                        )
+                        .map_err(#sylvia ::multitest::contract_error)
+                        .map(|addr| #sylvia ::multitest::Proxy::new(addr, code_id.app))
+                }
+            }
+        }
+    }
+
+    fn is_mockable(&self) -> bool {
+        let Self {
+            source,
+            associated_types,
+            exec_variants,
+            query_variants,
+            sudo_variants,
+            ..
+        } = self;
+
+        if associated_types.without_error().next().is_some() || !source.supertraits.is_empty() {
+            return false;
+        }
+
+        let messages: Vec<_> = exec_variants
+            .variants()
+            .chain(query_variants.variants())
+            .chain(sudo_variants.variants())
+            .map(MsgVariant::function_name)
+            .collect();
+
+        source.items.iter().all(|item| match item {
+            TraitItem::Type(ty) => ty.bounds.iter().all(is_from_std_error),
+            TraitItem::Fn(method) => {
+                method.default.is_some() || messages.contains(&&method.sig.ident)
+            }
+            _ => false,
+        })
+    }
+
+    fn signature(&self, function_name: &Ident) -> Option<&'a Signature> {
+        self.source.items.iter().find_map(|item| match item {
+            TraitItem::Fn(method) if &method.sig.ident == function_name => Some(&method.sig),
+            _ => None,
+        })
+    }
+}
+
+/// Checks if the bound is `From<StdError>`, which is satisfied by the error of the mock.
+fn is_from_std_error(bound: &TypeParamBound) -> bool {
+    let TypeParamBound::Trait(bound) = bound else {
+        return false;
+    };
+    let Some(segment) = bound.path.segments.last() else {
+        return false;
+    };
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    segment.ident == "From"
+        && matches!(
+            args.args.first(),
+            Some(GenericArgument::Type(Type::Path(error)))
+                if error.path.segments.last().is_some_and(|segment| segment.ident == "StdError")
+        )
+}
+
+/// Message of the interface implemented by the generated mock.
+struct MockMethod<'a> {
+    variant: &'a MsgVariant<'a>,
+    ctx_type: &'a Type,
+    params: Vec<(&'a Ident, &'a Type)>,
+    output: Type,
+}
+
+impl<'a> MockMethod<'a> {
+    fn new(variant: &'a MsgVariant<'a>, sig: &'a Signature) -> Self {
+        let sylvia = crate_module();
+        let mut inputs = sig.inputs.iter().filter_map(|input| match input {
+            FnArg::Typed(input) => Some(input),
+            FnArg::Receiver(_) => None,
+        });
+        // The `interface` macro checks the presence of the `ctx` parameter.
+        let ctx_type = inputs.next().map(|ctx| &*ctx.ty).unwrap();
+        let params = inputs
+            .filter_map(|input| match &*input.pat {
+                Pat::Ident(pat) => Some((&pat.ident, &*input.ty)),
+                _ => None,
+            })
+            .collect();
+        let output = match &sig.output {
+            syn::ReturnType::Type(_, output) => {
+                ReplaceSelfError(parse_quote! { #sylvia ::cw_std::StdError })
+                    .fold_type(*output.clone())
+            }
+            syn::ReturnType::Default => parse_quote! { () },
+        };
+
+        Self {
+            variant,
+            ctx_type,
+            params,
+            output,
+        }
+    }
+
+    fn emit_args_type(&self) -> TokenStream {
+        let types = self.params.iter().map(|(_, ty)| ty);
+        quote! { ( #(#types,)* ) }
+    }
+
+    fn emit_state_field(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let name = self.variant.function_name();
+        let args = self.emit_args_type();
+        let output = &self.output;
+
+        quote! {
+            #name: #sylvia ::multitest::MockMethod< #args, #output >
+        }
+    }
+
+    fn emit_mock_methods(&self) -> TokenStream {
+        let name = self.variant.function_name();
+        let on_name = format_ident!("on_{}", name);
+        let calls_name = format_ident!("{}_calls", name);
+        let args = self.emit_args_type();
+        let output = &self.output;
+        let names: Vec<_> = self.params.iter().map(|(name, _)| name).collect();
+        let types = self.params.iter().map(|(_, ty)| ty);
+
+        let on_doc = format!("Sets the handler producing the response to the `{name}` message.");
+        let calls_doc =
+            format!("Arguments of the `{name}` messages received by the mock, in order.");
+
+        quote! {
+            #[doc = #on_doc]
+            pub fn #on_name (&self, handler: impl Fn( #(#types),* ) -> #output + 'static) -> &Self {
+                self.state
+                    .borrow_mut()
+                    . #name
+                    .set_handler(move |( #(#names,)* )| handler( #(#names),* ));
+                self
+            }
+
+            #[doc = #calls_doc]
+            pub fn #calls_name (&self) -> Vec< #args > {
+                self.state.borrow(). #name .calls().to_vec()
+            }
+        }
+    }
+
+    fn emit_trait_method(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let name = self.variant.function_name();
+        let ctx_type = self.ctx_type;
+        let output = &self.output;
+        let names: Vec<_> = self.params.iter().map(|(name, _)| name).collect();
+        let params = self.params.iter().map(|(name, ty)| quote! { #name: #ty });
+
+        let default = match self.variant.msg_attr().msg_type() {
+            MsgType::Query => {
+                let msg = format!("Response to the `{name}` query is not set in the mock");
+                quote! { Err(Into::into( #sylvia ::cw_std::StdError::generic_err(#msg))) }
+            }
+            _ => quote! { Ok( #sylvia ::cw_std::Response::new()) },
+        };
+
+        quote! {
+            fn #name (&self, _ctx: #ctx_type, #(#params),* ) -> #output {
+                let handler = self.state.borrow_mut(). #name .record(&( #(&#names,)* ))?;
+                match handler {
+                    Some(handler) => handler(( #(#names,)* )),
+                    None => #default,
+                }
             }
         }
     }
@@ -201,17 +619,46 @@ impl EmitMethods for MsgVariant<'_> {
         let arguments = self.as_fields_names();
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
+        let data_type = self
+            .msg_attr()
+            .data_type()
+            .map(|data_type| quote! { , #data_type });
+        let with_data = data_type.as_ref().map(|_| quote! { .with_data() });
+        let funds_policy = self.funds().map(|funds| {
+            let funds = funds.emit();
+            quote! { .with_funds_policy(#funds) }
+        });
 
         match self.msg_attr().msg_type() {
             MsgType::Exec => quote! {
                 #[track_caller]
-                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api :: #type_name, #mt_app, #custom_msg> {
+                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api :: #type_name, #mt_app, #custom_msg #data_type > {
                     let msg = #api :: #type_name :: #name ( #(#arguments),* );
 
                     #sylvia ::multitest::ExecProxy::new(&self.contract_addr, msg, &self.app)
+                        #with_data
+                        #funds_policy
                 }
             },
             MsgType::Query => {
+                let page_iter = self
+                    .emit_page_iter_signature(&quote! { #error_type })
+                    .map(|signature| {
+                        let arguments = self.emit_page_iter_arguments();
+                        quote! {
+                            #signature {
+                                #sylvia ::types::PageIter::new(move |start_after| {
+                                    let msg = #api :: #type_name :: #name ( #(#arguments),* );
+
+                                    (*self.app)
+                                        .querier()
+                                        .query_wasm_smart::< #return_type >(self.contract_addr.clone(), &msg)
+                                        .map_err(Into::into)
+                                })
+                            }
+                        }
+                    });
+
                 quote! {
                     fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type> {
                         let msg = #api :: #type_name :: #name ( #(#arguments),* );
@@ -221,6 +668,8 @@ impl EmitMethods for MsgVariant<'_> {
                             .query_wasm_smart(self.contract_addr.clone(), &msg)
                             .map_err(Into::into)
                     }
+
+                    #page_iter
                 }
             }
             MsgType::Sudo => quote! {
@@ -230,7 +679,7 @@ impl EmitMethods for MsgVariant<'_> {
                     (*self.app)
                         .app_mut()
                         .wasm_sudo(self.contract_addr.clone(), &msg)
-                        .map_err(|err| err.downcast().unwrap())
+                        .map_err(#sylvia ::multitest::contract_error)
                 }
             },
             MsgType::Migrate => quote! {
@@ -263,14 +712,33 @@ impl EmitMethods for MsgVariant<'_> {
             .collect();
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
+        let data_type = self
+            .msg_attr()
+            .data_type()
+            .map(|data_type| quote! { , #data_type });
 
-        match self.msg_attr().msg_type() {
+        let deprecated = self.emit_deprecated();
+
+        let declaration = match self.msg_attr().msg_type() {
             MsgType::Exec => quote! {
-                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api:: #type_name, MtApp, #custom_msg>;
-            },
-            MsgType::Query => quote! {
-                fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
+                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api:: #type_name, MtApp, #custom_msg #data_type >;
             },
+            MsgType::Query => {
+                let page_iter =
+                    self.emit_page_iter_signature(&quote! { #error_type })
+                        .map(|signature| {
+                            quote! {
+                                #deprecated
+                                #signature;
+                            }
+                        });
+
+                quote! {
+                    fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
+
+                    #page_iter
+                }
+            }
             MsgType::Sudo => quote! {
                 fn #name (&self, #(#params,)* ) -> Result< #sylvia ::cw_multi_test::AppResponse, #error_type>;
             },
@@ -278,7 +746,12 @@ impl EmitMethods for MsgVariant<'_> {
                 #[track_caller]
                 fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::MigrateProxy::< #error_type, #api :: #type_name, MtApp, #custom_msg>;
             },
-            _ => quote! {},
+            _ => return quote! {},
+        };
+
+        quote! {
+            #deprecated
+            #declaration
         }
     }
 }
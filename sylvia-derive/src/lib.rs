@@ -2,9 +2,11 @@
 //!
 //! Please refer to the [Sylvia-book](https://cosmwasm.github.io/sylvia-book/index.html) on how to use these macros.
 
-use crate::parser::EntryPointArgs;
+use crate::parser::{EntryPointArgs, EventArgs};
 use contract::ContractInput;
 use entry_points::EntryPointInput;
+use error_codes::ErrorCodesInput;
+use event::EventInput;
 use fold::StripInput;
 use interface::InterfaceInput;
 use proc_macro::TokenStream;
@@ -13,10 +15,12 @@ use proc_macro_error::proc_macro_error;
 use quote::quote;
 use syn::fold::Fold;
 use syn::spanned::Spanned;
-use syn::{parse2, parse_quote, ItemImpl, ItemTrait, Path};
+use syn::{parse2, parse_quote, ItemEnum, ItemImpl, ItemStruct, ItemTrait, Path};
 
 mod contract;
 mod entry_points;
+mod error_codes;
+mod event;
 mod fold;
 mod interface;
 mod parser;
@@ -111,6 +115,45 @@ pub(crate) fn crate_module() -> Path {
 ///
 /// Similarly for `Query` and `Sudo` enum messages.
 ///
+/// ## Interface id
+///
+/// Macro generates the `sv::INTERFACE_ID` constant of type `sylvia::types::InterfaceId`.
+/// It consists of the interface name and the hash of its messages signatures, so any change
+/// to the messages results in a new id.
+/// The signatures are built from the message names, field names and the JSON schema names
+/// of the field and query response types, so changes not affecting the wire format,
+/// like reordering the parameters or the methods, keep the id.
+/// The `sylvia::types::Interface` trait is implemented for the `dyn Interface` type, which
+/// allows checking if the remote contract supports the interface with
+/// `BoundQuerier::supports::<dyn Interface<...>>()`.
+///
+/// ## Mock
+///
+/// With the `mt` feature the macro generates the `sv::mt::<Interface>Mock` type implementing
+/// the interface, which can be deployed in the MultiTest in place of the remote contract
+/// with the generated `sv::mt::CodeId`. Every message gets the `on_<method>` function setting
+/// the handler of its response and the `<method>_calls` function returning the arguments of
+/// the received messages.
+/// Without the handler `exec` and `sudo` messages respond with the empty `Response`,
+/// while queries fail.
+///
+/// ```rust,ignore
+/// let mock = OracleMock::new();
+/// mock.on_price(|denom| Ok(Uint128::new(10)));
+/// let oracle = sv::mt::CodeId::store_code(&app, &mock)
+///     .instantiate()
+///     .call(&owner)
+///     .unwrap();
+///
+/// // Test the contract calling the `oracle.contract_addr`
+///
+/// assert_eq!(mock.price_calls(), [("atom".to_owned(),)]);
+/// ```
+///
+/// The mock uses `StdError` as its `Error`, so it's generated only for the interfaces without
+/// associated types other than the `Error` bound by `From<StdError>`, and without methods other
+/// than the messages or the provided ones.
+///
 /// ## Associated types
 ///
 /// Generics are not supported by the `interface` macro and won't be. Instead, you can define
@@ -187,6 +230,17 @@ pub(crate) fn crate_module() -> Path {
 /// # fn main() {}
 /// ```
 ///
+/// Query marked with `#[sv::msg(query, paginated)]` has to take the `start_after: Option<T>` and
+/// `limit: u32` parameters and return the type implementing `sylvia::types::Paginated`.
+/// The `limit` is optional in the message and clamped by the generated dispatch to the page limits,
+/// which are `default = 10` and `max = 30` unless set with `paginated(default = .., max = ..)`.
+/// Generated `Querier` trait and multitest proxy get the `<query>_iter` method iterating over
+/// the items of all the pages.
+///
+/// Exec message marked with `#[sv::msg(exec, data = T)]` declares the type of the data set in its
+/// response. The multitest proxy decodes it with `call_typed` and the generated `Executor`
+/// carries it in the returned `ExecutorBuilder`.
+///
 /// ### `sv::custom(msg=..., query=...)`
 ///
 /// Allows restricting interface to use specific
@@ -220,6 +274,96 @@ pub(crate) fn crate_module() -> Path {
 /// # fn main() {}
 /// ```
 ///
+/// ### `sv::guard(path::to::check)`
+///
+/// Can be used only next to the `sv::msg(exec)` attribute.
+/// The generated `dispatch` calls `check(&ctx, contract)` before the method
+/// and returns early if the check fails.
+/// Generic checks like `fn check<C: SvInterface>(ctx: &ExecCtx, contract: &C) -> Result<(), C::Error>`
+/// can use the interface methods.
+///
+/// ### `sv::allow_when_paused`
+///
+/// Can be used only next to the `sv::msg(exec)` attribute.
+/// Allows the message to be dispatched while the `pausable` contract is paused.
+///
+/// ### `sv::deprecated(since = "...", note = "...")`
+///
+/// Can be used next to the `sv::msg(exec)`, `sv::msg(query)` and `sv::msg(sudo)` attributes.
+/// Both parameters are optional.
+/// Marks the generated message variant, its constructor and the methods of the generated
+/// `Querier`, `Executor` and multitest proxy as `deprecated`, so callers get a warning.
+/// The message is marked as `deprecated` in the JSON schema.
+///
+/// ### `sv::alias("old_name", ...)`
+///
+/// Can be used next to the `sv::msg(exec)`, `sv::msg(query)` and `sv::msg(sudo)` attributes.
+/// The message is still accepted under its former names, which allows renaming it without
+/// breaking the existing callers. Messages are always serialized with the current name.
+///
+/// ### `sv::addr`
+///
+/// Can be used on the `Addr`, `Option<Addr>` and `Vec<Addr>` parameters of the message.
+/// The message carries the address as a `String`, which the generated dispatch validates
+/// with `deps.api` before calling the method. Invalid address results in the
+/// `sylvia::types::InvalidAddr` naming the field, converted with `From` into the error type
+/// of the message. `StdError` implements the conversion, custom error types have to implement it,
+/// e.g. with the `InvalidAddr(#[from] InvalidAddr)` variant.
+///
+/// ```rust,ignore
+/// #[sv::msg(exec)]
+/// fn set_admin(&self, ctx: ExecCtx, #[sv::addr] admin: Addr) -> StdResult<Response>;
+/// ```
+///
+/// ### `sv::validate(...)`
+///
+/// Can be used on the parameters of the message. The generated dispatch checks the rules
+/// before calling the method and returns the `StdError` naming the invalid parameter.
+/// The parameters are validated after the funds policy and the guards are checked.
+/// Supported rules:
+/// - `min = expr` and `max = expr` - bounds of the value,
+/// - `length(min = expr, max = expr)` - length of the `String`, `Vec` or `Binary`,
+/// - `non_empty` - shorthand for `length(min = 1)`,
+/// - `chars(alphanumeric, "-_")` - characters allowed in the `String`; classes `alphabetic`,
+///   `numeric`, `alphanumeric`, `lowercase` and `uppercase` cover the ASCII characters,
+/// - `custom = path::to::check` - function called with the reference to the parameter
+///   and returning the `Result<(), E>`, with `E` convertible into the error type of the message.
+///
+/// For the `Option` parameters the rules are checked only if the value is present.
+/// Bounds given as literals and the allowed characters are also emitted into the JSON schema
+/// as `minimum`, `maximum`, `minLength`, `maxLength`, `minItems`, `maxItems` and `pattern`.
+/// Length of the `Binary` is checked in bytes, so in the schema it's converted to the length
+/// of its base64 string.
+///
+/// ```rust,ignore
+/// #[sv::msg(instantiate)]
+/// fn instantiate(
+///     &self,
+///     ctx: InstantiateCtx,
+///     #[sv::validate(length(min = 3, max = 12), chars(alphabetic, "-"))] symbol: String,
+///     #[sv::validate(max = 18)] decimals: u8,
+/// ) -> StdResult<Response>;
+/// ```
+///
+/// ### `sv::events(...)`
+///
+/// Can be used next to the `sv::msg(exec)`, `sv::msg(sudo)`, `sv::msg(instantiate)` and
+/// `sv::msg(migrate)` attributes to declare the [events](macro@crate::event) the method may emit.
+/// Declared events are listed in the description of the message in the schema and returned by
+/// the generated `sv::message_events()` function.
+///
+/// ```rust,ignore
+/// #[sv::msg(exec)]
+/// #[sv::events(Transfer, Burn)]
+/// fn transfer(&self, ctx: ExecCtx, recipient: String, amount: Uint128) -> StdResult<Response>;
+/// ```
+///
+/// ### `sv::funds(...)`
+///
+/// Sets the funds policy of the `exec` method, or the default one if used on the trait.
+/// Works the same as for the contract: `nonpayable`, `payable`, `payable(denom)`
+/// and `must_pay(denom)` are supported.
+///
 /// ### `sv::msg_attr(msg_type, {...})`
 ///
 /// This attribute can be used for the whole `trait Interface {}` block and
@@ -416,6 +560,16 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 ///
 /// And appropriate messages for `exec`, `query`, `migrate`, `reply` and `sudo` variants.
 ///
+/// ## Arbitrary messages
+///
+/// With the `arbitrary` feature the macro implements `arbitrary::Arbitrary` for the messages
+/// of the non-generic contracts, including the `ContractExecMsg` and the other glue messages.
+/// Fields are generated with `sylvia::fuzz::ArbitraryField`, so the `Addr` fields, including
+/// the `sv::addr` ones, are one of the `sylvia::fuzz::account`s and the amounts don't overflow
+/// on the first addition. The `exec` messages implement `sylvia::fuzz::ExecFunds` returning
+/// the funds policy of the called method.
+/// The `interface` macro does the same for the non-generic interface messages.
+///
 /// ## Attributes
 ///
 /// `Contract` macro supports multiple attributes to customize the behavior of generated messages.
@@ -431,6 +585,14 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// * `migrate` - migrate message variant
 /// * `reply` - reply message variant
 /// * `sudo` - sudo message variant
+/// * `ibc_channel_open`, `ibc_channel_connect`, `ibc_channel_close`, `ibc_packet_receive`,
+///     `ibc_packet_ack` and `ibc_packet_timeout` - IBC handlers. There can be at most one
+///     handler of each kind. Handler takes the matching context from `sylvia::ctx`
+///     (e.g. `IbcChannelOpenCtx`) and the `cosmwasm_std` IBC message as parameters.
+/// * `ibc_packet` - IBC packet variant. The `IbcPacketMsg` is generated from these methods
+///     together with the `IbcPacketSender` trait implemented on `sylvia::types::IbcPacketBuilder`.
+///     Handler takes `IbcPacketCtx` as its context. It cannot be combined with the `ibc_packet_receive`
+///     handler.
 ///
 /// In the case of a query, it is possible to pass a second argument which is its `ResponseType`.
 /// This is required in case of aliased results wrapping their `ResponseType` to properly
@@ -480,6 +642,65 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// Query marked with `#[sv::msg(query, paginated)]` takes the `start_after: Option<T>` and
+/// `limit: u32` parameters. The `limit` is optional in the message and clamped by the generated
+/// dispatch to the page limits, which are `default = 10` and `max = 30` unless set with
+/// `paginated(default = .., max = ..)`. The query has to return the type implementing
+/// `sylvia::types::Paginated`, e.g. the `sylvia::types::Page`.
+/// Generated `Querier` trait and multitest proxy get the `<query>_iter` method iterating over
+/// the items of all the pages.
+///
+/// ```rust
+/// # use sylvia::ctx::{InstantiateCtx, QueryCtx};
+/// # use sylvia::cw_std::{Order, Response, StdResult};
+/// # use sylvia::types::Page;
+/// # use cw_storage_plus::{Bound, Map};
+/// #
+/// pub struct Group {
+///     members: Map<String, u64>,
+/// }
+///
+/// ##[sylvia::contract]
+/// impl Group {
+///     pub const fn new() -> Self {
+///         Self {
+///             members: Map::new("members"),
+///         }
+///     }
+///
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(query, paginated(default = 10, max = 50))]
+///     fn list_members(
+///         &self,
+///         ctx: QueryCtx,
+///         start_after: Option<String>,
+///         limit: u32,
+///     ) -> StdResult<Page<(String, u64), String>> {
+///         let members = self
+///             .members
+///             .range(ctx.deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+///             .take(limit as usize)
+///             .collect::<StdResult<_>>()?;
+///         Ok(Page::from_items(members, limit, |(name, _)| name.clone()))
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Exec message marked with `#[sv::msg(exec, data = T)]` declares the type of the data set in its
+/// response with `Response::set_data`. `ExecProxy` returned by the generated multitest proxy
+/// decodes it with `call_typed`, returning the data together with the emitted events. Missing data
+/// is accepted only if `T` is an `Option`.
+///
+/// The `ExecutorBuilder` returned by the generated `Executor` carries `T`, and can be converted
+/// straight into the submessage with the `SubMsgMethods` of the calling contract. It's accepted
+/// only by the reply handlers decoding the data to `T` with `#[sv::data]` or `#[sv::data(exec = T)]`,
+/// so the mismatch is reported at compile time.
+///
 /// ### `sv::custom(msg=..., query=...)`
 ///
 /// Allows restricting interface to use specific
@@ -490,6 +711,130 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 ///
 /// Allows specifing custom error type for the contract. Default is `cosmwasm_std::StdError`.
 ///
+/// With `sv::error(ContractError, codes)` the entry points return the errors together with their
+/// codes, so the clients can decode them back. The error has to be marked with
+/// [`error_codes`](macro@crate::error_codes).
+///
+/// ### `sv::guard(path::to::check)`
+///
+/// Can be used only next to the `sv::msg(exec)` attribute. The generated `dispatch` calls
+/// every guard in the order of declaration before the method, passing the `&ExecCtx`
+/// and the contract. The check should return `Result<(), E>` where the contract error
+/// implements `From<E>`. In case of an error the method is not called.
+///
+/// ```rust
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Response, StdError, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// fn only_admin(ctx: &ExecCtx, contract: &SvContract) -> StdResult<()> {
+/// #    Ok(())
+/// }
+///
+/// ##[sylvia::contract]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(exec)]
+///     #[sv::guard(only_admin)]
+///     fn update_config(&self, ctx: ExecCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::funds(...)`
+///
+/// Sets the funds policy of the `exec` or `instantiate` method. Used on the contract
+/// it sets the default policy for all its `exec` and `instantiate` methods.
+/// Supported policies are:
+/// * `nonpayable` - rejects any funds,
+/// * `payable` - accepts any funds, used to override the contract default,
+/// * `payable(denom)` - accepts funds only in the given denom,
+/// * `must_pay(denom)` - requires non zero funds in the given denom.
+///
+/// The generated `dispatch` checks the funds before calling the method and returns
+/// the `cosmwasm_std::StdError` converted into the contract error if the policy isn't satisfied.
+/// The policy is added to the message description in the generated schema and
+/// listed by the generated `sv::funds_policies()` function.
+/// MultiTest proxies panic if funds are sent to the `nonpayable` method.
+///
+/// ```rust
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Response, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// ##[sv::funds(nonpayable)]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec)]
+///     #[sv::funds(must_pay("uatom"))]
+///     fn deposit(&self, ctx: ExecCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::version(name=..., version=...)`
+///
+/// Enables [cw2](https://crates.io/crates/cw2) compatible version tracking.
+/// Both parameters are `&'static str` expressions exposed as `sv::CONTRACT_NAME` and `sv::CONTRACT_VERSION`.
+///
+/// The generated instantiate dispatch saves the contract name and version before calling
+/// the `instantiate` method. The generated migrate dispatch returns an error if the stored
+/// contract name differs or the stored version is newer than the migrated one, and otherwise
+/// updates the stored version before calling the `migrate` method.
+/// The contract error type has to implement `From<cosmwasm_std::StdError>`.
+///
+/// In the multitest the stored version can be checked with
+/// `Proxy::contract_version` and `Proxy::assert_contract_version`.
+///
+/// ```rust
+/// # use sylvia::ctx::{InstantiateCtx, MigrateCtx};
+/// # use sylvia::cw_std::{Response, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// ##[sv::version(name = "crates.io:sv-contract", version = env!("CARGO_PKG_VERSION"))]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+///     }
+///
+///     #[sv::msg(migrate)]
+///     fn migrate(&self, ctx: MigrateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ### `sv::override_entry_point(entry_point_type=<path_to_entry_point(msg_path)>`
 ///
 /// Allows overriding default entry point for specific message type.
@@ -609,6 +954,12 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// Ids of the interfaces declared with `sv::messages` are listed in the generated
+/// `sv::INTERFACE_IDS` constant.
+/// Contract using the `supported_interfaces` feature handles the `supported_interfaces` query
+/// returning them as strings.
+/// Compilation fails if two of the interfaces share the same id.
+///
 /// ### `sv::msg_attr(msg_type, {...})`
 ///
 /// This attribute can be used for the whole `impl Contract {}` block and
@@ -637,8 +988,99 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 ///     Based on this parameters reply ids will be generated and associated with
 ///     proper scenario specified by the `reply_on` parameter.
 ///
+///     By default reply ids are assigned in order of the handlers definition.
+///     To keep them stable between the contract versions, the reply id can be pinned
+///     with the `id` parameter, either to an explicit value `#[sv::msg(reply, id = 42)]`
+///     or to the hash of the handler name `#[sv::msg(reply, handlers=[scenario1], id = hash)]`
+///     (see `sylvia::utils::reply_id_hash`). Colliding reply ids are reported as a compile error.
+///     Generated `sv::reply_ids()` returns every reply id paired with its handler name.
+///
 ///     User can also specify custom `data` and `payload` types that will be auto
 ///     deserialized from the `cosmwasm_std::Binary` type.
+/// * `pausable` - the generated `ContractExecMsg::dispatch` rejects all `exec` messages,
+///     including the interface ones, while the contract is paused.
+///     Messages marked with `sv::allow_when_paused` are still dispatched.
+///     The paused state is managed with the `sylvia::pausable::Pausable` interface,
+///     which has to be listed with `#[sv::messages(sylvia::pausable as Pausable)]`.
+///     Its default `ensure_pauser` requires the owner set with `sylvia::ownable::initialize_owner`.
+/// * `supported_interfaces` - adds the `supported_interfaces` query to the `ContractQueryMsg`.
+///     It returns the ids of the interfaces listed with `sv::messages`.
+///
+/// ### `sv::allow_when_paused`
+///
+/// Can be used only next to the `sv::msg(exec)` attribute.
+/// Allows the message to be dispatched while the `pausable` contract is paused.
+///
+/// ### `sv::deprecated(since = "...", note = "...")`
+///
+/// Can be used next to the `sv::msg(exec)`, `sv::msg(query)` and `sv::msg(sudo)` attributes.
+/// Both parameters are optional.
+/// Marks the generated message variant, its constructor and the methods of the generated
+/// `Querier`, `Executor` and multitest proxy as `deprecated`, so callers get a warning.
+/// The message is marked as `deprecated` in the JSON schema.
+///
+/// ### `sv::alias("old_name", ...)`
+///
+/// Can be used next to the `sv::msg(exec)`, `sv::msg(query)` and `sv::msg(sudo)` attributes.
+/// The message is still accepted under its former names, which allows renaming it without
+/// breaking the existing callers. Messages are always serialized with the current name.
+///
+/// ### `sv::addr`
+///
+/// Can be used on the `Addr`, `Option<Addr>` and `Vec<Addr>` parameters of the message.
+/// The message carries the address as a `String`, which the generated dispatch validates
+/// with `deps.api` before calling the method. Invalid address results in the
+/// `sylvia::types::InvalidAddr` naming the field, converted with `From` into the error type
+/// of the message. `StdError` implements the conversion, custom error types have to implement it,
+/// e.g. with the `InvalidAddr(#[from] InvalidAddr)` variant.
+///
+/// ```rust,ignore
+/// #[sv::msg(exec)]
+/// fn set_admin(&self, ctx: ExecCtx, #[sv::addr] admin: Addr) -> StdResult<Response>;
+/// ```
+///
+/// ### `sv::validate(...)`
+///
+/// Can be used on the parameters of the message. The generated dispatch checks the rules
+/// before calling the method and returns the `StdError` naming the invalid parameter.
+/// The parameters are validated after the funds policy and the guards are checked.
+/// Supported rules:
+/// - `min = expr` and `max = expr` - bounds of the value,
+/// - `length(min = expr, max = expr)` - length of the `String`, `Vec` or `Binary`,
+/// - `non_empty` - shorthand for `length(min = 1)`,
+/// - `chars(alphanumeric, "-_")` - characters allowed in the `String`; classes `alphabetic`,
+///   `numeric`, `alphanumeric`, `lowercase` and `uppercase` cover the ASCII characters,
+/// - `custom = path::to::check` - function called with the reference to the parameter
+///   and returning the `Result<(), E>`, with `E` convertible into the error type of the message.
+///
+/// For the `Option` parameters the rules are checked only if the value is present.
+/// Bounds given as literals and the allowed characters are also emitted into the JSON schema
+/// as `minimum`, `maximum`, `minLength`, `maxLength`, `minItems`, `maxItems` and `pattern`.
+/// Length of the `Binary` is checked in bytes, so in the schema it's converted to the length
+/// of its base64 string.
+///
+/// ```rust,ignore
+/// #[sv::msg(instantiate)]
+/// fn instantiate(
+///     &self,
+///     ctx: InstantiateCtx,
+///     #[sv::validate(length(min = 3, max = 12), chars(alphabetic, "-"))] symbol: String,
+///     #[sv::validate(max = 18)] decimals: u8,
+/// ) -> StdResult<Response>;
+/// ```
+///
+/// ### `sv::events(...)`
+///
+/// Can be used next to the `sv::msg(exec)`, `sv::msg(sudo)`, `sv::msg(instantiate)` and
+/// `sv::msg(migrate)` attributes to declare the [events](macro@crate::event) the method may emit.
+/// Declared events are listed in the description of the message in the schema and returned by
+/// the generated `sv::message_events()` function.
+///
+/// ```rust,ignore
+/// #[sv::msg(exec)]
+/// #[sv::events(Transfer, Burn)]
+/// fn transfer(&self, ctx: ExecCtx, recipient: String, amount: Uint128) -> StdResult<Response>;
+/// ```
 ///
 /// ### `sv::payload(raw)`
 ///
@@ -647,6 +1089,38 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// Used next to the reply method argument. It disables auto deserialization
 /// of the payload argument.
 ///
+/// Without this attribute, every parameter following the `result`/`error`/`data` parameter
+/// is treated as a typed payload. The generated `SubMsgMethods` methods take these parameters,
+/// serialize them into the `SubMsg::payload` and `dispatch_reply` deserializes them back before
+/// calling the handler. In case the payload doesn't match the handler parameters, `dispatch_reply`
+/// returns an error with the reply id and the expected payload type.
+///
+/// ```rust
+/// # use sylvia::ctx::{InstantiateCtx, ReplyCtx};
+/// # use sylvia::cw_std::{Response, StdResult, SubMsgResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// ##[sv::features(replies)]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(reply, reply_on=always)]
+///     fn reply(&self, ctx: ReplyCtx, result: SubMsgResult, amount: u128, recipient: String) -> StdResult<Response> {
+/// #        Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ### `sv::data(...)`
 ///
 /// Requires contract to be marked with the `sv::features(replies)`.
@@ -698,8 +1172,27 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// |---|---|---|
 /// | forwards `Some(valid)` | early returns error specifying what went wrong with `serde` error attached | Forwards `None` |
 ///
+/// * `#[sv::data(instantiate = T)]` - special case for reply to `WasmMsg::instantiate` and
+///     `WasmMsg::instantiate2`. Expects data in the method signature to be
+///     `data: sylvia::types::InstantiateReplyData<T> where T: Deserialize`.
+///     Extracts address of the instantiated contract and deserializes the data set by it to `T`.
+///     Can be paired with `opt` in which case `data` should be `Option<InstantiateReplyData<T>>`.
+///
+/// If `data` is:
+/// | `Some(valid)` | `Some(invalid)` | `None` or contract set no data |
+/// |---|---|---|
+/// | forwards `valid` | early returns error specifying what went wrong with `serde` error attached | early returns error specifying the `data` is missing |
+///
+/// * `#[sv::data(exec = T)]` - explicit variant of `#[sv::data]` for reply to `WasmMsg::Execute`.
+///     Deserializes the data set by the executed contract to `T`.
+///     Can be paired with `opt` in which case `data` should be `Option<T>`.
+///
 /// * Missing `#[sv::data(...)]` - In case `sv::data` is not found Sylvia won't forward the `data` argument
 ///     so the `data` should be omited in the method signature.
+///
+/// Except for the `raw` variants, if the `data` field of the reply is `None`, the data is
+/// extracted from the matching `msg_responses` entry as it's the only place the data is set
+/// since CosmWasm 2.0.
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -779,6 +1272,77 @@ fn contract_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// ## IBC
+///
+/// The `ibc_*` entry points are generated for every IBC handler defined in the contract.
+/// Exporting them to the wasm binary requires the `stargate` feature of `sylvia`.
+///
+/// ```rust
+/// # use sylvia::ctx::{IbcChannelOpenCtx, IbcPacketReceiveCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketReceiveMsg};
+/// # use sylvia::cw_std::{IbcReceiveResponse, Response, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::entry_points]
+/// ##[sylvia::contract]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(ibc_channel_open)]
+///     fn channel_open(&self, ctx: IbcChannelOpenCtx, msg: IbcChannelOpenMsg) -> StdResult<IbcChannelOpenResponse> {
+/// #        Ok(None)
+///     }
+///
+///     #[sv::msg(ibc_packet_receive)]
+///     fn packet_receive(&self, ctx: IbcPacketReceiveCtx, msg: IbcPacketReceiveMsg) -> StdResult<IbcReceiveResponse> {
+/// #        Ok(IbcReceiveResponse::without_ack())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// If the contract defines `ibc_packet` messages, the `ibc_packet_receive` entry point decodes
+/// the packet data into the `IbcPacketMsg` and dispatches it to the respective handler.
+/// Result of the handler is written as the `sylvia::types::PacketAck`. Handler returning
+/// `sylvia::types::PacketResponse` sends its messages and attributes with the acknowledgement.
+/// Packets that cannot be decoded are acknowledged with the `sylvia::types::PacketAck::Error`.
+/// Errors of the handler are returned from the entry point, so the chain reverts the state
+/// changes of the handler and writes the error acknowledgement itself.
+///
+/// ```rust
+/// # use sylvia::ctx::{IbcPacketCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Response, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::entry_points]
+/// ##[sylvia::contract]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(ibc_packet)]
+///     fn transfer(&self, ctx: IbcPacketCtx, recipient: String, amount: u128) -> StdResult<u128> {
+///         Ok(amount)
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ## Generics
 ///
 /// `Cosmwasm` entry point has to be implemented with concrete types.
@@ -844,6 +1408,121 @@ fn entry_points_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
     inner(attr, item).unwrap_or_else(syn::Error::into_compile_error)
 }
 
+/// Macro implementing the conversion of the structure from and into the `cosmwasm_std::Event`.
+///
+/// ## Example usage
+/// ```rust
+/// # use sylvia::cw_std::{Addr, Response, Uint128};
+/// use sylvia::events::AddTypedEvent;
+///
+/// #[sylvia::event(name = "transfer")]
+/// pub struct Transfer {
+///     pub recipient: Addr,
+///     pub amount: Uint128,
+///     pub memo: Option<String>,
+/// }
+///
+/// let resp: Response = Response::new().add_typed_event(Transfer {
+///     recipient: Addr::unchecked("recipient"),
+///     amount: Uint128::new(100),
+///     memo: None,
+/// });
+/// ```
+///
+/// Every field is stored as the event attribute under the field name, with `None` values
+/// skipped. Field types have to implement `sylvia::events::EventAttribute`.
+/// The event type defaults to the snake cased name of the structure.
+///
+/// The macro generates:
+///     - `sylvia::events::TypedEvent` implementation with the `EVENT_TYPE` constant
+///     - `From<Transfer> for cosmwasm_std::Event` implementation
+///
+/// The chain prefixes the type of the events emitted by the contract with `wasm-`.
+/// Decoding with `TypedEvent::from_event` accepts both forms, and `sylvia::multitest::TypedEvents`
+/// allows extracting the typed events from the `AppResponse`.
+///
+/// Methods of the contracts and interfaces can declare the events they emit with the
+/// `sv::events(...)` attribute.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
+    event_impl(attr.into(), item.into()).into()
+}
+
+fn event_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    fn inner(attr: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+        let input: ItemStruct = parse2(item)?;
+        let args = EventArgs::new(&attr)?;
+        let expanded = EventInput::new(&input, args).process();
+
+        Ok(quote! {
+            #input
+
+            #expanded
+        })
+    }
+
+    inner(attr, item).unwrap_or_else(syn::Error::into_compile_error)
+}
+
+/// Macro assigning the stable codes to the variants of the contract error enum.
+///
+/// ## Example usage
+/// ```rust
+/// # use sylvia::cw_std::{StdError, Uint128};
+/// use sylvia::errors::ErrorCodes;
+///
+/// #[sylvia::error_codes]
+/// #[derive(thiserror::Error, Debug, PartialEq)]
+/// pub enum ContractError {
+///     #[error("{0}")]
+///     #[sv::code(1)]
+///     Std(#[from] StdError),
+///
+///     #[error("Unauthorized")]
+///     #[sv::code(2)]
+///     Unauthorized,
+///
+///     #[error("Insufficient funds, needed {needed}")]
+///     InsufficientFunds { needed: Uint128 },
+/// }
+///
+/// assert_eq!(ContractError::Unauthorized.code(), 2);
+/// ```
+///
+/// The code is set with the `sv::code(...)` attribute or defaults to the hash of the variant name
+/// (see `sylvia::utils::error_code_hash`), so it doesn't change when the variants are reordered.
+/// Codes have to be unique, colliding codes are reported as a compile error.
+///
+/// The macro generates the `sylvia::errors::ErrorCodes` implementation, which encodes the fields
+/// of the variant so the error can be decoded back. Fields have to implement `serde::Serialize`
+/// and `serde::Deserialize`, except for the `StdError` stored as its message. Variants with
+/// other source errors, marked with `#[from]` or `#[source]`, only carry their code.
+///
+/// Contract declaring its error with `#[sv::error(ContractError, codes)]` returns the errors
+/// together with their codes from the entry points. They can be decoded with
+/// `sylvia::errors::DecodeError` out of the failed transaction result.
+/// On chain `wasmd` redacts the errors received by the reply handler and returned by the
+/// `BoundQuerier`, so decoding them works only in multitest.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn error_codes(attr: TokenStream, item: TokenStream) -> TokenStream {
+    error_codes_impl(attr.into(), item.into()).into()
+}
+
+fn error_codes_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    fn inner(attr: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+        if !attr.is_empty() {
+            return Err(syn::Error::new(attr.span(), "Unexpected tokens."));
+        }
+
+        let input: ItemEnum = parse2(item)?;
+        Ok(ErrorCodesInput::new(&input).process())
+    }
+
+    inner(attr, item).unwrap_or_else(syn::Error::into_compile_error)
+}
+
 #[cfg(test)]
 mod test {
     use std::{env, fs};
@@ -0,0 +1,37 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{Error, LitStr, MetaList, Result, Token};
+
+/// Type wrapping data parsed from `sv::alias` attribute.
+#[derive(Clone, Debug)]
+pub struct AliasAttr {
+    pub aliases: Vec<LitStr>,
+}
+
+impl AliasAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        AliasAttr::parse.parse2(attr.tokens.clone()).map_err(|err| {
+            emit_error!(err.span(), err);
+            err
+        })
+    }
+}
+
+impl Parse for AliasAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let aliases: Vec<_> = Punctuated::<LitStr, Token![,]>::parse_terminated(input)?
+            .into_iter()
+            .collect();
+
+        if aliases.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "Missing aliases.\n
+  = note: Expected `#[sv::alias(\"old_name\", ...)]`.\n",
+            ));
+        }
+
+        Ok(Self { aliases })
+    }
+}
@@ -63,9 +63,10 @@ impl Parse for MsgAttrForwarding {
             "migrate" => MsgType::Migrate,
             "reply" => MsgType::Reply,
             "sudo" => MsgType::Sudo,
+            "ibc_packet" => MsgType::IbcPacket,
             _ => return Err(Error::new(
                 msg_type.span(),
-                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply` or `sudo`.",
+                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply`, `sudo` or `ibc_packet`.",
             ))
         };
         Ok(Self { msg_type, attrs })
@@ -0,0 +1,38 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{Error, LitInt, MetaList, Result};
+
+/// Type wrapping data parsed from `sv::code` attribute.
+#[derive(Clone, Debug)]
+pub struct CodeAttr {
+    pub code: u32,
+}
+
+impl CodeAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        CodeAttr::parse.parse2(attr.tokens.clone()).map_err(|err| {
+            emit_error!(err.span(), err);
+            err
+        })
+    }
+}
+
+impl Parse for CodeAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let invalid_code = |err: Error| {
+            Error::new(
+                err.span(),
+                "Invalid code parameter.\n
+  = note: Expected `#[sv::code(42)]`.\n",
+            )
+        };
+        let lit: LitInt = input.parse().map_err(invalid_code)?;
+        let code = lit.base10_parse().map_err(invalid_code)?;
+
+        if !input.is_empty() {
+            return Err(Error::new(input.span(), "Unexpected tokens."));
+        }
+
+        Ok(Self { code })
+    }
+}
@@ -1,7 +1,7 @@
 use proc_macro_error::emit_error;
 use syn::parse::{Parse, ParseStream, Parser};
 use syn::spanned::Spanned;
-use syn::{Error, Ident, MetaList, Result, Token};
+use syn::{Error, Ident, MetaList, Result, Token, Type};
 
 /// Type wrapping data parsed from `sv::data` attribute.
 #[derive(Default, Debug)]
@@ -9,6 +9,10 @@ pub struct DataFieldParams {
     pub raw: bool,
     pub opt: bool,
     pub instantiate: bool,
+    pub exec: bool,
+    /// Type of the data set by the instantiated or executed contract,
+    /// i.e. `T` in `instantiate = T` or `exec = T`.
+    pub inner_type: Option<Type>,
 }
 
 impl DataFieldParams {
@@ -24,12 +28,30 @@ impl DataFieldParams {
             emit_error!(
                 attr.tokens.span(),
                 "The `instantiate` cannot be used in pair with `raw` parameter.";
-                note = "Use any combination of [`raw`, `opt`] or [`instantiate`, `opt`] pairs."
+                note = "Use any combination of [`raw`, `opt`], [`instantiate`, `opt`] or [`exec`, `opt`] pairs."
+            );
+        }
+
+        if data.exec && (data.raw || data.instantiate) {
+            emit_error!(
+                attr.tokens.span(),
+                "The `exec` cannot be used in pair with `raw` or `instantiate` parameter.";
+                note = "Use any combination of [`raw`, `opt`], [`instantiate`, `opt`] or [`exec`, `opt`] pairs."
             );
         }
 
         Ok(data)
     }
+
+    /// Parses optional `= T` following the `instantiate` and `exec` parameters.
+    fn parse_inner_type(input: ParseStream) -> Result<Option<Type>> {
+        if !input.peek(Token![=]) {
+            return Ok(None);
+        }
+
+        let _: Token![=] = input.parse()?;
+        input.parse().map(Some)
+    }
 }
 
 impl Parse for DataFieldParams {
@@ -41,12 +63,19 @@ impl Parse for DataFieldParams {
             match option.to_string().as_str() {
                 "raw" => data.raw = true,
                 "opt" => data.opt = true,
-                "instantiate" => data.instantiate = true,
+                "instantiate" => {
+                    data.instantiate = true;
+                    data.inner_type = Self::parse_inner_type(input)?;
+                }
+                "exec" => {
+                    data.exec = true;
+                    data.inner_type = Self::parse_inner_type(input)?;
+                }
                 _ => {
                     return Err(Error::new(
                         option.span(),
                         "Invalid data parameter.\n
-  = note: Expected one of [`raw`, `opt`, `instantiate`] comma separated.\n",
+  = note: Expected one of [`raw`, `opt`, `instantiate`, `exec`] comma separated.\n",
                     ))
                 }
             }
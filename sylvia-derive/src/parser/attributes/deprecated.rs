@@ -0,0 +1,76 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Error, Ident, LitStr, MetaList, Result, Token};
+
+/// Type wrapping data parsed from `sv::deprecated` attribute.
+#[derive(Clone, Debug)]
+pub struct DeprecatedAttr {
+    pub span: Span,
+    pub since: Option<LitStr>,
+    pub note: Option<LitStr>,
+}
+
+impl DeprecatedAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        DeprecatedAttr::parse
+            .parse2(attr.tokens.clone())
+            .map(|deprecated| Self {
+                span: attr.path.span(),
+                ..deprecated
+            })
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+
+    /// Creates the attribute used without parameters.
+    pub fn empty(span: Span) -> Self {
+        Self {
+            span,
+            since: None,
+            note: None,
+        }
+    }
+
+    /// Emits the Rust `deprecated` attribute.
+    pub fn emit(&self) -> TokenStream {
+        let since = self.since.as_ref().map(|since| quote! { since = #since });
+        let note = self.note.as_ref().map(|note| quote! { note = #note });
+        let params = since.into_iter().chain(note);
+
+        quote! {
+            #[deprecated( #(#params),* )]
+        }
+    }
+}
+
+impl Parse for DeprecatedAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut deprecated = Self::empty(input.span());
+
+        while !input.is_empty() {
+            let param: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            match param.to_string().as_str() {
+                "since" => deprecated.since = Some(input.parse()?),
+                "note" => deprecated.note = Some(input.parse()?),
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "Invalid deprecated parameter.\n
+  = note: Expected `#[sv::deprecated(since = \"..\", note = \"..\")]`.\n",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+
+        Ok(deprecated)
+    }
+}
@@ -1,6 +1,6 @@
 use proc_macro_error::emit_error;
-use syn::parse::{Parse, ParseStream, Parser};
-use syn::{parse_quote, MetaList, Result, Type};
+use syn::parse::{Error, Parse, ParseStream, Parser};
+use syn::{parse_quote, Ident, MetaList, Result, Token, Type};
 
 use crate::crate_module;
 
@@ -8,6 +8,9 @@ use crate::crate_module;
 #[derive(Debug)]
 pub struct ContractErrorAttr {
     pub error: Type,
+    /// Whether the error is encoded with its code by the generated entry points,
+    /// i.e. `#[sv::error(ContractError, codes)]`.
+    pub codes: bool,
 }
 
 impl Default for ContractErrorAttr {
@@ -15,6 +18,7 @@ impl Default for ContractErrorAttr {
         let sylvia = crate_module();
         Self {
             error: parse_quote! { #sylvia ::cw_std::StdError },
+            codes: false,
         }
     }
 }
@@ -32,6 +36,24 @@ impl ContractErrorAttr {
 
 impl Parse for ContractErrorAttr {
     fn parse(input: ParseStream) -> Result<Self> {
-        input.parse().map(|error| Self { error })
+        let error = input.parse()?;
+        if input.is_empty() {
+            return Ok(Self {
+                error,
+                codes: false,
+            });
+        }
+
+        let _: Token![,] = input.parse()?;
+        let codes: Ident = input.parse()?;
+        if codes != "codes" || !input.is_empty() {
+            return Err(Error::new(
+                codes.span(),
+                "Invalid error parameter.\n
+  = note: Expected `#[sv::error(ContractError)]` or `#[sv::error(ContractError, codes)]`.\n",
+            ));
+        }
+
+        Ok(Self { error, codes: true })
     }
 }
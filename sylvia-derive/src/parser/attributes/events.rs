@@ -0,0 +1,39 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{Error, MetaList, Path, Result, Token};
+
+/// Type wrapping data parsed from `sv::events` attribute.
+#[derive(Clone, Debug)]
+pub struct EventsAttr {
+    pub events: Vec<Path>,
+}
+
+impl EventsAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        EventsAttr::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+}
+
+impl Parse for EventsAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let events: Vec<_> = Punctuated::<Path, Token![,]>::parse_terminated(input)?
+            .into_iter()
+            .collect();
+
+        if events.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "Missing events.\n
+  = note: Expected `#[sv::events(path::to::Event, ...)]`.\n",
+            ));
+        }
+
+        Ok(Self { events })
+    }
+}
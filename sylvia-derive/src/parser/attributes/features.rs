@@ -7,6 +7,10 @@ use syn::{Error, Ident, MetaList, Result, Token};
 pub struct SylviaFeatures {
     /// Enables better dispatching and deserialization for replies.
     pub replies: bool,
+    /// Rejects `exec` messages while the contract is paused.
+    pub pausable: bool,
+    /// Adds the `supported_interfaces` query listing the ids of the implemented interfaces.
+    pub supported_interfaces: bool,
 }
 
 impl SylviaFeatures {
@@ -28,10 +32,12 @@ impl Parse for SylviaFeatures {
             let feature: Ident = input.parse()?;
             match feature.to_string().as_str() {
                 "replies" => features.replies = true,
+                "pausable" => features.pausable = true,
+                "supported_interfaces" => features.supported_interfaces = true,
                 _ => {
                     return Err(Error::new(
                         feature.span(),
-                        "Invalid feature.\n= note: Supported features for contract macro: [`replies`].\n",
+                        "Invalid feature.\n= note: Supported features for contract macro: [`replies`, `pausable`, `supported_interfaces`].\n",
                     ))
                 }
             }
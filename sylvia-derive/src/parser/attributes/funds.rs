@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{parenthesized, Error, Expr, ExprLit, Ident, Lit, MetaList, Result};
+
+use crate::crate_module;
+
+/// Type wrapping data parsed from `sv::funds` attribute.
+#[derive(Clone, Debug)]
+pub struct FundsPolicyAttr {
+    /// One of `nonpayable`, `payable` or `must_pay`.
+    pub policy: Ident,
+    pub denom: Option<Expr>,
+}
+
+impl FundsPolicyAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        FundsPolicyAttr::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+
+    /// Emits `sylvia::types::FundsPolicy` constructed from the attribute.
+    pub fn emit(&self) -> TokenStream {
+        let sylvia = crate_module();
+
+        match (self.policy.to_string().as_str(), &self.denom) {
+            ("nonpayable", _) => quote! { #sylvia ::types::FundsPolicy::Nonpayable },
+            ("must_pay", Some(denom)) => quote! { #sylvia ::types::FundsPolicy::MustPay(#denom) },
+            (_, Some(denom)) => quote! { #sylvia ::types::FundsPolicy::Payable(Some(#denom)) },
+            (_, None) => quote! { #sylvia ::types::FundsPolicy::Payable(None) },
+        }
+    }
+
+    /// Emits the `doc` attribute describing the policy.
+    /// It ends up as a description of the message in the generated schema.
+    pub fn emit_doc(&self) -> TokenStream {
+        let doc = match (self.policy.to_string().as_str(), &self.denom) {
+            ("nonpayable", _) => "Funds policy: does not accept funds.".to_owned(),
+            ("must_pay", Some(denom)) => {
+                format!("Funds policy: requires `{}` funds.", denom_name(denom))
+            }
+            (_, Some(denom)) => {
+                format!("Funds policy: accepts only `{}` funds.", denom_name(denom))
+            }
+            (_, None) => "Funds policy: accepts any funds.".to_owned(),
+        };
+
+        quote! { #[doc = #doc] }
+    }
+
+    pub fn is_nonpayable(&self) -> bool {
+        self.policy == "nonpayable"
+    }
+}
+
+fn denom_name(denom: &Expr) -> String {
+    match denom {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(denom),
+            ..
+        }) => denom.value(),
+        denom => quote! { #denom }.to_string(),
+    }
+}
+
+fn parse_denom(input: ParseStream) -> Result<Expr> {
+    let content;
+    parenthesized!(content in input);
+    content.parse()
+}
+
+impl Parse for FundsPolicyAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let policy: Ident = input.parse()?;
+        let denom = match policy.to_string().as_str() {
+            "nonpayable" => None,
+            "payable" if input.is_empty() => None,
+            "must_pay" if input.is_empty() => {
+                return Err(Error::new(
+                    policy.span(),
+                    "Missing denom for the `must_pay` funds policy.\n
+  = note: Expected `#[sv::funds(must_pay(denom))]`.\n",
+                ))
+            }
+            "payable" | "must_pay" => Some(parse_denom(input)?),
+            _ => {
+                return Err(Error::new(
+                    policy.span(),
+                    "Invalid funds policy.\n
+  = note: Expected one of [`nonpayable`, `payable`, `payable(denom)`, `must_pay(denom)`].\n",
+                ))
+            }
+        };
+
+        if !input.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "Unexpected tokens inside `sv::funds` attribute.\n
+  = note: Expected one of [`nonpayable`, `payable`, `payable(denom)`, `must_pay(denom)`].\n",
+            ));
+        }
+
+        Ok(Self { policy, denom })
+    }
+}
@@ -0,0 +1,24 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{MetaList, Path, Result};
+
+/// Type wrapping data parsed from `sv::guard` attribute.
+#[derive(Clone, Debug)]
+pub struct GuardAttr {
+    pub path: Path,
+}
+
+impl GuardAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        GuardAttr::parse.parse2(attr.tokens.clone()).map_err(|err| {
+            emit_error!(err.span(), err);
+            err
+        })
+    }
+}
+
+impl Parse for GuardAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse().map(|path| Self { path })
+    }
+}
@@ -4,26 +4,43 @@
 use data::DataFieldParams;
 use features::SylviaFeatures;
 use payload::PayloadFieldParam;
+use proc_macro2::Span;
 use proc_macro_error::emit_error;
 use syn::spanned::Spanned;
 use syn::{Attribute, MetaList, PathSegment};
 
+pub mod alias;
 pub mod attr;
+pub mod code;
 pub mod custom;
 pub mod data;
+pub mod deprecated;
 pub mod error;
+pub mod events;
 pub mod features;
+pub mod funds;
+pub mod guard;
 pub mod messages;
 pub mod msg;
 pub mod override_entry_point;
 pub mod payload;
+pub mod validate;
+pub mod version;
 
+pub use alias::AliasAttr;
 pub use attr::{MsgAttrForwarding, VariantAttrForwarding};
+pub use code::CodeAttr;
 pub use custom::Custom;
+pub use deprecated::DeprecatedAttr;
 pub use error::ContractErrorAttr;
+pub use events::EventsAttr;
+pub use funds::FundsPolicyAttr;
+pub use guard::GuardAttr;
 pub use messages::{ContractMessageAttr, Customs};
 pub use msg::{MsgAttr, MsgType};
 pub use override_entry_point::{FilteredOverrideEntryPoints, OverrideEntryPoint};
+pub use validate::ValidateAttr;
+pub use version::ContractVersionAttr;
 
 /// This struct represents all possible attributes that
 /// are parsed and utilized by sylvia.
@@ -39,6 +56,16 @@ pub enum SylviaAttribute {
     Payload,
     Data,
     Features,
+    Version,
+    Guard,
+    Funds,
+    AllowWhenPaused,
+    Deprecated,
+    Alias,
+    Addr,
+    Validate,
+    Events,
+    Code,
 }
 
 impl SylviaAttribute {
@@ -63,6 +90,16 @@ impl SylviaAttribute {
             "payload" => Some(Self::Payload),
             "data" => Some(Self::Data),
             "features" => Some(Self::Features),
+            "version" => Some(Self::Version),
+            "guard" => Some(Self::Guard),
+            "funds" => Some(Self::Funds),
+            "allow_when_paused" => Some(Self::AllowWhenPaused),
+            "deprecated" => Some(Self::Deprecated),
+            "alias" => Some(Self::Alias),
+            "addr" => Some(Self::Addr),
+            "validate" => Some(Self::Validate),
+            "events" => Some(Self::Events),
+            "code" => Some(Self::Code),
             _ => None,
         }
     }
@@ -82,6 +119,14 @@ pub struct ParsedSylviaAttributes {
     pub sv_features: SylviaFeatures,
     pub data: Option<DataFieldParams>,
     pub payload: Option<PayloadFieldParam>,
+    pub version_attr: Option<ContractVersionAttr>,
+    pub guards: Vec<GuardAttr>,
+    pub funds: Option<FundsPolicyAttr>,
+    /// Span of the `sv::allow_when_paused` attribute if present.
+    pub allow_when_paused: Option<Span>,
+    pub deprecated: Option<DeprecatedAttr>,
+    pub aliases: Vec<AliasAttr>,
+    pub events: Vec<EventsAttr>,
 }
 
 impl ParsedSylviaAttributes {
@@ -96,6 +141,32 @@ impl ParsedSylviaAttributes {
             } else if sylvia_attr == Some(SylviaAttribute::Data) {
                 // The `sv::data` attribute can be used without parameters.
                 result.data = Some(DataFieldParams::default());
+            } else if sylvia_attr == Some(SylviaAttribute::AllowWhenPaused) {
+                // The `sv::allow_when_paused` attribute is used without parameters.
+                result.allow_when_paused = Some(attr.path().span());
+            } else if sylvia_attr == Some(SylviaAttribute::Deprecated) {
+                // The `sv::deprecated` attribute can be used without parameters.
+                result.deprecated = Some(DeprecatedAttr::empty(attr.path().span()));
+            } else if sylvia_attr == Some(SylviaAttribute::Addr) {
+                emit_error!(
+                    attr.path().span(), "The attribute `sv::addr` is only supported on the message parameters";
+                    note = "Use `#[sv::addr] param: Addr` in the `exec`, `query`, `sudo`, `instantiate` or `migrate` method"
+                );
+            } else if sylvia_attr == Some(SylviaAttribute::Validate) {
+                emit_error!(
+                    attr.path().span(), "The attribute `sv::validate` is only supported on the message parameters";
+                    note = "Use `#[sv::validate(...)] param: Type` in the `exec`, `query`, `sudo`, `instantiate` or `migrate` method"
+                );
+            } else if sylvia_attr == Some(SylviaAttribute::Alias) {
+                emit_error!(
+                    attr.path().span(), "Missing parameters for `sv::alias`";
+                    note = "Expected `#[sv::alias(\"old_name\", ...)]`"
+                );
+            } else if sylvia_attr == Some(SylviaAttribute::Events) {
+                emit_error!(
+                    attr.path().span(), "Missing parameters for `sv::events`";
+                    note = "Expected `#[sv::events(path::to::Event, ...)]`"
+                );
             } else if sylvia_attr == Some(SylviaAttribute::Payload) {
                 emit_error!(
                     attr.span(), "Missing parameters for `sv::payload`";
@@ -119,6 +190,86 @@ impl ParsedSylviaAttributes {
             }
         }
 
+        if let Some(guard) = result.guards.first() {
+            let msg_type = result.msg_attr.as_ref().map(MsgAttr::msg_type);
+            if msg_type != Some(MsgType::Exec) {
+                emit_error!(
+                    guard.path.span(), "The attribute `sv::guard` is only supported for `exec`";
+                    note = "Use `#[sv::guard(path::to::check)]` next to the `#[sv::msg(exec)]` attribute";
+                );
+            }
+        }
+
+        if let Some(funds) = &result.funds {
+            let msg_type = result.msg_attr.as_ref().map(MsgAttr::msg_type);
+            if !matches!(
+                msg_type,
+                None | Some(MsgType::Exec) | Some(MsgType::Instantiate)
+            ) {
+                emit_error!(
+                    funds.policy.span(), "The attribute `sv::funds` is only supported for `exec` and `instantiate`";
+                    note = "Use `#[sv::funds(...)]` next to the `#[sv::msg(exec)]` or `#[sv::msg(instantiate)]` attribute, or on the contract";
+                );
+            }
+        }
+
+        if let Some(span) = result.allow_when_paused {
+            let msg_type = result.msg_attr.as_ref().map(MsgAttr::msg_type);
+            if msg_type != Some(MsgType::Exec) {
+                emit_error!(
+                    span, "The attribute `sv::allow_when_paused` is only supported for `exec`";
+                    note = "Use `#[sv::allow_when_paused]` next to the `#[sv::msg(exec)]` attribute";
+                );
+            }
+        }
+
+        let msg_type = result.msg_attr.as_ref().map(MsgAttr::msg_type);
+        let is_enum_msg = matches!(
+            msg_type,
+            Some(MsgType::Exec) | Some(MsgType::Query) | Some(MsgType::Sudo)
+        );
+
+        if let Some(deprecated) = &result.deprecated {
+            if !is_enum_msg {
+                emit_error!(
+                    deprecated.span, "The attribute `sv::deprecated` is only supported for `exec`, `query` and `sudo`";
+                    note = "Use `#[sv::deprecated(note = \"..\")]` next to the `#[sv::msg(...)]` attribute";
+                );
+            }
+        }
+
+        if let Some(alias) = result
+            .aliases
+            .first()
+            .and_then(|alias| alias.aliases.first())
+        {
+            if !is_enum_msg {
+                emit_error!(
+                    alias.span(), "The attribute `sv::alias` is only supported for `exec`, `query` and `sudo`";
+                    note = "Use `#[sv::alias(\"old_name\")]` next to the `#[sv::msg(...)]` attribute";
+                );
+            }
+        }
+
+        if let Some(event) = result
+            .events
+            .first()
+            .and_then(|events| events.events.first())
+        {
+            if !matches!(
+                msg_type,
+                Some(MsgType::Exec)
+                    | Some(MsgType::Sudo)
+                    | Some(MsgType::Instantiate)
+                    | Some(MsgType::Migrate)
+            ) {
+                emit_error!(
+                    event.span(), "The attribute `sv::events` is only supported for `exec`, `sudo`, `instantiate` and `migrate`";
+                    note = "Use `#[sv::events(path::to::Event)]` next to the `#[sv::msg(...)]` attribute";
+                );
+            }
+        }
+
         result
     }
 
@@ -197,6 +348,84 @@ impl ParsedSylviaAttributes {
                     self.sv_features = features;
                 }
             }
+            SylviaAttribute::Guard => {
+                if let Ok(guard) = GuardAttr::new(attr) {
+                    self.guards.push(guard);
+                }
+            }
+            SylviaAttribute::Funds => {
+                if self.funds.is_none() {
+                    if let Ok(funds) = FundsPolicyAttr::new(attr) {
+                        self.funds = Some(funds);
+                    }
+                } else {
+                    emit_error!(
+                        attr, "The attribute `sv::funds` is redefined";
+                        note = attr.span() => "Previous definition of the attribute `sv::funds`";
+                        note = "Only one `sv::funds` attribute can exist on a single method or contract"
+                    );
+                }
+            }
+            SylviaAttribute::AllowWhenPaused => {
+                emit_error!(
+                    attr, "The attribute `sv::allow_when_paused` does not accept parameters";
+                    note = "Expected `#[sv::allow_when_paused]`"
+                );
+            }
+            SylviaAttribute::Deprecated => {
+                if self.deprecated.is_none() {
+                    if let Ok(deprecated) = DeprecatedAttr::new(attr) {
+                        self.deprecated = Some(deprecated);
+                    }
+                } else {
+                    emit_error!(
+                        attr, "The attribute `sv::deprecated` is redefined";
+                        note = attr.span() => "Previous definition of the attribute `sv::deprecated`";
+                        note = "Only one `sv::deprecated` attribute can exist on a single method"
+                    );
+                }
+            }
+            SylviaAttribute::Alias => {
+                if let Ok(alias) = AliasAttr::new(attr) {
+                    self.aliases.push(alias);
+                }
+            }
+            SylviaAttribute::Events => {
+                if let Ok(events) = EventsAttr::new(attr) {
+                    self.events.push(events);
+                }
+            }
+            SylviaAttribute::Addr => {
+                emit_error!(
+                    attr.path.span(), "The attribute `sv::addr` is only supported on the message parameters";
+                    note = "Use `#[sv::addr] param: Addr` in the `exec`, `query`, `sudo`, `instantiate` or `migrate` method"
+                );
+            }
+            SylviaAttribute::Validate => {
+                emit_error!(
+                    attr.path.span(), "The attribute `sv::validate` is only supported on the message parameters";
+                    note = "Use `#[sv::validate(...)] param: Type` in the `exec`, `query`, `sudo`, `instantiate` or `migrate` method"
+                );
+            }
+            SylviaAttribute::Code => {
+                emit_error!(
+                    attr.path.span(), "The attribute `sv::code` is only supported on the error variants";
+                    note = "Use `#[sv::code(42)]` on the variant of the enum marked with `#[sylvia::error_codes]`"
+                );
+            }
+            SylviaAttribute::Version => {
+                if self.version_attr.is_none() {
+                    if let Ok(version_attr) = ContractVersionAttr::new(attr) {
+                        self.version_attr = Some(version_attr);
+                    }
+                } else {
+                    emit_error!(
+                        attr, "The attribute `sv::version` is redefined";
+                        note = attr.span() => "Previous definition of the attribute `sv::version`";
+                        note = "Only one `sv::version` attribute can exist on a single contract"
+                    );
+                }
+            }
         }
     }
 }
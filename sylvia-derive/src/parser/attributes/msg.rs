@@ -1,6 +1,8 @@
+use proc_macro2::Span;
 use proc_macro_error::emit_error;
 use syn::parse::{Error, Parse, ParseStream, Parser};
-use syn::{bracketed, Ident, MetaList, Result, Token};
+use syn::spanned::Spanned;
+use syn::{bracketed, parenthesized, token, Ident, LitInt, MetaList, Result, Token, Type};
 
 /// Supported message types.
 /// Representation of the first parameter in `#[sv::msg(..)] attribute.
@@ -12,6 +14,13 @@ pub enum MsgType {
     Migrate,
     Reply,
     Sudo,
+    IbcChannelOpen,
+    IbcChannelConnect,
+    IbcChannelClose,
+    IbcPacketReceive,
+    IbcPacketAck,
+    IbcPacketTimeout,
+    IbcPacket,
 }
 
 /// ArgumentParser holds `resp` parameter parsed from `sv::msg` attribute.
@@ -20,6 +29,9 @@ struct ArgumentParser {
     pub query_resp_type: Option<Ident>,
     pub reply_handlers: Vec<Ident>,
     pub reply_on: Option<ReplyOn>,
+    pub reply_id: Option<PinnedReplyId>,
+    pub paginated: Option<PageLimits>,
+    pub data_type: Option<Type>,
 }
 
 impl Parse for ArgumentParser {
@@ -55,10 +67,37 @@ impl Parse for ArgumentParser {
                     let reply_on = ReplyOn::new(reply_on)?;
                     result.reply_on = Some(reply_on);
                 }
+                "id" => {
+                    let _: Token![=] = input.parse()?;
+                    let reply_id = PinnedReplyId::parse(input)?;
+                    result.reply_id = Some(reply_id);
+                }
+                "paginated" => {
+                    let page_limits = match input.peek(token::Paren) {
+                        true => {
+                            let limits_content;
+                            parenthesized!(limits_content in input);
+                            PageLimits::parse(arg_type.span(), &limits_content)?
+                        }
+                        false => PageLimits::new(arg_type.span()),
+                    };
+                    result.paginated = Some(page_limits);
+                }
+                "data" => {
+                    if !input.peek(Token![=]) {
+                        return Err(Error::new(
+                            arg_type.span(),
+                            "Missing type of the data, expected `data = Type`.",
+                        ));
+                    }
+                    let _: Token![=] = input.parse()?;
+                    let data_type: Type = input.parse()?;
+                    result.data_type = Some(data_type);
+                }
                 _ => {
                     return Err(Error::new(
                         arg_type.span(),
-                        "Invalid argument type, expected `resp`, `handlers`, `reply_on` or no argument.",
+                        "Invalid argument type, expected `resp`, `handlers`, `reply_on`, `id`, `paginated`, `data` or no argument.",
                     ))
                 }
             }
@@ -109,6 +148,98 @@ impl ReplyOn {
     }
 }
 
+/// Representation of `id` parameter in `#[sv::msg(reply(...))]` attribute.
+///
+/// Pins the value of the generated reply id instead of deriving it from the order of handlers.
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub enum PinnedReplyId {
+    /// Explicitly assigned value, e.g. `id = 42`.
+    Value(u64),
+    /// Value computed from the name of the handler, i.e. `id = hash`.
+    Hash,
+}
+
+impl Parse for PinnedReplyId {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitInt) {
+            let value: LitInt = input.parse()?;
+            return value.base10_parse().map(Self::Value);
+        }
+
+        let reply_id: Ident = input.parse()?;
+        match reply_id.to_string().as_str() {
+            "hash" => Ok(Self::Hash),
+            _ => Err(Error::new(
+                reply_id.span(),
+                "Invalid reply id, expected an integer literal or `hash`.",
+            )),
+        }
+    }
+}
+
+/// Representation of `paginated` parameter in `#[sv::msg(query, paginated(...))]` attribute.
+///
+/// Limits of the page applied by the generated dispatch to the `limit` parameter.
+#[derive(Copy, Debug, Clone)]
+pub struct PageLimits {
+    pub default: u32,
+    pub max: u32,
+    span: Span,
+}
+
+impl PageLimits {
+    const DEFAULT_LIMIT: u32 = 10;
+    const MAX_LIMIT: u32 = 30;
+
+    fn new(span: Span) -> Self {
+        Self {
+            default: Self::DEFAULT_LIMIT,
+            max: Self::MAX_LIMIT,
+            span,
+        }
+    }
+
+    fn parse(span: Span, input: ParseStream) -> Result<Self> {
+        let mut default = None;
+        let mut max = None;
+
+        while !input.is_empty() {
+            let limit_type: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let value: LitInt = input.parse()?;
+            let value = Some(value.base10_parse()?);
+            match limit_type.to_string().as_str() {
+                "default" => default = value,
+                "max" => max = value,
+                _ => {
+                    return Err(Error::new(
+                        limit_type.span(),
+                        "Invalid page limit, expected `default` or `max`.",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+
+        let max = max.unwrap_or(Self::MAX_LIMIT);
+        let default = default.unwrap_or(Self::DEFAULT_LIMIT.min(max));
+        if default == 0 || default > max {
+            return Err(Error::new(
+                span,
+                "The default page limit has to be positive and cannot exceed the max limit.",
+            ));
+        }
+
+        Ok(Self { default, max, span })
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
 /// Parsed representation of `#[sv::msg(...)]` attribute.
 #[derive(Debug, Clone)]
 pub struct MsgAttr {
@@ -116,6 +247,9 @@ pub struct MsgAttr {
     query_resp_type: Option<Ident>,
     reply_handlers: Vec<Ident>,
     reply_on: ReplyOn,
+    reply_id: Option<PinnedReplyId>,
+    paginated: Option<PageLimits>,
+    data_type: Option<Type>,
 }
 
 impl MsgAttr {
@@ -141,6 +275,19 @@ impl MsgAttr {
     pub fn reply_on(&self) -> ReplyOn {
         self.reply_on
     }
+
+    pub fn reply_id(&self) -> Option<PinnedReplyId> {
+        self.reply_id
+    }
+
+    pub fn paginated(&self) -> Option<PageLimits> {
+        self.paginated
+    }
+
+    /// Type of the data set in the response of the `exec` message.
+    pub fn data_type(&self) -> Option<&Type> {
+        self.data_type.as_ref()
+    }
 }
 
 impl PartialEq<MsgType> for MsgAttr {
@@ -157,13 +304,40 @@ impl Parse for MsgAttr {
             query_resp_type,
             reply_handlers,
             reply_on,
+            reply_id,
+            paginated,
+            data_type,
         } = ArgumentParser::parse(input)?;
 
+        if let (Some(paginated), false) = (paginated, msg_type == MsgType::Query) {
+            return Err(Error::new(
+                paginated.span(),
+                "The `paginated` argument is only supported for the `query` messages.",
+            ));
+        }
+
+        if let (Some(data_type), false) = (&data_type, msg_type == MsgType::Exec) {
+            return Err(Error::new(
+                data_type.span(),
+                "The `data` argument is only supported for the `exec` messages.",
+            ));
+        }
+
+        if let (Some(PinnedReplyId::Value(_)), Some(handler)) = (reply_id, reply_handlers.get(1)) {
+            return Err(Error::new(
+                handler.span(),
+                "Explicit reply `id` can only be assigned to a single handler.",
+            ));
+        }
+
         Ok(Self {
             msg_type,
             query_resp_type,
             reply_handlers,
             reply_on: reply_on.unwrap_or_default(),
+            reply_id,
+            paginated,
+            data_type,
         })
     }
 }
@@ -77,10 +77,16 @@ impl Parse for OverrideEntryPoint {
             "migrate" => MsgType::Migrate,
             "reply" => MsgType::Reply,
             "sudo" =>  MsgType::Sudo,
+            "ibc_channel_open" => MsgType::IbcChannelOpen,
+            "ibc_channel_connect" => MsgType::IbcChannelConnect,
+            "ibc_channel_close" => MsgType::IbcChannelClose,
+            "ibc_packet_receive" => MsgType::IbcPacketReceive,
+            "ibc_packet_ack" => MsgType::IbcPacketAck,
+            "ibc_packet_timeout" => MsgType::IbcPacketTimeout,
             &_ => {
                 return Err(Error::new(
                     ty.span(),
-                    "Invalid entry point. Expected exec, instantiate, query, migrate, reply or sudo. Found {ty}",
+                    "Invalid entry point. Expected exec, instantiate, query, migrate, reply, sudo or one of the ibc_* entry points. Found {ty}",
                 ))
             }
         };
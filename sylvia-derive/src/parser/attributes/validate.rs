@@ -0,0 +1,366 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{
+    parenthesized, parse_quote, Error, Expr, ExprLit, ExprUnary, Ident, Lit, LitInt, LitStr,
+    MetaList, Path, Result, Token, UnOp,
+};
+
+use crate::crate_module;
+
+/// Type wrapping data parsed from `sv::validate` attribute.
+#[derive(Clone, Debug, Default)]
+pub struct ValidateAttr {
+    pub min: Option<Expr>,
+    pub max: Option<Expr>,
+    pub min_length: Option<Expr>,
+    pub max_length: Option<Expr>,
+    pub chars: Option<CharsRule>,
+    pub custom: Option<Path>,
+}
+
+/// Set of characters allowed in the string parameter.
+#[derive(Clone, Debug)]
+pub struct CharsRule {
+    /// Any of `alphabetic`, `numeric`, `alphanumeric`, `lowercase` or `uppercase`.
+    pub classes: Vec<Ident>,
+    /// Additional characters allowed next to the classes.
+    pub extra: Option<LitStr>,
+}
+
+impl ValidateAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        ValidateAttr::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.min.is_none()
+            && self.max.is_none()
+            && self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.chars.is_none()
+            && self.custom.is_none()
+    }
+
+    /// Emits checks of the `value` reference against the rules.
+    /// Errors are returned from the surrounding function using the `?` operator.
+    pub fn emit_checks(&self, field: &str, value: &Ident) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            min,
+            max,
+            min_length,
+            max_length,
+            chars,
+            custom,
+        } = self;
+
+        let min = min.as_ref().map(|min| quote! { .min(#min)? });
+        let max = max.as_ref().map(|max| quote! { .max(#max)? });
+        let length = (min_length.is_some() || max_length.is_some()).then(|| {
+            let min_length = emit_option(min_length);
+            let max_length = emit_option(max_length);
+            quote! { .length(#min_length, #max_length)? }
+        });
+        let chars = chars.as_ref().map(|chars| {
+            let predicate = chars.emit_predicate();
+            quote! { .chars(#predicate)? }
+        });
+        let custom = custom.as_ref().map(|custom| quote! { #custom (#value)?; });
+
+        quote! {
+            #sylvia ::types::FieldValidator::new(#field, #value) #min #max #length #chars;
+            #custom
+        }
+    }
+
+    /// Emits `schemars` attributes describing the rules in the JSON schema of the message.
+    /// Only the bounds given as literals end up in the schema.
+    ///
+    /// Length of the `base64` encoded field, like the `Binary`, is checked in bytes, so its bounds
+    /// are converted to the length of the encoded string.
+    pub fn emit_schema_attrs(&self, base64: bool) -> TokenStream {
+        let range = emit_literal_bounds(&self.min, &self.max)
+            .map(|bounds| quote! { #[schemars(range( #bounds ))] });
+        let length = match base64 {
+            true => emit_literal_bounds(
+                &base64_length(&self.min_length),
+                &base64_length(&self.max_length),
+            ),
+            false => emit_literal_bounds(&self.min_length, &self.max_length),
+        }
+        .map(|bounds| quote! { #[schemars(length( #bounds ))] });
+        let regex = self.chars.as_ref().map(|chars| {
+            let pattern = chars.pattern();
+            quote! { #[schemars(regex(pattern = #pattern))] }
+        });
+
+        quote! {
+            #range
+            #length
+            #regex
+        }
+    }
+}
+
+impl CharsRule {
+    const CLASSES: [(&'static str, &'static str, &'static str); 5] = [
+        ("alphabetic", "is_ascii_alphabetic", "a-zA-Z"),
+        ("numeric", "is_ascii_digit", "0-9"),
+        ("alphanumeric", "is_ascii_alphanumeric", "a-zA-Z0-9"),
+        ("lowercase", "is_ascii_lowercase", "a-z"),
+        ("uppercase", "is_ascii_uppercase", "A-Z"),
+    ];
+
+    fn class(class: &Ident) -> Option<(&'static str, &'static str, &'static str)> {
+        Self::CLASSES.into_iter().find(|(name, _, _)| class == name)
+    }
+
+    /// Emits closure checking if the character is allowed.
+    fn emit_predicate(&self) -> TokenStream {
+        let checks = self.classes.iter().filter_map(|class| {
+            let (_, method, _) = Self::class(class)?;
+            let method = Ident::new(method, class.span());
+            Some(quote! { c.#method() })
+        });
+        let extra = self.extra.iter().map(|extra| quote! { #extra.contains(c) });
+        let checks = checks.chain(extra);
+
+        quote! {
+            |c: char| #( #checks )||*
+        }
+    }
+
+    /// Regular expression matching strings built from the allowed characters.
+    fn pattern(&self) -> String {
+        let classes = self
+            .classes
+            .iter()
+            .filter_map(Self::class)
+            .map(|(_, _, range)| range);
+        let extra = self
+            .extra
+            .iter()
+            .flat_map(|extra| extra.value().chars().collect::<Vec<_>>())
+            .map(|c| match c {
+                '\\' | ']' | '[' | '^' | '-' => format!("\\{c}"),
+                c => c.to_string(),
+            });
+
+        format!(
+            "^[{}{}]*$",
+            classes.collect::<String>(),
+            extra.collect::<String>()
+        )
+    }
+}
+
+fn emit_option(expr: &Option<Expr>) -> TokenStream {
+    match expr {
+        Some(expr) => quote! { Some(#expr) },
+        None => quote! { None },
+    }
+}
+
+/// Emits `min = .., max = ..` parameters of the `schemars` attribute for the literal bounds.
+fn emit_literal_bounds(min: &Option<Expr>, max: &Option<Expr>) -> Option<TokenStream> {
+    let literal = |bound: &Option<Expr>| bound.clone().filter(is_number_literal);
+    match (literal(min), literal(max)) {
+        (None, None) => None,
+        (Some(min), None) => Some(quote! { min = #min }),
+        (None, Some(max)) => Some(quote! { max = #max }),
+        (Some(min), Some(max)) => Some(quote! { min = #min, max = #max }),
+    }
+}
+
+/// Length of the padded `base64` string encoding the literal number of bytes.
+fn base64_length(bytes: &Option<Expr>) -> Option<Expr> {
+    let Some(Expr::Lit(ExprLit {
+        lit: Lit::Int(bytes),
+        ..
+    })) = bytes
+    else {
+        return None;
+    };
+    let length = 4 * bytes.base10_parse::<u64>().ok()?.div_ceil(3);
+    let length = LitInt::new(&length.to_string(), bytes.span());
+    Some(parse_quote! { #length })
+}
+
+fn is_number_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(_) | Lit::Float(_),
+            ..
+        }) => true,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => is_number_literal(expr),
+        _ => false,
+    }
+}
+
+fn redefined(param: &Ident) -> Error {
+    Error::new(
+        param.span(),
+        format!("Parameter `{param}` of the `sv::validate` attribute is redefined."),
+    )
+}
+
+fn parse_length(input: ParseStream, validate: &mut ValidateAttr, param: &Ident) -> Result<()> {
+    let content;
+    parenthesized!(content in input);
+
+    while !content.is_empty() {
+        let bound: Ident = content.parse()?;
+        let _: Token![=] = content.parse()?;
+        let target = match bound.to_string().as_str() {
+            "min" => &mut validate.min_length,
+            "max" => &mut validate.max_length,
+            _ => {
+                return Err(Error::new(
+                    bound.span(),
+                    "Invalid length parameter.\n
+  = note: Expected `#[sv::validate(length(min = .., max = ..))]`.\n",
+                ))
+            }
+        };
+        if target.is_some() {
+            return Err(redefined(param));
+        }
+        *target = Some(content.parse()?);
+        if !content.is_empty() {
+            let _: Token![,] = content.parse()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_chars(input: ParseStream) -> Result<CharsRule> {
+    let content;
+    parenthesized!(content in input);
+
+    let mut rule = CharsRule {
+        classes: vec![],
+        extra: None,
+    };
+    for item in Punctuated::<CharsItem, Token![,]>::parse_terminated(&content)? {
+        match item {
+            CharsItem::Class(class) => rule.classes.push(class),
+            CharsItem::Extra(extra) if rule.extra.is_none() => rule.extra = Some(extra),
+            CharsItem::Extra(extra) => {
+                return Err(Error::new(
+                    extra.span(),
+                    "Additional characters are redefined.\n
+  = note: Put all additional characters in a single string.\n",
+                ))
+            }
+        }
+    }
+
+    if rule.classes.is_empty() && rule.extra.is_none() {
+        return Err(Error::new(
+            content.span(),
+            "Missing allowed characters.\n
+  = note: Expected `#[sv::validate(chars(alphanumeric, \"-_\"))]`.\n",
+        ));
+    }
+
+    Ok(rule)
+}
+
+enum CharsItem {
+    Class(Ident),
+    Extra(LitStr),
+}
+
+impl Parse for CharsItem {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            return input.parse().map(Self::Extra);
+        }
+
+        let class: Ident = input.parse()?;
+        match CharsRule::class(&class) {
+            Some(_) => Ok(Self::Class(class)),
+            None => Err(Error::new(
+                class.span(),
+                "Invalid character class.\n
+  = note: Expected one of `alphabetic`, `numeric`, `alphanumeric`, `lowercase` or `uppercase`.\n",
+            )),
+        }
+    }
+}
+
+impl Parse for ValidateAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut validate = Self::default();
+
+        while !input.is_empty() {
+            let param: Ident = input.parse()?;
+            match param.to_string().as_str() {
+                "min" | "max" => {
+                    let _: Token![=] = input.parse()?;
+                    let target = match param == "min" {
+                        true => &mut validate.min,
+                        false => &mut validate.max,
+                    };
+                    if target.is_some() {
+                        return Err(redefined(&param));
+                    }
+                    *target = Some(input.parse()?);
+                }
+                "length" => parse_length(input, &mut validate, &param)?,
+                "non_empty" => {
+                    if validate.min_length.is_some() {
+                        return Err(redefined(&param));
+                    }
+                    validate.min_length = Some(syn::parse_quote! { 1 });
+                }
+                "chars" => {
+                    if validate.chars.is_some() {
+                        return Err(redefined(&param));
+                    }
+                    validate.chars = Some(parse_chars(input)?);
+                }
+                "custom" => {
+                    let _: Token![=] = input.parse()?;
+                    if validate.custom.is_some() {
+                        return Err(redefined(&param));
+                    }
+                    validate.custom = Some(input.parse()?);
+                }
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "Invalid validate parameter.\n
+  = note: Expected `#[sv::validate(min = .., max = .., length(min = .., max = ..), non_empty, chars(..), custom = path::to::check)]`.\n",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+
+        if validate.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "Missing validation rules.\n
+  = note: Expected `#[sv::validate(min = .., max = .., length(min = .., max = ..), non_empty, chars(..), custom = path::to::check)]`.\n",
+            ));
+        }
+
+        Ok(validate)
+    }
+}
@@ -0,0 +1,68 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{Error, Expr, Ident, MetaList, Result, Token};
+
+/// Type wrapping data parsed from `sv::version` attribute.
+#[derive(Debug)]
+pub struct ContractVersionAttr {
+    pub name: Expr,
+    pub version: Expr,
+}
+
+impl ContractVersionAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        ContractVersionAttr::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+
+    /// Emits `CONTRACT_NAME` and `CONTRACT_VERSION` constants used by the generated dispatch.
+    pub fn emit_consts(&self) -> TokenStream {
+        let Self { name, version } = self;
+
+        quote! {
+            pub const CONTRACT_NAME: &str = #name;
+            pub const CONTRACT_VERSION: &str = #version;
+        }
+    }
+}
+
+impl Parse for ContractVersionAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut name = None;
+        let mut version = None;
+
+        while !input.is_empty() {
+            let param: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            match param.to_string().as_str() {
+                "name" => name = Some(input.parse()?),
+                "version" => version = Some(input.parse()?),
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "Invalid version parameter.\n
+  = note: Expected `#[sv::version(name = .., version = ..)]`.\n",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+
+        match (name, version) {
+            (Some(name), Some(version)) => Ok(Self { name, version }),
+            _ => Err(Error::new(
+                input.span(),
+                "Missing version parameters.\n
+  = note: Expected `#[sv::version(name = .., version = ..)]`.\n",
+            )),
+        }
+    }
+}
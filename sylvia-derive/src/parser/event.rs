@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream};
+use syn::{parse2, Ident, LitStr, Result, Token};
+
+/// Parsed arguments for `event` macro
+#[derive(Default)]
+pub struct EventArgs {
+    /// Type of the event overriding the default one.
+    pub name: Option<LitStr>,
+}
+
+impl EventArgs {
+    pub fn new(attr: &TokenStream2) -> Result<Self> {
+        parse2(attr.clone()).map_err(|err| {
+            emit_error!(err.span(), err);
+            err
+        })
+    }
+}
+
+impl Parse for EventArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut event_args = Self::default();
+        if input.is_empty() {
+            return Ok(event_args);
+        }
+
+        let param: Ident = input.parse()?;
+        if param != "name" {
+            return Err(Error::new(
+                param.span(),
+                "Invalid event parameter.\n
+  = note: Expected `#[sylvia::event(name = \"event_type\")]`.\n",
+            ));
+        }
+        let _: Token![=] = input.parse()?;
+        event_args.name = Some(input.parse()?);
+        let _: Option<Token![,]> = input.parse()?;
+
+        if !input.is_empty() {
+            return Err(Error::new(input.span(), "Unexpected tokens."));
+        }
+
+        Ok(event_args)
+    }
+}
@@ -4,6 +4,7 @@
 pub mod attributes;
 pub mod check_generics;
 pub mod entry_point;
+pub mod event;
 pub mod variant_descs;
 
 pub use attributes::{
@@ -12,6 +13,7 @@ pub use attributes::{
 };
 use check_generics::{CheckGenerics, GetPath};
 pub use entry_point::EntryPointArgs;
+pub use event::EventArgs;
 
 use proc_macro_error::emit_error;
 use syn::punctuated::Punctuated;
@@ -1,11 +1,19 @@
-use crate::parser::attributes::VariantAttrForwarding;
-use crate::parser::{MsgAttr, ParsedSylviaAttributes};
-use syn::{Attribute, ImplItem, ItemImpl, ItemTrait, Signature, TraitItem};
+use crate::parser::attributes::{
+    DeprecatedAttr, FundsPolicyAttr, GuardAttr, VariantAttrForwarding,
+};
+use crate::parser::{MsgAttr, ParsedSylviaAttributes, SylviaAttribute};
+use syn::{Attribute, ImplItem, ItemImpl, ItemTrait, LitStr, Path, Signature, TraitItem};
 
 /// Type wrapping common data between [ItemImpl] and [ItemTrait].
 pub struct VariantDesc<'a> {
     msg_attr: Option<MsgAttr>,
     attrs_to_forward: Vec<VariantAttrForwarding>,
+    guards: Vec<GuardAttr>,
+    funds: Option<FundsPolicyAttr>,
+    allow_when_paused: bool,
+    deprecated: Option<DeprecatedAttr>,
+    aliases: Vec<LitStr>,
+    events: Vec<Path>,
     sig: &'a Signature,
 }
 
@@ -14,13 +22,40 @@ impl<'a> VariantDesc<'a> {
         let sylvia_params = ParsedSylviaAttributes::new(attrs.iter());
         let attrs_to_forward = sylvia_params.variant_attrs_forward;
         let msg_attr = sylvia_params.msg_attr;
+        let guards = sylvia_params.guards;
+        let funds = sylvia_params.funds;
+        let allow_when_paused = sylvia_params.allow_when_paused.is_some();
+        let deprecated = sylvia_params.deprecated;
+        let aliases = sylvia_params
+            .aliases
+            .into_iter()
+            .flat_map(|alias| alias.aliases)
+            .collect();
+        let events = sylvia_params
+            .events
+            .into_iter()
+            .flat_map(|events| events.events)
+            .collect();
         Self {
             msg_attr,
             attrs_to_forward,
+            guards,
+            funds,
+            allow_when_paused,
+            deprecated,
+            aliases,
+            events,
             sig,
         }
     }
 
+    /// Uses the funds policy set on the whole `impl` or `trait` block
+    /// if the method doesn't specify its own.
+    pub fn with_default_funds(self, default_funds: &Option<FundsPolicyAttr>) -> Self {
+        let funds = self.funds.or_else(|| default_funds.clone());
+        Self { funds, ..self }
+    }
+
     pub fn into_sig(self) -> &'a Signature {
         self.sig
     }
@@ -32,6 +67,30 @@ impl<'a> VariantDesc<'a> {
     pub fn attrs_to_forward(&self) -> Vec<VariantAttrForwarding> {
         self.attrs_to_forward.clone()
     }
+
+    pub fn guards(&self) -> Vec<GuardAttr> {
+        self.guards.clone()
+    }
+
+    pub fn funds(&self) -> Option<FundsPolicyAttr> {
+        self.funds.clone()
+    }
+
+    pub fn allow_when_paused(&self) -> bool {
+        self.allow_when_paused
+    }
+
+    pub fn deprecated(&self) -> Option<DeprecatedAttr> {
+        self.deprecated.clone()
+    }
+
+    pub fn aliases(&self) -> Vec<LitStr> {
+        self.aliases.clone()
+    }
+
+    pub fn events(&self) -> Vec<Path> {
+        self.events.clone()
+    }
 }
 
 pub type VariantDescs<'a> = Box<dyn Iterator<Item = VariantDesc<'a>> + 'a>;
@@ -51,8 +110,11 @@ impl AsVariantDescs for ItemImpl {
     type Iter<'a> = VariantDescs<'a>;
 
     fn as_variants(&self) -> Self::Iter<'_> {
-        Box::new(self.items.iter().filter_map(|item| match item {
-            ImplItem::Fn(method) => Some(VariantDesc::new(&method.attrs, &method.sig)),
+        let default_funds = default_funds(&self.attrs);
+        Box::new(self.items.iter().filter_map(move |item| match item {
+            ImplItem::Fn(method) => Some(
+                VariantDesc::new(&method.attrs, &method.sig).with_default_funds(&default_funds),
+            ),
             _ => None,
         }))
     }
@@ -62,9 +124,20 @@ impl AsVariantDescs for ItemTrait {
     type Iter<'a> = VariantDescs<'a>;
 
     fn as_variants(&self) -> Self::Iter<'_> {
-        Box::new(self.items.iter().filter_map(|item| match item {
-            TraitItem::Fn(method) => Some(VariantDesc::new(&method.attrs, &method.sig)),
+        let default_funds = default_funds(&self.attrs);
+        Box::new(self.items.iter().filter_map(move |item| match item {
+            TraitItem::Fn(method) => Some(
+                VariantDesc::new(&method.attrs, &method.sig).with_default_funds(&default_funds),
+            ),
             _ => None,
         }))
     }
 }
+
+/// Parses only the `sv::funds` attribute of the `impl` or `trait` block.
+fn default_funds(attrs: &[Attribute]) -> Option<FundsPolicyAttr> {
+    let funds_attrs = attrs
+        .iter()
+        .filter(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Funds));
+    ParsedSylviaAttributes::new(funds_attrs).funds
+}
@@ -135,6 +135,65 @@ impl Interfaces {
         }).collect()
     }
 
+    /// Emits `TsClient` of every interface implemented by the contract.
+    pub fn emit_ts_clients(&self) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.interfaces
+            .iter()
+            .map(|interface| {
+                let ContractMessageAttr {
+                    module, variant, ..
+                } = interface;
+                let name = variant.to_string();
+
+                quote! {
+                    #sylvia ::typescript::TsClient::new(
+                        #name,
+                        < <Self as #module ::sv::InterfaceMessagesApi>::Query >::ts_methods(ts_gen),
+                        < <Self as #module ::sv::InterfaceMessagesApi>::Exec >::ts_methods(ts_gen),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    /// Emits `INTERFACE_IDS` of the interfaces implemented by the contract with the compile time
+    /// uniqueness check and, if requested, the `supported_interfaces` function used by the generated query.
+    ///
+    /// For the `pausable` contract also checks if the `sylvia::pausable::Pausable` interface is listed,
+    /// as otherwise the contract could never be unpaused.
+    pub fn emit_interface_ids(&self, supported_interfaces: bool, pausable: bool) -> TokenStream {
+        let sylvia = crate_module();
+        let modules = self.variants_modules();
+        let interfaces_cnt = self.interfaces.len();
+        let supported_interfaces = supported_interfaces.then(|| {
+            quote! {
+                /// Returns ids of the interfaces implemented by the contract.
+                pub fn supported_interfaces() -> Vec<String> {
+                    INTERFACE_IDS.iter().map(ToString::to_string).collect()
+                }
+            }
+        });
+
+        let pausable_listed = pausable.then(|| {
+            quote! {
+                const _: () = #sylvia ::pausable::assert_pausable_listed(&INTERFACE_IDS);
+            }
+        });
+
+        quote! {
+            /// Ids of the interfaces implemented by the contract.
+            pub const INTERFACE_IDS: [#sylvia ::types::InterfaceId; #interfaces_cnt] = [#(#modules ::sv::INTERFACE_ID,)*];
+
+            const _: () = #sylvia ::utils::assert_unique_interface_ids(&INTERFACE_IDS);
+
+            #pausable_listed
+
+            #supported_interfaces
+        }
+    }
+
     pub fn variants_names(&self) -> impl Iterator<Item = &Ident> {
         self.interfaces.iter().map(|interface| &interface.variant)
     }
@@ -1,20 +1,31 @@
+use crate::crate_module;
 use crate::fold::StripSelfPath;
+use crate::parser::attributes::msg::PageLimits;
+use crate::parser::attributes::ValidateAttr;
 use crate::parser::check_generics::{CheckGenerics, GetPath};
+use crate::parser::SylviaAttribute;
 use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
 use quote::quote;
 use syn::fold::Fold;
 use syn::spanned::Spanned;
 use syn::visit::Visit;
-use syn::{Attribute, Ident, Pat, PatType, Type};
+use syn::{parse_quote, Attribute, GenericArgument, Ident, Pat, PatType, PathArguments, Type};
 
 /// Representation of single message variant field
 #[derive(Debug)]
 pub struct MsgField<'a> {
     name: &'a Ident,
-    ty: &'a Type,
+    /// Type of the field in the message.
+    ty: Type,
     stripped_ty: Type,
     attrs: &'a Vec<Attribute>,
+    /// Whether the field is marked with `sv::addr` and validated by the generated dispatch.
+    addr: bool,
+    /// Rules of the `sv::validate` attribute checked by the generated dispatch.
+    validate: Option<ValidateAttr>,
+    /// Limits of the page applied to the `limit` parameter of the paginated query.
+    page_limits: Option<PageLimits>,
 }
 
 impl<'a> MsgField<'a> {
@@ -50,9 +61,52 @@ impl<'a> MsgField<'a> {
             }
         }?;
 
-        let ty = &item.ty;
-        let stripped_ty = StripSelfPath.fold_type((*item.ty).clone());
         let attrs = &item.attrs;
+        let addr_attr = attrs
+            .iter()
+            .find(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Addr));
+        if let Some(attr) = addr_attr {
+            if attr.meta.require_path_only().is_err() {
+                emit_error!(
+                    attr.meta.span(), "The attribute `sv::addr` does not accept parameters";
+                    note = "Expected `#[sv::addr]`"
+                );
+            }
+        }
+
+        let mut validate = None;
+        for attr in attrs
+            .iter()
+            .filter(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Validate))
+        {
+            let Ok(meta) = attr.meta.require_list() else {
+                emit_error!(
+                    attr.path().span(), "Missing parameters for `sv::validate`";
+                    note = "Expected `#[sv::validate(min = .., max = .., length(min = .., max = ..), non_empty, chars(..), custom = path::to::check)]`"
+                );
+                continue;
+            };
+            if validate.is_some() {
+                emit_error!(
+                    meta, "The attribute `sv::validate` is redefined";
+                    note = "Only one `sv::validate` attribute can exist on a single parameter"
+                );
+            } else if let Ok(attr) = ValidateAttr::new(meta) {
+                validate = Some(attr);
+            }
+        }
+
+        let ty = match addr_attr {
+            Some(_) => unchecked_addr_type(&item.ty).unwrap_or_else(|| {
+                emit_error!(
+                    item.ty.span(), "The attribute `sv::addr` is only supported for the `Addr` type";
+                    note = "Expected `Addr`, `Option<Addr>` or `Vec<Addr>`"
+                );
+                (*item.ty).clone()
+            }),
+            None => (*item.ty).clone(),
+        };
+        let stripped_ty = StripSelfPath.fold_type(ty.clone());
         generics_checker.visit_type(&stripped_ty);
 
         Some(Self {
@@ -60,20 +114,32 @@ impl<'a> MsgField<'a> {
             ty,
             stripped_ty,
             attrs,
+            addr: addr_attr.is_some(),
+            validate,
+            page_limits: None,
         })
     }
 
+    /// Turns the field into the `limit` of the paginated query.
+    ///
+    /// The limit is optional in the message and clamped by the generated dispatch.
+    pub fn set_page_limits(&mut self, page_limits: PageLimits) {
+        self.ty = parse_quote! { Option<u32> };
+        self.stripped_ty = self.ty.clone();
+        self.page_limits = Some(page_limits);
+    }
+
     /// Emits message field
     pub fn emit(&self) -> TokenStream {
         let Self {
-            name,
-            stripped_ty,
-            attrs,
-            ..
+            name, stripped_ty, ..
         } = self;
+        let attrs = self.attrs_to_forward();
+        let schema_attrs = self.emit_schema_attrs();
 
         quote! {
             #(#attrs)*
+            #schema_attrs
             #name: #stripped_ty
         }
     }
@@ -81,18 +147,26 @@ impl<'a> MsgField<'a> {
     /// Emits struct field
     pub fn emit_pub(&self) -> TokenStream {
         let Self {
-            name,
-            stripped_ty,
-            attrs,
-            ..
+            name, stripped_ty, ..
         } = self;
+        let attrs = self.attrs_to_forward();
+        let schema_attrs = self.emit_schema_attrs();
 
         quote! {
             #(#attrs)*
+            #schema_attrs
             pub #name: #stripped_ty
         }
     }
 
+    /// Emits `schemars` attributes of the `sv::validate` rules.
+    fn emit_schema_attrs(&self) -> Option<TokenStream> {
+        let base64 = is_binary(&self.ty);
+        self.validate
+            .as_ref()
+            .map(|validate| validate.emit_schema_attrs(base64))
+    }
+
     /// Emits method field
     pub fn emit_method_field(&self) -> TokenStream {
         let Self {
@@ -112,15 +186,173 @@ impl<'a> MsgField<'a> {
         }
     }
 
+    /// Emits checks of the `sv::validate` rules followed by the validation of the `sv::addr`
+    /// field shadowing the `arg` with the validated address, or by the clamping of the page limit.
+    /// Expects the dispatched `ctx` tuple to be in scope.
+    pub fn emit_validation(&self, arg: &Ident) -> Option<TokenStream> {
+        let sylvia = crate_module();
+
+        let checks = self.validate.as_ref().map(|validate| {
+            let checks = validate.emit_checks(&self.name.to_string(), arg);
+            match is_option(&self.ty) {
+                true => quote! { if let Some(#arg) = &#arg { #checks } },
+                false => quote! { { let #arg = &#arg; #checks } },
+            }
+        });
+        let addr_validation = self.addr.then(|| {
+            let field = self.name.to_string();
+            quote! {
+                let #arg = #sylvia ::types::ValidateAddr::validate(#arg, #field, ctx.0.api)?;
+            }
+        });
+
+        let page_limit = self.page_limits.map(|PageLimits { default, max, .. }| {
+            quote! {
+                let #arg = #sylvia ::types::page_limit(#arg, #default, #max);
+            }
+        });
+
+        match (checks, addr_validation, page_limit) {
+            (None, None, None) => None,
+            (checks, addr_validation, page_limit) => {
+                Some(quote! { #checks #addr_validation #page_limit })
+            }
+        }
+    }
+
+    /// Attributes forwarded to the message field, without the Sylvia ones.
+    fn attrs_to_forward(&self) -> impl Iterator<Item = &Attribute> {
+        self.attrs.iter().filter(|attr| {
+            !matches!(
+                SylviaAttribute::new(attr),
+                Some(SylviaAttribute::Addr | SylviaAttribute::Validate)
+            )
+        })
+    }
+
+    /// Whether the field is marked with `sv::addr` and sent as the unchecked address.
+    pub fn is_addr(&self) -> bool {
+        self.addr
+    }
+
+    pub fn is_page_limit(&self) -> bool {
+        self.page_limits.is_some()
+    }
+
     pub fn name(&self) -> &'a Ident {
         self.name
     }
 
-    pub fn ty(&self) -> &'a Type {
-        self.ty
+    pub fn stripped_ty(&self) -> &Type {
+        &self.stripped_ty
+    }
+
+    pub fn ty(&self) -> &Type {
+        &self.ty
     }
 
     pub fn attrs(&self) -> &'a Vec<Attribute> {
         self.attrs
     }
 }
+
+/// Maps the `Addr`, `Option<Addr>` and `Vec<Addr>` types to their unchecked `String` counterparts
+/// sent in the message.
+fn unchecked_addr_type(ty: &Type) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+
+    match (segment.ident.to_string().as_str(), &segment.arguments) {
+        ("Addr", PathArguments::None) => Some(parse_quote! { String }),
+        ("Option" | "Vec", PathArguments::AngleBracketed(args)) if args.args.len() == 1 => {
+            let GenericArgument::Type(inner) = args.args.first()? else {
+                return None;
+            };
+            let inner = unchecked_addr_type(inner)?;
+            let mut type_path = type_path.clone();
+            let segment = type_path.path.segments.last_mut()?;
+            segment.arguments = PathArguments::AngleBracketed(parse_quote! { <#inner> });
+            Some(Type::Path(type_path))
+        }
+        _ => None,
+    }
+}
+
+/// Checks if the type is the `Binary` or `Option<Binary>`, serialized as the `base64` string.
+fn is_binary(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| match &segment.arguments {
+            PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+                matches!(args.args.first(), Some(GenericArgument::Type(inner)) if is_binary(inner))
+            }
+            PathArguments::None => segment.ident == "Binary",
+            _ => false,
+        })
+}
+
+/// Checks if the type is generated with the `sylvia::fuzz::ArbitraryField`, i.e. it's one of the
+/// CosmWasm types not implementing `Arbitrary`, also wrapped in the `Option` or `Vec`.
+/// The types are recognized by their names.
+pub fn is_arbitrary_field(ty: &Type) -> bool {
+    const FIELD_TYPES: [&str; 7] = [
+        "Addr",
+        "Uint64",
+        "Uint128",
+        "Decimal",
+        "Coin",
+        "Binary",
+        "Timestamp",
+    ];
+
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| match &segment.arguments {
+            PathArguments::AngleBracketed(args)
+                if segment.ident == "Option" || segment.ident == "Vec" =>
+            {
+                matches!(args.args.first(), Some(GenericArgument::Type(inner)) if is_arbitrary_field(inner))
+            }
+            PathArguments::None => FIELD_TYPES.iter().any(|name| segment.ident == name),
+            _ => false,
+        })
+}
+
+/// Checks if the type is an `Option`, in which case the `sv::validate` rules apply to its value.
+pub fn is_option(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.path.segments.last().is_some_and(|segment| {
+        segment.ident == "Option" && matches!(segment.arguments, PathArguments::AngleBracketed(_))
+    })
+}
+
+/// Returns `T` if the type is `Option<T>`.
+pub fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+            match args.args.first() {
+                Some(GenericArgument::Type(inner)) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -19,13 +19,53 @@ impl MsgType {
             "migrate" => Ok(Self::Migrate),
             "reply" => Ok(Self::Reply ),
             "sudo" => Ok(Self::Sudo),
+            "ibc_channel_open" => Ok(Self::IbcChannelOpen),
+            "ibc_channel_connect" => Ok(Self::IbcChannelConnect),
+            "ibc_channel_close" => Ok(Self::IbcChannelClose),
+            "ibc_packet_receive" => Ok(Self::IbcPacketReceive),
+            "ibc_packet_ack" => Ok(Self::IbcPacketAck),
+            "ibc_packet_timeout" => Ok(Self::IbcPacketTimeout),
+            "ibc_packet" => Ok(Self::IbcPacket),
             _ => Err(Error::new(
                 msg_type.span(),
-                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply` or `sudo`.",
+                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply`, `sudo`, `ibc_packet` or one of the `ibc_*` handlers.",
             ))
         }
     }
 
+    /// All the IBC message types in the order of the IBC channel lifecycle.
+    pub const fn ibc_types() -> [Self; 6] {
+        [
+            Self::IbcChannelOpen,
+            Self::IbcChannelConnect,
+            Self::IbcChannelClose,
+            Self::IbcPacketReceive,
+            Self::IbcPacketAck,
+            Self::IbcPacketTimeout,
+        ]
+    }
+
+    pub fn is_ibc(self) -> bool {
+        Self::ibc_types().contains(&self)
+    }
+
+    /// Emits the `cosmwasm_std` type of the message received by the IBC entry point.
+    pub fn emit_ibc_msg_type(self) -> Option<TokenStream> {
+        use MsgType::*;
+
+        let sylvia = crate_module();
+
+        match self {
+            IbcChannelOpen => Some(quote! { #sylvia ::cw_std::IbcChannelOpenMsg }),
+            IbcChannelConnect => Some(quote! { #sylvia ::cw_std::IbcChannelConnectMsg }),
+            IbcChannelClose => Some(quote! { #sylvia ::cw_std::IbcChannelCloseMsg }),
+            IbcPacketReceive => Some(quote! { #sylvia ::cw_std::IbcPacketReceiveMsg }),
+            IbcPacketAck => Some(quote! { #sylvia ::cw_std::IbcPacketAckMsg }),
+            IbcPacketTimeout => Some(quote! { #sylvia ::cw_std::IbcPacketTimeoutMsg }),
+            Exec | Query | Instantiate | Migrate | Reply | Sudo | IbcPacket => None,
+        }
+    }
+
     pub fn emit_ctx_type(self, query_type: &Type) -> TokenStream {
         use MsgType::*;
 
@@ -35,12 +75,16 @@ impl MsgType {
             Exec | Instantiate => quote! {
                 (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env, #sylvia ::cw_std::MessageInfo)
             },
-            Migrate | Reply | Sudo => quote! {
+            Migrate | Reply | Sudo | IbcChannelOpen | IbcChannelConnect | IbcChannelClose
+            | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => quote! {
                 (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env)
             },
             Query => quote! {
                 (#sylvia ::cw_std::Deps< #query_type >, #sylvia ::cw_std::Env)
             },
+            IbcPacket => quote! {
+                (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env, #sylvia ::cw_std::IbcPacketReceiveMsg)
+            },
         }
     }
 
@@ -67,12 +111,16 @@ impl MsgType {
             Exec | Instantiate => quote! {
                 deps: #sylvia ::cw_std::DepsMut< #query_type>, env: #sylvia ::cw_std::Env, info: #sylvia ::cw_std::MessageInfo
             },
-            Migrate | Reply | Sudo => quote! {
+            Migrate | Reply | Sudo | IbcChannelOpen | IbcChannelConnect | IbcChannelClose
+            | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => quote! {
                 deps: #sylvia ::cw_std::DepsMut< #query_type>, env: #sylvia ::cw_std::Env
             },
             Query => quote! {
                 deps: #sylvia ::cw_std::Deps< #query_type>, env: #sylvia ::cw_std::Env
             },
+            IbcPacket => quote! {
+                deps: #sylvia ::cw_std::DepsMut< #query_type>, env: #sylvia ::cw_std::Env, msg: #sylvia ::cw_std::IbcPacketReceiveMsg
+            },
         }
     }
 
@@ -84,6 +132,13 @@ impl MsgType {
             Self::Sudo => parse_quote! { sudo },
             Self::Reply => parse_quote! { reply },
             Self::Query => parse_quote! { query },
+            Self::IbcChannelOpen => parse_quote! { ibc_channel_open },
+            Self::IbcChannelConnect => parse_quote! { ibc_channel_connect },
+            Self::IbcChannelClose => parse_quote! { ibc_channel_close },
+            Self::IbcPacketReceive => parse_quote! { ibc_packet_receive },
+            Self::IbcPacketAck => parse_quote! { ibc_packet_ack },
+            Self::IbcPacketTimeout => parse_quote! { ibc_packet_timeout },
+            Self::IbcPacket => parse_quote! { ibc_packet },
         }
     }
 
@@ -92,7 +147,11 @@ impl MsgType {
 
         match self {
             Exec | Instantiate => quote! { deps, env, info },
-            Migrate | Reply | Query | Sudo => quote! { deps, env },
+            Migrate | Reply | Query | Sudo | IbcChannelOpen | IbcChannelConnect
+            | IbcChannelClose | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => {
+                quote! { deps, env }
+            }
+            IbcPacket => quote! { deps, env, msg },
         }
     }
 
@@ -111,6 +170,15 @@ impl MsgType {
             Query => quote! {
                 std::result::Result<#sylvia ::cw_std::Binary, #err_type>
             },
+            IbcChannelOpen => quote! {
+                std::result::Result< #sylvia ::cw_std::IbcChannelOpenResponse, #err_type>
+            },
+            IbcPacketReceive | IbcPacket => quote! {
+                std::result::Result< #sylvia ::cw_std::IbcReceiveResponse <#msg_type>, #err_type>
+            },
+            IbcChannelConnect | IbcChannelClose | IbcPacketAck | IbcPacketTimeout => quote! {
+                std::result::Result< #sylvia ::cw_std::IbcBasicResponse <#msg_type>, #err_type>
+            },
         }
     }
 
@@ -131,6 +199,13 @@ impl MsgType {
             MsgType::Migrate => parse_quote! { MigrateMsg },
             MsgType::Reply => parse_quote! { ReplyMsg },
             MsgType::Sudo => parse_quote! { SudoMsg },
+            MsgType::IbcChannelOpen => parse_quote! { IbcChannelOpenMsg },
+            MsgType::IbcChannelConnect => parse_quote! { IbcChannelConnectMsg },
+            MsgType::IbcChannelClose => parse_quote! { IbcChannelCloseMsg },
+            MsgType::IbcPacketReceive => parse_quote! { IbcPacketReceiveMsg },
+            MsgType::IbcPacketAck => parse_quote! { IbcPacketAckMsg },
+            MsgType::IbcPacketTimeout => parse_quote! { IbcPacketTimeoutMsg },
+            MsgType::IbcPacket => parse_quote! { IbcPacketMsg },
         }
     }
 
@@ -151,6 +226,13 @@ impl MsgType {
             MsgType::Migrate => parse_quote! { Migrate },
             MsgType::Sudo => parse_quote! { Sudo },
             MsgType::Reply => parse_quote! { Reply },
+            MsgType::IbcChannelOpen => parse_quote! { IbcChannelOpen },
+            MsgType::IbcChannelConnect => parse_quote! { IbcChannelConnect },
+            MsgType::IbcChannelClose => parse_quote! { IbcChannelClose },
+            MsgType::IbcPacketReceive => parse_quote! { IbcPacketReceive },
+            MsgType::IbcPacketAck => parse_quote! { IbcPacketAck },
+            MsgType::IbcPacketTimeout => parse_quote! { IbcPacketTimeout },
+            MsgType::IbcPacket => parse_quote! { IbcPacket },
         }
     }
 
@@ -190,7 +272,15 @@ impl MsgType {
         }
     }
 
-    pub fn emit_dispatch_leg(&self, function_name: &Ident, args: &Vec<Ident>) -> TokenStream {
+    /// Emits the call of the handler dispatching the message.
+    ///
+    /// `packet_response` is set for the `ibc_packet` handlers returning the `PacketResponse`.
+    pub fn emit_dispatch_leg(
+        &self,
+        function_name: &Ident,
+        args: &Vec<Ident>,
+        packet_response: bool,
+    ) -> TokenStream {
         use MsgType::*;
         let sylvia = crate_module();
 
@@ -198,10 +288,22 @@ impl MsgType {
             Exec | Sudo => quote! {
                 contract.#function_name(Into::into(ctx), #(#args),*).map_err(Into::into)
             },
+            IbcPacket if packet_response => quote! {
+                contract.#function_name(Into::into(ctx), #(#args),*)?
+                    .into_receive_response()
+                    .map_err(Into::into)
+            },
+            IbcPacket => quote! {
+                #sylvia ::types::PacketAck::success(contract.#function_name(Into::into(ctx), #(#args),*)?)
+                    .to_binary()
+                    .map(#sylvia ::cw_std::IbcReceiveResponse::new)
+                    .map_err(Into::into)
+            },
             Query => quote! {
                 #sylvia ::cw_std::to_json_binary(&contract.#function_name(Into::into(ctx), #(#args),*)?).map_err(Into::into)
             },
-            Instantiate | Migrate | Reply => {
+            Instantiate | Migrate | Reply | IbcChannelOpen | IbcChannelConnect
+            | IbcChannelClose | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => {
                 emit_error!(function_name.span(), "Internal Error";
                 note = "Dispatch leg should be called only for `Enum` type messages.");
                 quote! {}
@@ -1,18 +1,26 @@
 use crate::crate_module;
 use crate::fold::StripSelfPath;
-use crate::parser::attributes::VariantAttrForwarding;
+use crate::parser::attributes::msg::PageLimits;
+use crate::parser::attributes::{
+    DeprecatedAttr, FundsPolicyAttr, GuardAttr, VariantAttrForwarding,
+};
 use crate::parser::check_generics::{CheckGenerics, GetPath};
-use crate::parser::variant_descs::VariantDescs;
+use crate::parser::variant_descs::{VariantDesc, VariantDescs};
 use crate::parser::{process_fields, MsgAttr, MsgType};
-use crate::utils::{extract_return_type, filter_wheres, SvCasing};
+use crate::utils::{
+    emit_bracketed_generics, extract_return_type, filter_wheres, json_schema_name,
+    returns_packet_response, SvCasing,
+};
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
 use quote::{quote, ToTokens};
 use syn::fold::Fold;
+use syn::spanned::Spanned;
 use syn::visit::Visit;
-use syn::{parse_quote, Ident, Signature, Type, WhereClause, WherePredicate};
+use syn::{parse_quote, Ident, LitStr, Path, Type, WhereClause, WherePredicate};
 
-use super::msg_field::MsgField;
+use super::msg_field::{is_arbitrary_field, is_option, MsgField};
 
 /// Representation of whole message variant
 #[derive(Debug)]
@@ -25,23 +33,49 @@ pub struct MsgVariant<'a> {
     return_type: Option<Type>,
     msg_attr: MsgAttr,
     attrs_to_forward: Vec<VariantAttrForwarding>,
+    /// Checks called by the generated dispatch before the method.
+    guards: Vec<GuardAttr>,
+    /// Funds policy of the `exec` and `instantiate` methods.
+    funds: Option<FundsPolicyAttr>,
+    /// Whether the `exec` method can be called while the contract is paused.
+    allow_when_paused: bool,
+    /// Deprecation of the message reflected in the generated types and the schema.
+    deprecated: Option<DeprecatedAttr>,
+    /// Former names of the message still accepted on deserialization.
+    aliases: Vec<LitStr>,
+    /// Events the method may emit.
+    events: Vec<Path>,
+    /// Whether the `ibc_packet` method returns the `PacketResponse`.
+    packet_response: bool,
 }
 
 impl<'a> MsgVariant<'a> {
     /// Creates new message variant from trait method
     pub fn new<Generic>(
-        sig: &'a Signature,
+        variant_desc: VariantDesc<'a>,
         generics_checker: &mut CheckGenerics<Generic>,
         msg_attr: MsgAttr,
-        attrs_to_forward: Vec<VariantAttrForwarding>,
+        funds: Option<FundsPolicyAttr>,
     ) -> MsgVariant<'a>
     where
         Generic: GetPath + PartialEq,
     {
+        let attrs_to_forward = variant_desc.attrs_to_forward();
+        let guards = variant_desc.guards();
+        let allow_when_paused = variant_desc.allow_when_paused();
+        let deprecated = variant_desc.deprecated();
+        let aliases = variant_desc.aliases();
+        let events = variant_desc.events();
+        let sig = variant_desc.into_sig();
         let function_name = &sig.ident;
+        let packet_response =
+            msg_attr.msg_type() == MsgType::IbcPacket && returns_packet_response(&sig.output);
 
         let name = function_name.to_case(Case::UpperCamel);
-        let fields = process_fields(sig, generics_checker);
+        let mut fields = process_fields(sig, generics_checker);
+        if let Some(page_limits) = msg_attr.paginated() {
+            set_pagination(&mut fields, page_limits, function_name);
+        }
 
         let return_type = if msg_attr.msg_type() == MsgType::Query {
             let resp_type = &msg_attr.resp_type();
@@ -69,6 +103,13 @@ impl<'a> MsgVariant<'a> {
             return_type,
             msg_attr,
             attrs_to_forward,
+            guards,
+            funds,
+            allow_when_paused,
+            deprecated,
+            aliases,
+            events,
+            packet_response,
         }
     }
 
@@ -80,13 +121,22 @@ impl<'a> MsgVariant<'a> {
             msg_attr,
             return_type,
             attrs_to_forward,
+            funds,
+            aliases,
             ..
         } = self;
         let fields = fields.iter().map(MsgField::emit);
         let returns_attribute = msg_attr.msg_type().emit_returns_attribute(return_type);
         let attrs_to_forward = attrs_to_forward.iter().map(|attr| &attr.attrs);
+        let funds_doc = funds.as_ref().map(FundsPolicyAttr::emit_doc);
+        let events_doc = self.emit_events_doc();
+        let deprecated = self.emit_deprecated();
 
         quote! {
+            #funds_doc
+            #events_doc
+            #deprecated
+            #( #[serde(alias = #aliases)] )*
             #returns_attribute
             #( #[ #attrs_to_forward ] )*
             #name {
@@ -104,6 +154,7 @@ impl<'a> MsgVariant<'a> {
             fields,
             function_name,
             msg_attr,
+            packet_response,
             ..
         } = self;
 
@@ -113,13 +164,27 @@ impl<'a> MsgVariant<'a> {
             .map(|(field, num)| Ident::new(&format!("field{}", num), field.name().span()))
             .collect();
 
+        let checks = self.emit_checks(&args.iter().collect::<Vec<_>>());
+
         let fields = fields
             .iter()
             .map(MsgField::name)
             .zip(args.clone())
             .map(|(field, num_field)| quote!(#field : #num_field));
 
-        let method_call = msg_attr.msg_type().emit_dispatch_leg(function_name, &args);
+        let method_call =
+            msg_attr
+                .msg_type()
+                .emit_dispatch_leg(function_name, &args, *packet_response);
+        let method_call = match checks.is_empty() {
+            true => method_call,
+            false => quote! {
+                {
+                    #checks
+                    #method_call
+                }
+            },
+        };
 
         quote! {
             #name {
@@ -135,14 +200,98 @@ impl<'a> MsgVariant<'a> {
         let method_name = name.to_case(Case::Snake);
         let parameters = fields.iter().map(MsgField::emit_method_field);
         let arguments = fields.iter().map(MsgField::name);
+        let deprecated = self.emit_deprecated();
 
         quote! {
+            #deprecated
             pub fn #method_name( #(#parameters),*) -> Self {
                 Self :: #name { #(#arguments),* }
             }
         }
     }
 
+    /// Emits the `deprecated` attribute if the message is marked with `sv::deprecated`.
+    pub fn emit_deprecated(&self) -> Option<TokenStream> {
+        self.deprecated.as_ref().map(DeprecatedAttr::emit)
+    }
+
+    /// Emits the generic parameter of the `ReadyExecutorBuilderState` if the message declares
+    /// its data with `#[sv::msg(exec, data = ...)]`.
+    pub fn emit_executor_data_type(&self) -> Option<TokenStream> {
+        self.msg_attr
+            .data_type()
+            .map(|data_type| quote! { < #data_type > })
+    }
+
+    /// Emits the `doc` attribute listing the events declared with `sv::events`.
+    /// It ends up as a description of the message in the generated schema.
+    pub fn emit_events_doc(&self) -> Option<TokenStream> {
+        if self.events.is_empty() {
+            return None;
+        }
+
+        let events = self
+            .events
+            .iter()
+            .filter_map(|event| event.segments.last())
+            .map(|segment| format!("`{}`", segment.ident))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let doc = format!("Emits events: {events}.");
+
+        Some(quote! { #[doc = #doc] })
+    }
+
+    /// Emits the name of the message paired with the types of the events it may emit.
+    pub fn emit_events_entry(&self) -> Option<TokenStream> {
+        if self.events.is_empty() {
+            return None;
+        }
+
+        let sylvia = crate_module();
+        let name = self.function_name.to_string();
+        let events = &self.events;
+
+        Some(quote! {
+            (#name, vec![#(<#events as #sylvia ::events::TypedEvent>::EVENT_TYPE),*])
+        })
+    }
+
+    /// Emits the signature of the `<query>_iter` method iterating over the pages of
+    /// the paginated query, without the `start_after` and `limit` parameters.
+    pub fn emit_page_iter_signature(&self, error_type: &TokenStream) -> Option<TokenStream> {
+        self.msg_attr.paginated()?;
+
+        let sylvia = crate_module();
+        let method_name = Ident::new(
+            &format!("{}_iter", self.name.to_case(Case::Snake)),
+            self.name.span(),
+        );
+        let return_type = &self.return_type;
+        let parameters = self
+            .fields
+            .iter()
+            .filter(|field| !field.is_page_limit() && field.name() != "start_after")
+            .map(MsgField::emit_method_field_folded);
+
+        Some(quote! {
+            fn #method_name(&self, #(#parameters),*) -> #sylvia ::types::PageIter<'_, <#return_type as #sylvia ::types::Paginated>::Item, #error_type>
+        })
+    }
+
+    /// Emits the arguments of the message constructor called by the `<query>_iter` method.
+    /// The `start_after` is expected to be in scope, while the other parameters are cloned.
+    pub fn emit_page_iter_arguments(&self) -> Vec<TokenStream> {
+        self.fields
+            .iter()
+            .map(|field| match field.name() {
+                _ if field.is_page_limit() => quote! { None },
+                name if name == "start_after" => quote! { start_after },
+                name => quote! { #name .clone() },
+            })
+            .collect()
+    }
+
     pub fn as_fields_names(&self) -> Vec<&Ident> {
         self.fields.iter().map(MsgField::name).collect()
     }
@@ -158,11 +307,48 @@ impl<'a> MsgVariant<'a> {
         &self.name
     }
 
+    /// Emits the checks done by the generated dispatch before calling the method,
+    /// shared by the enum and struct messages.
+    ///
+    /// The funds and guards are checked first, so the fields are validated only for the callers
+    /// allowed to call the method. The validated fields are rebound under the `args` names.
+    pub fn emit_checks(&self, args: &[&Ident]) -> TokenStream {
+        let sylvia = crate_module();
+
+        let funds_check = self.funds.as_ref().map(|funds| {
+            let funds = funds.emit();
+            quote! { #funds .check(&ctx.2)?; }
+        });
+        let guards_check = (!self.guards.is_empty()).then(|| {
+            let guards = self.guards.iter().map(|guard| &guard.path);
+            quote! {
+                let ctx: #sylvia ::ctx::ExecCtx<_> = Into::into(ctx);
+                #( #guards (&ctx, contract)?; )*
+                let ctx = (ctx.deps, ctx.env, ctx.info);
+            }
+        });
+        let validations = self
+            .fields
+            .iter()
+            .zip(args)
+            .filter_map(|(field, arg)| field.emit_validation(arg));
+
+        quote! {
+            #funds_check
+            #guards_check
+            #(#validations)*
+        }
+    }
+
+    pub fn funds(&self) -> Option<&FundsPolicyAttr> {
+        self.funds.as_ref()
+    }
+
     pub fn function_name(&self) -> &Ident {
         self.function_name
     }
 
-    pub fn fields(&self) -> &Vec<MsgField> {
+    pub fn fields(&self) -> &Vec<MsgField<'_>> {
         &self.fields
     }
 
@@ -173,6 +359,52 @@ impl<'a> MsgVariant<'a> {
     pub fn return_type(&self) -> &Option<Type> {
         &self.return_type
     }
+
+    /// Emits the fields of the message generated by the `Arbitrary` implementation.
+    /// Expects the `u` in scope.
+    ///
+    /// The `sv::addr` fields are generated as the validated addresses, so they pass the dispatch.
+    /// The CosmWasm types are generated with the `ArbitraryField`, other ones with `Arbitrary`.
+    pub fn emit_arbitrary_fields(&self) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.fields
+            .iter()
+            .map(|field| {
+                let name = field.name();
+                let ty = field.stripped_ty();
+                match field.is_addr() {
+                    true => quote! {
+                        #name: #sylvia ::fuzz::UncheckedAddr::unchecked(
+                            << #ty as #sylvia ::types::ValidateAddr>::Validated as #sylvia ::fuzz::ArbitraryField>::arbitrary_field(u)?
+                        )
+                    },
+                    false if is_arbitrary_field(ty) => quote! {
+                        #name: <#ty as #sylvia ::fuzz::ArbitraryField>::arbitrary_field(u)?
+                    },
+                    false => quote! {
+                        #name: <#ty as #sylvia ::arbitrary::Arbitrary>::arbitrary(u)?
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Emits the bounds of the `Arbitrary` implementation required by the fields, which are not
+    /// generated with the `ArbitraryField`.
+    ///
+    /// The message implements `Arbitrary` only if all of its fields do.
+    pub fn emit_arbitrary_bounds(&self) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.fields
+            .iter()
+            .filter(|field| !field.is_addr())
+            .map(MsgField::stripped_ty)
+            .filter(|ty| !is_arbitrary_field(ty))
+            .map(|ty| quote! { #ty: #sylvia ::arbitrary::Arbitrary<'sv_arbitrary> })
+            .collect()
+    }
 }
 
 #[derive(Debug)]
@@ -198,21 +430,25 @@ where
         let variants: Vec<_> = source
             .filter_map(|variant_desc| {
                 let msg_attr: MsgAttr = variant_desc.attr_msg()?;
-                let attrs_to_forward = variant_desc.attrs_to_forward();
+                let funds = variant_desc
+                    .funds()
+                    .filter(|_| matches!(msg_ty, MsgType::Exec | MsgType::Instantiate));
 
                 if msg_attr.msg_type() != msg_ty {
                     return None;
                 }
 
                 Some(MsgVariant::new(
-                    variant_desc.into_sig(),
+                    variant_desc,
                     &mut generics_checker,
                     msg_attr,
-                    attrs_to_forward,
+                    funds,
                 ))
             })
             .collect();
 
+        check_aliases(&variants);
+
         let (used_generics, unused_generics) = generics_checker.used_unused();
         let where_predicates = filter_wheres(unfiltered_where_clause, all_generics, &used_generics);
 
@@ -234,10 +470,19 @@ where
         }
     }
 
-    pub fn variants(&self) -> impl Iterator<Item = &MsgVariant> {
+    pub fn variants(&self) -> impl Iterator<Item = &MsgVariant<'_>> {
         self.variants.iter()
     }
 
+    /// Checks if any message has the `sv::addr` field, so the error type of the dispatch
+    /// has to be convertible from the `InvalidAddr`.
+    pub fn has_addr_fields(&self) -> bool {
+        self.variants
+            .iter()
+            .flat_map(|variant| &variant.fields)
+            .any(MsgField::is_addr)
+    }
+
     pub fn used_generics(&self) -> &Vec<&'a Generic> {
         &self.used_generics
     }
@@ -267,10 +512,44 @@ where
             .map(|variant| variant.emit_dispatch_leg())
     }
 
+    /// Names of the messages accepted on deserialization, including the aliases.
     pub fn as_names_snake_cased(&self) -> Vec<String> {
         self.variants
             .iter()
-            .map(|variant| variant.name.to_string().to_case(Case::Snake))
+            .flat_map(|variant| {
+                let name = variant.name.to_string().to_case(Case::Snake);
+                let aliases = variant.aliases.iter().map(LitStr::value);
+                std::iter::once(name).chain(aliases)
+            })
+            .collect()
+    }
+
+    /// Signatures of the messages used to compute the interface id.
+    ///
+    /// Each signature describes the message as it's sent over the wire: the entry point,
+    /// the message name, its fields sorted by name and, for queries, the response, all with
+    /// the JSON schema names of their types, e.g. `query count(owner: String) -> uint64`.
+    /// Renaming the Rust types or reordering the parameters doesn't change the signature.
+    pub fn as_signatures(&self) -> Vec<String> {
+        let ep_name = self.msg_ty.emit_ep_name().to_string();
+        self.variants
+            .iter()
+            .map(|variant| {
+                let mut fields: Vec<_> = variant
+                    .fields
+                    .iter()
+                    .map(|field| format!("{}: {}", field.name(), json_schema_name(field.ty())))
+                    .collect();
+                fields.sort();
+                let fields = fields.join(", ");
+                let return_type = variant
+                    .return_type
+                    .as_ref()
+                    .map(|ty| format!(" -> {}", json_schema_name(ty)))
+                    .unwrap_or_default();
+                let name = variant.name.to_string().to_case(Case::Snake);
+                format!("{ep_name} {name}({fields}){return_type}")
+            })
             .collect()
     }
 
@@ -284,7 +563,184 @@ where
         self.variants.iter().map(MsgVariant::emit)
     }
 
-    pub fn get_only_variant(&self) -> Option<&MsgVariant> {
+    /// Emits `ts_methods` describing the `exec` and `query` messages for the TypeScript client.
+    pub fn emit_ts_methods(&self, enum_name: &Ident) -> TokenStream {
+        if !cfg!(feature = "typescript") || !matches!(self.msg_ty, MsgType::Exec | MsgType::Query) {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let Self {
+            variants,
+            used_generics,
+            where_predicates,
+            ..
+        } = self;
+
+        let bracketed_used_generics = emit_bracketed_generics(used_generics);
+        let schema_predicates = used_generics
+            .iter()
+            .filter_map(|generic| generic.get_path())
+            .map(|generic| quote! { #generic: #sylvia ::schemars::JsonSchema });
+
+        let methods = variants.iter().map(|variant| {
+            let name = variant.name.to_string().to_case(Case::Snake);
+            let params = variant.fields.iter().map(|field| {
+                let field_name = field.name().to_string();
+                let ty = field.stripped_ty();
+                quote! { (#field_name, ts_gen.ts_type::<#ty>()) }
+            });
+            let response = match &variant.return_type {
+                Some(return_type) => {
+                    let return_type = StripSelfPath.fold_type(return_type.clone());
+                    quote! { Some(ts_gen.ts_type::<#return_type>()) }
+                }
+                None => quote! { None },
+            };
+            quote! { #sylvia ::typescript::TsMethod::new(#name, vec![#(#params),*], #response) }
+        });
+
+        quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            impl #bracketed_used_generics #enum_name #bracketed_used_generics
+            where
+                #(#where_predicates,)*
+                #(#schema_predicates,)*
+            {
+                /// Describes the messages for the generated TypeScript client.
+                #[allow(unused_variables)]
+                pub fn ts_methods(ts_gen: &mut #sylvia ::typescript::TsGenerator) -> Vec<#sylvia ::typescript::TsMethod> {
+                    vec![#(#methods),*]
+                }
+            }
+        }
+    }
+
+    /// Emits the `Arbitrary` implementation of the message used in the property-based tests.
+    ///
+    /// Generic messages are skipped, as the generated values would depend on the generic types.
+    pub fn emit_arbitrary_impl(&self, msg_name: &Ident) -> TokenStream {
+        if !cfg!(feature = "arbitrary") || !self.used_generics.is_empty() {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let generated_variants = match self.msg_ty {
+            MsgType::Instantiate | MsgType::Migrate => &self.variants[..self.variants.len().min(1)],
+            _ => &self.variants[..],
+        };
+        let bounds = generated_variants
+            .iter()
+            .flat_map(MsgVariant::emit_arbitrary_bounds);
+        let body = match self.msg_ty {
+            MsgType::Instantiate | MsgType::Migrate => {
+                let fields = self
+                    .variants
+                    .first()
+                    .map(MsgVariant::emit_arbitrary_fields)
+                    .unwrap_or_default();
+                quote! { Ok(Self { #(#fields,)* }) }
+            }
+            _ if self.variants.is_empty() => {
+                quote! { Err(#sylvia ::arbitrary::Error::EmptyChoose) }
+            }
+            _ => {
+                let arms = self.variants.iter().enumerate().map(|(idx, variant)| {
+                    let name = &variant.name;
+                    let fields = variant.emit_arbitrary_fields();
+                    quote! { #idx => Ok(Self:: #name { #(#fields,)* }) }
+                });
+                let variants_cnt = self.variants.len();
+                quote! {
+                    match u.choose_index(#variants_cnt)? {
+                        #(#arms,)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        };
+
+        quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            impl<'sv_arbitrary> #sylvia ::arbitrary::Arbitrary<'sv_arbitrary> for #msg_name
+            where
+                #(#bounds,)*
+            {
+                #[allow(unused_variables)]
+                fn arbitrary(u: &mut #sylvia ::arbitrary::Unstructured<'sv_arbitrary>) -> #sylvia ::arbitrary::Result<Self> {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Emits `ExecFunds` implementation followed by the `ExecSequence` when sending the message.
+    pub fn emit_exec_funds(&self, enum_name: &Ident) -> TokenStream {
+        if !cfg!(feature = "arbitrary") || self.msg_ty != MsgType::Exec {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let arms = self.variants.iter().map(|variant| {
+            let name = &variant.name;
+            let funds = variant
+                .funds
+                .as_ref()
+                .map(FundsPolicyAttr::emit)
+                .unwrap_or_else(|| quote! { Default::default() });
+            quote! { Self:: #name { .. } => #funds }
+        });
+        let phantom_arm = (!self.used_generics.is_empty())
+            .then(|| quote! { Self::_Phantom(_) => Default::default(), });
+
+        let bracketed_used_generics = emit_bracketed_generics(&self.used_generics);
+        let where_clause = self.where_clause();
+
+        quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            impl #bracketed_used_generics #sylvia ::fuzz::ExecFunds for #enum_name #bracketed_used_generics #where_clause {
+                fn funds_policy(&self) -> #sylvia ::types::FundsPolicy {
+                    match *self {
+                        #(#arms,)*
+                        #phantom_arm
+                    }
+                }
+            }
+        }
+    }
+
+    /// Emits `AllowWhenPaused` implementation checked by the dispatch of the `pausable` contracts.
+    pub fn emit_allowed_when_paused(&self, enum_name: &Ident) -> TokenStream {
+        if self.msg_ty != MsgType::Exec {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let allowed: Vec<_> = self
+            .variants
+            .iter()
+            .filter(|variant| variant.allow_when_paused)
+            .map(|variant| &variant.name)
+            .collect();
+
+        let body = match allowed.is_empty() {
+            true => quote! { false },
+            false => quote! { matches!(self, #(Self:: #allowed { .. })|*) },
+        };
+
+        let bracketed_used_generics = emit_bracketed_generics(&self.used_generics);
+        let where_clause = self.where_clause();
+
+        quote! {
+            impl #bracketed_used_generics #sylvia ::pausable::AllowWhenPaused for #enum_name #bracketed_used_generics #where_clause {
+                fn allowed_when_paused(&self) -> bool {
+                    #body
+                }
+            }
+        }
+    }
+
+    pub fn get_only_variant(&self) -> Option<&MsgVariant<'_>> {
         self.variants.first()
     }
 
@@ -311,3 +767,54 @@ where
         }
     }
 }
+
+/// Checks that the paginated query declares the `start_after` and `limit` parameters
+/// and turns the `limit` into the optional parameter of the message.
+fn set_pagination(fields: &mut [MsgField], page_limits: PageLimits, function_name: &Ident) {
+    match fields.iter().find(|field| field.name() == "start_after") {
+        Some(field) if !is_option(field.ty()) => emit_error!(
+            field.ty().span(), "The `start_after` parameter of the paginated query has to be an `Option`";
+            note = "Expected `start_after: Option<T>`"
+        ),
+        Some(_) => (),
+        None => emit_error!(
+            function_name.span(), "Missing `start_after` parameter of the paginated query";
+            note = "Expected `start_after: Option<T>` parameter"
+        ),
+    }
+
+    match fields.iter_mut().find(|field| field.name() == "limit") {
+        Some(field) if !is_u32(field.ty()) => emit_error!(
+            field.ty().span(), "The `limit` parameter of the paginated query has to be `u32`";
+            note = "The limit is optional in the message and clamped to the page limits before calling the method"
+        ),
+        Some(field) => field.set_page_limits(page_limits),
+        None => emit_error!(
+            function_name.span(), "Missing `limit` parameter of the paginated query";
+            note = "Expected `limit: u32` parameter"
+        ),
+    }
+}
+
+fn is_u32(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("u32"))
+}
+
+/// Checks that the aliases don't overlap with names of the other messages.
+fn check_aliases(variants: &[MsgVariant]) {
+    let mut names: Vec<_> = variants
+        .iter()
+        .map(|variant| variant.name.to_string().to_case(Case::Snake))
+        .collect();
+
+    for alias in variants.iter().flat_map(|variant| &variant.aliases) {
+        let alias_name = alias.value();
+        if names.contains(&alias_name) {
+            emit_error!(
+                alias.span(), "Alias `{}` is already used by another message", alias_name;
+                note = "Aliases have to be unique among the messages of the same type"
+            );
+        }
+        names.push(alias_name);
+    }
+}
@@ -72,6 +72,35 @@ pub fn extract_return_type(ret_type: &ReturnType) -> &Path {
     &type_path.path
 }
 
+/// Checks if the `Ok` type of the `Result` returned by the method is the `PacketResponse`.
+///
+/// The type is checked as written in the signature, so an alias of the `PacketResponse`
+/// is treated as the plain acknowledgement.
+pub fn returns_packet_response(ret_type: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = ret_type else {
+        return false;
+    };
+    let Type::Path(type_path) = ty.as_ref() else {
+        return false;
+    };
+    let Some(PathArguments::AngleBracketed(args)) = type_path
+        .path
+        .segments
+        .last()
+        .map(|segment| &segment.arguments)
+    else {
+        return false;
+    };
+    let Some(GenericArgument::Type(Type::Path(ok_type))) = args.args.first() else {
+        return false;
+    };
+    ok_type
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "PacketResponse")
+}
+
 /// Creates [`Option<WhereClause>`] based on the provided predicates.
 /// Returns [`None`] if predicates array is empty.
 pub fn as_where_clause(where_predicates: &[&WherePredicate]) -> Option<WhereClause> {
@@ -108,6 +137,103 @@ pub fn emit_turbofish(ty: &Type, generics: &[&GenericParam]) -> Type {
     }
 }
 
+/// Renders the type the way it's usually written in the source, e.g. `Vec<String>` instead of
+/// the `Vec < String >` produced by the [TokenStream], so it's stable for the error messages.
+pub fn type_to_string(ty: &Type) -> String {
+    const SPACING: [(&str, &str); 12] = [
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        (" ::", "::"),
+        (":: ", "::"),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        ("& ", "&"),
+        (" ;", ";"),
+    ];
+
+    SPACING
+        .iter()
+        .fold(ty.to_token_stream().to_string(), |ty, (from, to)| {
+            ty.replace(from, to)
+        })
+}
+
+/// Name of the JSON schema `schemars` generates for the type, e.g. `uint32` for `u32`,
+/// `Array_of_String` for `Vec<String>` or `Nullable_Coin` for `Option<cosmwasm_std::Coin>`.
+///
+/// It depends only on how the type is serialized, so `&str`, `String` and `std::string::String`
+/// have the same name.
+/// Types not known to the macro are named after the last segment of their path.
+pub fn json_schema_name(ty: &Type) -> String {
+    match ty {
+        Type::Reference(reference) => json_schema_name(&reference.elem),
+        Type::Paren(paren) => json_schema_name(&paren.elem),
+        Type::Group(group) => json_schema_name(&group.elem),
+        Type::Slice(slice) => format!("Array_of_{}", json_schema_name(&slice.elem)),
+        Type::Array(array) => {
+            let len = &array.len;
+            format!(
+                "Array_size_{}_of_{}",
+                quote! { #len },
+                json_schema_name(&array.elem)
+            )
+        }
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "Null".to_owned(),
+        Type::Tuple(tuple) => {
+            let elems: Vec<_> = tuple.elems.iter().map(json_schema_name).collect();
+            format!("Tuple_of_{}", elems.join("_and_"))
+        }
+        Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => path_segment_schema_name(segment),
+            None => type_to_string(ty),
+        },
+        _ => type_to_string(ty),
+    }
+}
+
+fn path_segment_schema_name(segment: &syn::PathSegment) -> String {
+    let args: Vec<_> = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(json_schema_name(ty)),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    let name = segment.ident.to_string();
+
+    match (name.as_str(), args.as_slice()) {
+        ("str" | "String", _) => "String".to_owned(),
+        ("bool", _) => "Boolean".to_owned(),
+        ("char", _) => "Character".to_owned(),
+        ("f32", _) => "float".to_owned(),
+        ("f64", _) => "double".to_owned(),
+        ("u8" | "u16" | "u32" | "u64" | "u128", _) => format!("uint{}", &name[1..]),
+        ("i8" | "i16" | "i32" | "i64" | "i128", _) => format!("int{}", &name[1..]),
+        ("usize", _) => "uint".to_owned(),
+        ("isize", _) => "int".to_owned(),
+        (
+            "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock" | "Cow" | "Wrapping"
+            | "Reverse",
+            [inner],
+        ) => inner.clone(),
+        ("Option", [inner]) => format!("Nullable_{inner}"),
+        ("Vec" | "VecDeque" | "LinkedList" | "BinaryHeap", [inner]) => format!("Array_of_{inner}"),
+        ("BTreeSet" | "HashSet", [inner]) => format!("Set_of_{inner}"),
+        ("BTreeMap" | "HashMap", [_, value]) => format!("Map_of_{value}"),
+        ("Result", [ok, err]) => format!("Result_of_{ok}_or_{err}"),
+        (_, []) => name,
+        (_, args) => format!("{name}_for_{}", args.join("_and_")),
+    }
+}
+
 /// Trait for converting `Ident` to different cases preserving original [proc_macro2::Span].
 pub trait SvCasing {
     fn to_case(&self, case: convert_case::Case) -> Self;
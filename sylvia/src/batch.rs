@@ -0,0 +1,274 @@
+//! Batches of queries sent to the other contracts.
+//!
+//! [QueryBatch] collects the queries, e.g. the methods of the generated `Querier` traits called
+//! on the [BoundQuerier](crate::types::BoundQuerier), and executes them returning the tuple of
+//! their results. Every query keeps its own result, so a failure of one of them doesn't affect
+//! the others.
+//!
+//! Identical queries can be memoized with [QueryBatch::memoized], or with the [QueryCache] shared
+//! by several batches within the same transaction. Contract state doesn't change while the
+//! queries are executed, so the cached response is sent back instead of querying the contract
+//! again.
+//!
+//! ## Example usage
+//! ```rust
+//! use sylvia::batch::QueryBatch;
+//! use sylvia::cw_std::{Addr, Deps, Empty, StdResult};
+//! use sylvia::types::BoundQuerier;
+//!
+//! fn count_interfaces(deps: Deps, first: &Addr, second: &Addr) -> StdResult<usize> {
+//!     let (first, second) = QueryBatch::new(&deps.querier)
+//!         .memoized()
+//!         .query(|querier| BoundQuerier::<_, Empty>::borrowed(first, querier).supported_interfaces())
+//!         .query(|querier| BoundQuerier::<_, Empty>::borrowed(second, querier).supported_interfaces())
+//!         .execute();
+//!
+//!     // Contract which fails to answer supports no interfaces
+//!     Ok(first?.len() + second.unwrap_or_default().len())
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use cosmwasm_std::{CustomQuery, Querier, QuerierResult, QuerierWrapper};
+
+/// Queries collected to be executed together.
+///
+/// Created with [QueryBatch::new] or [BoundQuerier::batch](crate::types::BoundQuerier::batch).
+/// Every query is a closure receiving the [QuerierWrapper] it should be sent through.
+#[must_use]
+pub struct QueryBatch<'a, C: CustomQuery, Queries = ()> {
+    querier: &'a QuerierWrapper<'a, C>,
+    cache: Option<&'a QueryCache>,
+    memoize: bool,
+    queries: Queries,
+}
+
+impl<'a, C: CustomQuery> QueryBatch<'a, C> {
+    pub fn new(querier: &'a QuerierWrapper<'a, C>) -> Self {
+        Self {
+            querier,
+            cache: None,
+            memoize: false,
+            queries: (),
+        }
+    }
+}
+
+impl<'a, C: CustomQuery, Queries> QueryBatch<'a, C, Queries> {
+    /// Sends the identical queries of the batch only once.
+    pub fn memoized(self) -> Self {
+        Self {
+            memoize: true,
+            ..self
+        }
+    }
+
+    /// Memoizes the queries in the `cache`, which can be shared with the other batches.
+    pub fn with_cache(self, cache: &'a QueryCache) -> Self {
+        Self {
+            cache: Some(cache),
+            ..self
+        }
+    }
+
+    /// Adds the query to the batch.
+    ///
+    /// Its result is placed in the tuple returned by [QueryBatch::execute] in the order
+    /// of adding the queries.
+    pub fn query<Query, Output>(self, query: Query) -> QueryBatch<'a, C, Queries::Output>
+    where
+        Queries: Append<Query>,
+        Query: FnOnce(&QuerierWrapper<'_, C>) -> Output,
+    {
+        QueryBatch {
+            querier: self.querier,
+            cache: self.cache,
+            memoize: self.memoize,
+            queries: self.queries.append(query),
+        }
+    }
+
+    /// Executes the queries and returns the tuple of their results.
+    pub fn execute(self) -> Queries::Results
+    where
+        Queries: BatchQueries<C>,
+    {
+        let local_cache = QueryCache::default();
+        let cache = match (self.cache, self.memoize) {
+            (Some(cache), _) => cache,
+            (None, true) => &local_cache,
+            (None, false) => return self.queries.execute(self.querier),
+        };
+
+        let querier = cache.querier(&**self.querier);
+        self.queries.execute(&QuerierWrapper::new(&querier))
+    }
+}
+
+/// Responses of the queries memoized in the scope of the transaction.
+///
+/// ```rust
+/// # use sylvia::batch::{QueryBatch, QueryCache};
+/// # use sylvia::cw_std::{Addr, Deps, Empty, StdResult};
+/// # use sylvia::types::BoundQuerier;
+/// #
+/// fn interfaces(deps: Deps, addr: &Addr) -> StdResult<Vec<String>> {
+///     let cache = QueryCache::default();
+///     let querier = BoundQuerier::<_, Empty>::borrowed(addr, &deps.querier);
+///
+///     let (interfaces,) = querier
+///         .batch()
+///         .with_cache(&cache)
+///         .query(|querier| BoundQuerier::<_, Empty>::borrowed(addr, querier).supported_interfaces())
+///         .execute();
+///
+///     // Answered out of the cache
+///     let (again,) = querier
+///         .batch()
+///         .with_cache(&cache)
+///         .query(|querier| BoundQuerier::<_, Empty>::borrowed(addr, querier).supported_interfaces())
+///         .execute();
+///
+///     assert_eq!(cache.len(), 1);
+///     assert_eq!(interfaces, again);
+///     interfaces
+/// }
+/// ```
+#[derive(Default)]
+pub struct QueryCache {
+    responses: RefCell<HashMap<Vec<u8>, QuerierResult>>,
+}
+
+impl QueryCache {
+    /// Wraps the `querier` so its responses are memoized in the cache.
+    pub fn querier<'a>(&'a self, querier: &'a dyn Querier) -> CachedQuerier<'a> {
+        CachedQuerier {
+            querier,
+            cache: self,
+        }
+    }
+
+    /// Number of the distinct queries memoized in the cache.
+    pub fn len(&self) -> usize {
+        self.responses.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.responses.borrow().is_empty()
+    }
+}
+
+/// [Querier] answering the queries already sent out of the [QueryCache].
+pub struct CachedQuerier<'a> {
+    querier: &'a dyn Querier,
+    cache: &'a QueryCache,
+}
+
+impl Querier for CachedQuerier<'_> {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        if let Some(response) = self.cache.responses.borrow().get(bin_request) {
+            return response.clone();
+        }
+
+        let response = self.querier.raw_query(bin_request);
+        self.cache
+            .responses
+            .borrow_mut()
+            .insert(bin_request.to_vec(), response.clone());
+        response
+    }
+}
+
+/// Tuple of the queries extended with the next one by [QueryBatch::query].
+pub trait Append<Query> {
+    type Output;
+
+    fn append(self, query: Query) -> Self::Output;
+}
+
+/// Tuple of the queries executed by [QueryBatch::execute].
+pub trait BatchQueries<C: CustomQuery> {
+    /// Tuple of the results of the queries.
+    type Results;
+
+    fn execute(self, querier: &QuerierWrapper<'_, C>) -> Self::Results;
+}
+
+macro_rules! impl_batch_queries {
+    ($($query:ident: $output:ident),*) => {
+        impl<$($query,)* Query> Append<Query> for ($($query,)*) {
+            type Output = ($($query,)* Query,);
+
+            #[allow(non_snake_case)]
+            fn append(self, query: Query) -> Self::Output {
+                let ($($query,)*) = self;
+                ($($query,)* query,)
+            }
+        }
+
+        impl<C: CustomQuery, $($query, $output),*> BatchQueries<C> for ($($query,)*)
+        where
+            $($query: FnOnce(&QuerierWrapper<'_, C>) -> $output,)*
+        {
+            type Results = ($($output,)*);
+
+            #[allow(non_snake_case, unused_variables, clippy::unused_unit)]
+            fn execute(self, querier: &QuerierWrapper<'_, C>) -> Self::Results {
+                let ($($query,)*) = self;
+                ($($query(querier),)*)
+            }
+        }
+    };
+}
+
+impl_batch_queries!();
+impl_batch_queries!(Q1: O1);
+impl_batch_queries!(Q1: O1, Q2: O2);
+impl_batch_queries!(Q1: O1, Q2: O2, Q3: O3);
+impl_batch_queries!(Q1: O1, Q2: O2, Q3: O3, Q4: O4);
+impl_batch_queries!(Q1: O1, Q2: O2, Q3: O3, Q4: O4, Q5: O5);
+impl_batch_queries!(Q1: O1, Q2: O2, Q3: O3, Q4: O4, Q5: O5, Q6: O6);
+impl_batch_queries!(Q1: O1, Q2: O2, Q3: O3, Q4: O4, Q5: O5, Q6: O6, Q7: O7);
+impl_batch_queries!(Q1: O1, Q2: O2, Q3: O3, Q4: O4, Q5: O5, Q6: O6, Q7: O7, Q8: O8);
+impl_batch_queries!(Q1: O1, Q2: O2, Q3: O3, Q4: O4, Q5: O5, Q6: O6, Q7: O7, Q8: O8, Q9: O9);
+impl_batch_queries!(
+    Q1: O1,
+    Q2: O2,
+    Q3: O3,
+    Q4: O4,
+    Q5: O5,
+    Q6: O6,
+    Q7: O7,
+    Q8: O8,
+    Q9: O9,
+    Q10: O10
+);
+impl_batch_queries!(
+    Q1: O1,
+    Q2: O2,
+    Q3: O3,
+    Q4: O4,
+    Q5: O5,
+    Q6: O6,
+    Q7: O7,
+    Q8: O8,
+    Q9: O9,
+    Q10: O10,
+    Q11: O11
+);
+impl_batch_queries!(
+    Q1: O1,
+    Q2: O2,
+    Q3: O3,
+    Q4: O4,
+    Q5: O5,
+    Q6: O6,
+    Q7: O7,
+    Q8: O8,
+    Q9: O9,
+    Q10: O10,
+    Q11: O11,
+    Q12: O12
+);
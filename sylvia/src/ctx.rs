@@ -1,6 +1,9 @@
 //! Module for context types used in method signatures.
 
-use cosmwasm_std::{Deps, DepsMut, Empty, Env, Event, MessageInfo, MsgResponse};
+use cosmwasm_std::{
+    Addr, Deps, DepsMut, Empty, Env, Event, IbcPacket, IbcPacketReceiveMsg, MessageInfo,
+    MsgResponse,
+};
 
 /// Represantation of `reply` context received in entry point.
 #[non_exhaustive]
@@ -49,6 +52,60 @@ pub struct SudoCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub env: Env,
 }
 
+/// Represantation of `ibc_channel_open` context received in entry point.
+#[non_exhaustive]
+pub struct IbcChannelOpenCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Represantation of `ibc_channel_connect` context received in entry point.
+#[non_exhaustive]
+pub struct IbcChannelConnectCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Represantation of `ibc_channel_close` context received in entry point.
+#[non_exhaustive]
+pub struct IbcChannelCloseCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Represantation of `ibc_packet_receive` context received in entry point.
+#[non_exhaustive]
+pub struct IbcPacketReceiveCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Represantation of `ibc_packet_ack` context received in entry point.
+#[non_exhaustive]
+pub struct IbcPacketAckCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Represantation of `ibc_packet_timeout` context received in entry point.
+#[non_exhaustive]
+pub struct IbcPacketTimeoutCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Represantation of `ibc_packet` context received in entry point.
+///
+/// Passed to the handlers of the typed IBC packets decoded from the
+/// [IbcPacketReceiveMsg].
+#[non_exhaustive]
+pub struct IbcPacketCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+    pub packet: IbcPacket,
+    pub relayer: Addr,
+}
+
 impl<C: cosmwasm_std::CustomQuery> ExecCtx<'_, C> {
     pub fn branch(&'_ mut self) -> ExecCtx<'_, C> {
         ExecCtx {
@@ -131,3 +188,52 @@ impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for SudoCtx<'
         Self { deps, env }
     }
 }
+
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcChannelOpenCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcChannelConnectCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcChannelCloseCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcPacketReceiveCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcPacketAckCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcPacketTimeoutCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env, IbcPacketReceiveMsg)>
+    for IbcPacketCtx<'a, C>
+{
+    fn from((deps, env, msg): (DepsMut<'a, C>, Env, IbcPacketReceiveMsg)) -> Self {
+        Self {
+            deps,
+            env,
+            packet: msg.packet,
+            relayer: msg.relayer,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Contract errors with stable codes.
+//!
+//! Errors leave the contract as strings, so callers can only match on the error message.
+//! Enum marked with the [error_codes](crate::error_codes) attribute assigns every variant a stable
+//! code. Contract declaring its error with `#[sv::error(ContractError, codes)]` encodes the code
+//! and the data of the variant in the error string, which allows decoding the typed error back
+//! with [DecodeError] on the caller side.
+//!
+//! ## Limitations
+//!
+//! On chain the error string reaches only the sender of the transaction, e.g. the client reading
+//! the failed transaction result. Errors of the submessages passed to the reply handler and errors
+//! of the queries sent by other contracts are redacted by `wasmd` to the generic
+//! `codespace: wasm, code: ..` message, which carries no code of the contract error.
+//! Decoding the errors in the reply handler or out of the failed [BoundQuerier](crate::types::BoundQuerier)
+//! query works only in [multitest](crate::multitest), which passes the full error strings.
+//!
+//! ## Example usage
+//! ```rust
+//! use sylvia::cw_std::{StdError, Uint128};
+//! use sylvia::errors::{CodedError, DecodeError, ErrorCodes};
+//!
+//! #[sylvia::error_codes]
+//! #[derive(thiserror::Error, Debug, PartialEq)]
+//! pub enum ContractError {
+//!     #[error("{0}")]
+//!     #[sv::code(1)]
+//!     Std(#[from] StdError),
+//!
+//!     #[error("Unauthorized")]
+//!     #[sv::code(2)]
+//!     Unauthorized,
+//!
+//!     #[error("Insufficient funds, needed {needed}")]
+//!     InsufficientFunds { needed: Uint128 },
+//! }
+//!
+//! let err = ContractError::InsufficientFunds {
+//!     needed: Uint128::new(100),
+//! };
+//! let encoded = CodedError::new(err).to_string();
+//! assert!(encoded.ends_with("] Insufficient funds, needed 100"));
+//!
+//! let decoded = encoded.decode_error::<ContractError>().unwrap();
+//! assert_eq!(
+//!     decoded,
+//!     ContractError::InsufficientFunds {
+//!         needed: Uint128::new(100)
+//!     }
+//! );
+//! assert_eq!(ContractError::Unauthorized.code(), 2);
+//! ```
+
+use std::fmt::{Debug, Display};
+
+use cosmwasm_std::{Binary, StdError, SubMsgResult};
+
+/// Marker preceding the code of the error in the encoded error string.
+const MARKER: &str = "[sv-error ";
+
+/// Error enum with a stable code assigned to every variant.
+///
+/// Implemented by the [error_codes](crate::error_codes) attribute.
+pub trait ErrorCodes: Display + Sized {
+    /// Name of the error type, distinguishing the codes of different errors.
+    const ERROR_NAME: &'static str;
+
+    /// Names of the variants paired with their codes.
+    const CODES: &'static [(&'static str, u32)];
+
+    /// Code of the variant.
+    fn code(&self) -> u32;
+
+    /// Fields of the variant serialized to the JSON array.
+    ///
+    /// `None` if the variant has no fields or some of them cannot be encoded.
+    fn encode_data(&self) -> Option<String>;
+
+    /// Recreates the variant of the `code` out of its encoded fields.
+    ///
+    /// `None` if the code is unknown or the variant cannot be decoded.
+    fn decode_data(code: u32, data: Option<&str>) -> Option<Self>;
+}
+
+/// Error code extracted from the error string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncodedError {
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+    pub data: Option<String>,
+}
+
+impl EncodedError {
+    pub fn new<E: ErrorCodes>(err: &E) -> Self {
+        Self {
+            name: E::ERROR_NAME.to_owned(),
+            code: err.code(),
+            message: err.to_string(),
+            data: err.encode_data(),
+        }
+    }
+
+    /// Extracts all the encoded errors from the string, e.g. from the message of the error
+    /// forwarded by the other contract.
+    pub fn parse_all(err: &str) -> Vec<Self> {
+        err.match_indices(MARKER)
+            .filter_map(|(idx, _)| Self::parse(&err[idx..]))
+            .collect()
+    }
+
+    /// Extracts the first encoded error from the string.
+    pub fn parse(err: &str) -> Option<Self> {
+        let (_, encoded) = err.split_once(MARKER)?;
+        let (header, message) = encoded.split_once("] ")?;
+        let message = message.lines().next().unwrap_or_default().to_owned();
+
+        let mut header = header.split(':');
+        let name = header.next()?.to_owned();
+        let code = header.next()?.parse().ok()?;
+        let data = match header.next() {
+            Some(data) => {
+                let data = Binary::from_base64(data).ok()?;
+                Some(String::from_utf8(data.into()).ok()?)
+            }
+            None => None,
+        };
+
+        Some(Self {
+            name,
+            code,
+            message,
+            data,
+        })
+    }
+
+    /// Decodes the typed error if the code belongs to the error `E`.
+    pub fn decode<E: ErrorCodes>(&self) -> Option<E> {
+        if self.name != E::ERROR_NAME {
+            return None;
+        }
+        E::decode_data(self.code, self.data.as_deref())
+    }
+}
+
+impl Display for EncodedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{MARKER}{}:{}", self.name, self.code)?;
+        if let Some(data) = &self.data {
+            write!(f, ":{}", Binary::from(data.as_bytes()).to_base64())?;
+        }
+        write!(f, "] {}", self.message)
+    }
+}
+
+/// Contract error displayed together with its code.
+///
+/// Returned by the entry points of the contract declaring its error with
+/// `#[sv::error(ContractError, codes)]`.
+#[derive(Debug, PartialEq)]
+pub struct CodedError<E>(pub E);
+
+impl<E: ErrorCodes> CodedError<E> {
+    pub fn new(err: E) -> Self {
+        Self(err)
+    }
+
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+
+    /// Converts the error into the [anyhow::Error] displayed with the code, which can still
+    /// be downcasted to the original error.
+    #[cfg(feature = "mt")]
+    pub fn into_anyhow(self) -> anyhow::Error
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let encoded = self.to_string();
+        anyhow::Error::new(self.0).context(encoded)
+    }
+}
+
+impl<E: ErrorCodes> From<E> for CodedError<E> {
+    fn from(err: E) -> Self {
+        Self(err)
+    }
+}
+
+impl<E: ErrorCodes> Display for CodedError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&EncodedError::new(&self.0), f)
+    }
+}
+
+/// Decoding of the typed error out of the error received from the other contract.
+pub trait DecodeError {
+    /// Decodes the first error of type `E` found in the error.
+    fn decode_error<E: ErrorCodes>(&self) -> Option<E>;
+}
+
+impl DecodeError for str {
+    fn decode_error<E: ErrorCodes>(&self) -> Option<E> {
+        EncodedError::parse_all(self)
+            .iter()
+            .find_map(EncodedError::decode)
+    }
+}
+
+impl DecodeError for String {
+    fn decode_error<E: ErrorCodes>(&self) -> Option<E> {
+        self.as_str().decode_error()
+    }
+}
+
+/// Error returned by the [BoundQuerier](crate::types::BoundQuerier) if the queried
+/// contract failed.
+///
+/// Decodes only in multitest, as on chain the error of the queried contract is redacted.
+impl DecodeError for StdError {
+    fn decode_error<E: ErrorCodes>(&self) -> Option<E> {
+        self.to_string().decode_error()
+    }
+}
+
+/// Result of the submessage received by the reply handler.
+///
+/// Decodes only in multitest, as on chain the error of the submessage is redacted.
+impl DecodeError for SubMsgResult {
+    fn decode_error<E: ErrorCodes>(&self) -> Option<E> {
+        match self {
+            SubMsgResult::Err(err) => err.decode_error(),
+            SubMsgResult::Ok(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "mt")]
+impl DecodeError for anyhow::Error {
+    fn decode_error<E: ErrorCodes>(&self) -> Option<E> {
+        format!("{self:?}").decode_error()
+    }
+}
+
+/// Message of the [StdError] stored in the encoded error data.
+///
+/// Generic errors are stored without the `Generic error: ` prefix, so they are decoded
+/// back as the same error.
+pub fn std_error_message(err: &StdError) -> String {
+    match err {
+        StdError::GenericErr { msg, .. } => msg.clone(),
+        err => err.to_string(),
+    }
+}
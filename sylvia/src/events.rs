@@ -0,0 +1,135 @@
+//! Typed events emitted by the contracts.
+//!
+//! Structure marked with the [event](crate::event) attribute is converted into the
+//! [Event] with every field stored as an attribute under the field name.
+//! The chain prefixes the type of every event emitted by the contract with `wasm-`, which is
+//! handled while decoding the event back with [TypedEvent::from_event].
+//!
+//! ## Example usage
+//! ```rust
+//! use sylvia::cw_std::{Addr, Response, StdResult, Uint128};
+//! use sylvia::events::{AddTypedEvent, TypedEvent};
+//!
+//! #[sylvia::event(name = "transfer")]
+//! #[derive(Debug, PartialEq)]
+//! pub struct Transfer {
+//!     pub recipient: Addr,
+//!     pub amount: Uint128,
+//!     pub memo: Option<String>,
+//! }
+//!
+//! let transfer = Transfer {
+//!     recipient: Addr::unchecked("recipient"),
+//!     amount: Uint128::new(100),
+//!     memo: None,
+//! };
+//! let resp: Response = Response::new().add_typed_event(transfer);
+//!
+//! assert_eq!(resp.events[0].ty, "transfer");
+//! assert_eq!(resp.events[0].attributes.len(), 2);
+//! assert_eq!(
+//!     Transfer::from_event(&resp.events[0]).unwrap(),
+//!     Transfer {
+//!         recipient: Addr::unchecked("recipient"),
+//!         amount: Uint128::new(100),
+//!         memo: None,
+//!     }
+//! );
+//! ```
+
+use cosmwasm_std::{
+    Addr, Decimal, Decimal256, Event, Response, StdError, StdResult, Uint128, Uint256,
+};
+
+/// Structure convertible from and into the [Event].
+///
+/// Implemented by the [event](crate::event) attribute.
+pub trait TypedEvent: Sized {
+    /// Type of the event, without the `wasm-` prefix added by the chain.
+    const EVENT_TYPE: &'static str;
+
+    fn into_event(self) -> Event;
+
+    /// Decodes the event, ignoring the attributes added by the chain.
+    fn from_event(event: &Event) -> StdResult<Self>;
+
+    /// Checks if the event is of this type, either as emitted or with the `wasm-` prefix.
+    fn matches(event: &Event) -> bool {
+        event.ty == Self::EVENT_TYPE || event.ty.strip_prefix("wasm-") == Some(Self::EVENT_TYPE)
+    }
+}
+
+/// Field of the [TypedEvent] stored as the event attribute.
+pub trait EventAttribute: Sized {
+    /// Value of the attribute. The attribute is skipped if `None` is returned.
+    fn to_attribute(&self) -> Option<String>;
+
+    /// Parses the value of the attribute `key`, which is `None` if the attribute is missing.
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self>;
+}
+
+fn missing_attribute(key: &str) -> StdError {
+    StdError::generic_err(format!("Missing event attribute `{key}`"))
+}
+
+macro_rules! impl_event_attribute {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl EventAttribute for $ty {
+                fn to_attribute(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+
+                fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+                    let value = value.ok_or_else(|| missing_attribute(key))?;
+                    value.parse().map_err(|_| {
+                        StdError::parse_err(
+                            stringify!($ty),
+                            format!("Invalid event attribute `{key}`: {value}"),
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_event_attribute!(
+    String, bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, Uint128, Uint256, Decimal,
+    Decimal256,
+);
+
+impl EventAttribute for Addr {
+    fn to_attribute(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+        value
+            .map(Addr::unchecked)
+            .ok_or_else(|| missing_attribute(key))
+    }
+}
+
+impl<T: EventAttribute> EventAttribute for Option<T> {
+    fn to_attribute(&self) -> Option<String> {
+        self.as_ref().and_then(T::to_attribute)
+    }
+
+    fn from_attribute(key: &str, value: Option<&str>) -> StdResult<Self> {
+        value
+            .map(|value| T::from_attribute(key, Some(value)))
+            .transpose()
+    }
+}
+
+/// Adds the [TypedEvent] to the [Response].
+pub trait AddTypedEvent {
+    fn add_typed_event(self, event: impl TypedEvent) -> Self;
+}
+
+impl<T> AddTypedEvent for Response<T> {
+    fn add_typed_event(self, event: impl TypedEvent) -> Self {
+        self.add_event(event.into_event())
+    }
+}
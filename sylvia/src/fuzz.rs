@@ -0,0 +1,392 @@
+//! Property-based testing of the contracts with the randomly generated messages.
+//!
+//! With the `arbitrary` feature the `contract` and `interface` macros implement
+//! [Arbitrary] for the generated messages without generic parameters. Fields of the CosmWasm
+//! types like `Addr` or `Uint128`, also wrapped in the `Option` or `Vec`, are generated with
+//! [ArbitraryField], and the other ones with [Arbitrary], so the message can mix them with any
+//! types deriving [Arbitrary]. The CosmWasm types are recognized by their names, so they can't be
+//! used through the type aliases. The message implements [Arbitrary] only if all of its fields
+//! can be generated.
+//!
+//! Fields marked with `sv::addr` are generated as the [Addr] of the generated accounts, so they
+//! pass the validation of the dispatch.
+//!
+//! With the `mt` feature [ExecSequence] sends the random sequences of the `exec` messages to
+//! the contract deployed in the MultiTest, checking the invariants after every step. Every
+//! message is sent with the funds generated according to its [FundsPolicy].
+//!
+//! ## Example usage
+//! ```rust,ignore
+//! use sylvia::fuzz::ExecSequence;
+//!
+//! let report = ExecSequence::new(&contract)
+//!     .with_seed(42)
+//!     .with_steps(200)
+//!     .run::<sv::ContractExecMsg>(|querier| {
+//!         let supply = querier.total_supply()?;
+//!         let balances = (0..ACCOUNTS)
+//!             .map(|idx| querier.balance(account(idx)))
+//!             .sum::<StdResult<Uint128>>()?;
+//!         ensure_eq!(supply, balances, StdError::generic_err("Supply doesn't match the balances"));
+//!         Ok(())
+//!     });
+//! assert!(report.executed > 0);
+//! ```
+//!
+//! [Arbitrary]: arbitrary::Arbitrary
+
+use arbitrary::{Result, Unstructured};
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Timestamp, Uint128, Uint64};
+
+use crate::types::FundsPolicy;
+
+/// Number of the generated accounts.
+pub const ACCOUNTS: usize = 4;
+
+/// Denoms of the generated coins.
+pub const DENOMS: [&str; 2] = ["uatom", "uosmo"];
+
+/// Maximal amount of the single coin sent with the message by the [ExecSequence].
+pub const MAX_FUNDS: u128 = 1_000;
+
+/// Maximal length of the generated vectors.
+const MAX_LEN: usize = 8;
+
+/// Address of the `idx` generated account, valid for the default `MockApi`.
+///
+/// Generated `Addr` fields and the senders of the [ExecSequence] are the accounts
+/// with the `idx` lower than [ACCOUNTS].
+pub fn account(idx: usize) -> Addr {
+    MockApi::default().addr_make(&format!("account{idx}"))
+}
+
+/// Values of the message fields, which types don't implement [Arbitrary](arbitrary::Arbitrary).
+///
+/// Amounts are generated within the `u64` range, so the sums of few of them don't overflow.
+pub trait ArbitraryField<'a>: Sized {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self>;
+}
+
+impl<'a> ArbitraryField<'a> for Addr {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(account(u.choose_index(ACCOUNTS)?))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Uint64 {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Uint64::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Uint128 {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Uint128::new(u.arbitrary::<u64>()?.into()))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Decimal {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Decimal::new(Uint128::arbitrary_field(u)?))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Coin {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        let denom = *u.choose(&DENOMS)?;
+        Ok(Coin::new(Uint128::arbitrary_field(u)?, denom))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Binary {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Binary::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> ArbitraryField<'a> for Timestamp {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Timestamp::from_nanos(u.arbitrary()?))
+    }
+}
+
+impl<'a, T: ArbitraryField<'a>> ArbitraryField<'a> for Option<T> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        match u.arbitrary()? {
+            true => Ok(Some(T::arbitrary_field(u)?)),
+            false => Ok(None),
+        }
+    }
+}
+
+impl<'a, T: ArbitraryField<'a>> ArbitraryField<'a> for Vec<T> {
+    fn arbitrary_field(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(0..=MAX_LEN)?;
+        (0..len).map(|_| T::arbitrary_field(u)).collect()
+    }
+}
+
+/// Funds sent with the `exec` message satisfying the `policy`.
+///
+/// Every coin has the non zero amount not greater than [MAX_FUNDS]. Payable messages are
+/// sometimes sent without funds.
+pub fn arbitrary_funds(policy: FundsPolicy, u: &mut Unstructured<'_>) -> Result<Vec<Coin>> {
+    let denoms = match policy {
+        FundsPolicy::Nonpayable => vec![],
+        FundsPolicy::MustPay(denom) => {
+            return Ok(vec![Coin::new(u.int_in_range(1..=MAX_FUNDS)?, denom)])
+        }
+        FundsPolicy::Payable(Some(denom)) => vec![denom],
+        FundsPolicy::Payable(None) => DENOMS.to_vec(),
+    };
+
+    let mut funds = vec![];
+    for denom in denoms {
+        if u.arbitrary()? {
+            funds.push(Coin::new(u.int_in_range(1..=MAX_FUNDS)?, denom));
+        }
+    }
+    Ok(funds)
+}
+
+/// Implemented by the generated `exec` messages, returning the [FundsPolicy] of the method
+/// the message calls.
+pub trait ExecFunds {
+    fn funds_policy(&self) -> FundsPolicy;
+}
+
+/// Conversion of the generated [Addr] to the unchecked value of the `sv::addr` field.
+#[doc(hidden)]
+pub trait UncheckedAddr {
+    type Unchecked;
+
+    fn unchecked(self) -> Self::Unchecked;
+}
+
+impl UncheckedAddr for Addr {
+    type Unchecked = String;
+
+    fn unchecked(self) -> Self::Unchecked {
+        self.to_string()
+    }
+}
+
+impl<T: UncheckedAddr> UncheckedAddr for Option<T> {
+    type Unchecked = Option<T::Unchecked>;
+
+    fn unchecked(self) -> Self::Unchecked {
+        self.map(T::unchecked)
+    }
+}
+
+impl<T: UncheckedAddr> UncheckedAddr for Vec<T> {
+    type Unchecked = Vec<T::Unchecked>;
+
+    fn unchecked(self) -> Self::Unchecked {
+        self.into_iter().map(T::unchecked).collect()
+    }
+}
+
+#[cfg(feature = "mt")]
+pub use sequence::{ExecSequence, SequenceReport};
+
+#[cfg(feature = "mt")]
+mod sequence {
+    use std::fmt::{Debug, Write};
+
+    use arbitrary::{Arbitrary, Unstructured};
+    use cosmwasm_std::{Api, Storage};
+    use cw_multi_test::{Bank, Distribution, Executor, Gov, Ibc, Module, Staking, Stargate, Wasm};
+    use serde::Serialize;
+
+    use super::{account, arbitrary_funds, ExecFunds, ACCOUNTS};
+    use crate::cw_std::StdResult;
+    use crate::multitest::Proxy;
+    use crate::types::{BoundQuerier, CustomMsg, CustomQuery};
+
+    /// Bytes from which the single message is generated.
+    const STEP_BYTES: usize = 512;
+
+    /// Random sequence of the messages sent to the contract by the generated accounts.
+    ///
+    /// Messages are sent with the funds generated by [arbitrary_funds](super::arbitrary_funds),
+    /// so the accounts have to be funded for the payable messages to succeed.
+    /// The sequence is determined by the seed, so the failing one can be reproduced. Messages
+    /// rejected by the contract are part of the sequence, as the state should stay valid
+    /// after them too.
+    pub struct ExecSequence<'a, 'app, MtApp, Contract> {
+        proxy: &'a Proxy<'app, MtApp, Contract>,
+        seed: u64,
+        steps: usize,
+    }
+
+    impl<'a, 'app, MtApp, Contract> ExecSequence<'a, 'app, MtApp, Contract> {
+        pub fn new(proxy: &'a Proxy<'app, MtApp, Contract>) -> Self {
+            Self {
+                proxy,
+                seed: 0,
+                steps: 100,
+            }
+        }
+
+        pub fn with_seed(self, seed: u64) -> Self {
+            Self { seed, ..self }
+        }
+
+        pub fn with_steps(self, steps: usize) -> Self {
+            Self { steps, ..self }
+        }
+    }
+
+    impl<
+            Contract,
+            BankT,
+            ApiT,
+            StorageT,
+            CustomT,
+            WasmT,
+            StakingT,
+            DistrT,
+            IbcT,
+            GovT,
+            StargateT,
+        >
+        ExecSequence<
+            '_,
+            '_,
+            cw_multi_test::App<
+                BankT,
+                ApiT,
+                StorageT,
+                CustomT,
+                WasmT,
+                StakingT,
+                DistrT,
+                IbcT,
+                GovT,
+                StargateT,
+            >,
+            Contract,
+        >
+    where
+        CustomT::ExecT: CustomMsg + 'static,
+        CustomT::QueryT: CustomQuery + 'static,
+        WasmT: Wasm<CustomT::ExecT, CustomT::QueryT>,
+        BankT: Bank,
+        ApiT: Api,
+        StorageT: Storage,
+        CustomT: Module,
+        StakingT: Staking,
+        DistrT: Distribution,
+        IbcT: Ibc,
+        GovT: Gov,
+        StargateT: Stargate,
+    {
+        /// Sends the random `Msg` messages checking the `invariant` before the first one and
+        /// after every one of them.
+        ///
+        /// Panics with the seed and the sent messages if the invariant is violated, and if none
+        /// of the messages could be generated.
+        #[track_caller]
+        pub fn run<Msg>(
+            self,
+            invariant: impl Fn(&BoundQuerier<'_, CustomT::QueryT, Contract>) -> StdResult<()>,
+        ) -> SequenceReport
+        where
+            Msg: for<'b> Arbitrary<'b> + ExecFunds + Serialize + Debug,
+        {
+            let Self { proxy, seed, steps } = self;
+
+            let mut rng = SplitMix64(seed);
+            let mut report = SequenceReport::default();
+            let mut history = String::new();
+
+            let check = |step: usize, history: &str| {
+                let querier = proxy.app.querier();
+                let querier = BoundQuerier::borrowed(&proxy.contract_addr, &querier);
+                if let Err(err) = invariant(&querier) {
+                    panic!(
+                        "Invariant violated after {step} steps of the sequence with seed {seed}: {err}\n\nSent messages:\n{history}"
+                    );
+                }
+            };
+
+            check(0, &history);
+            for step in 1..=steps {
+                let bytes = rng.bytes(STEP_BYTES);
+                let mut u = Unstructured::new(&bytes);
+                let (Ok(sender), Ok(msg)) = (u.choose_index(ACCOUNTS), Msg::arbitrary(&mut u))
+                else {
+                    report.skipped += 1;
+                    continue;
+                };
+                let Ok(funds) = arbitrary_funds(msg.funds_policy(), &mut u) else {
+                    report.skipped += 1;
+                    continue;
+                };
+                let sender = account(sender);
+
+                let result = proxy.app.app_mut().execute_contract(
+                    sender.clone(),
+                    proxy.contract_addr.clone(),
+                    &msg,
+                    &funds,
+                );
+                let outcome = match result {
+                    Ok(_) => {
+                        report.executed += 1;
+                        "ok".to_owned()
+                    }
+                    Err(err) => {
+                        report.failed += 1;
+                        format!("error: {err}")
+                    }
+                };
+                let _ = writeln!(
+                    history,
+                    "{step}. {sender} sent {msg:?} with {funds:?} - {outcome}"
+                );
+
+                check(step, &history);
+            }
+
+            if steps > 0 && report.skipped == steps {
+                panic!("None of the {steps} messages of the sequence with seed {seed} could be generated");
+            }
+
+            report
+        }
+    }
+
+    /// Summary of the [ExecSequence].
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct SequenceReport {
+        /// Number of the messages executed successfully.
+        pub executed: usize,
+        /// Number of the messages rejected by the contract.
+        pub failed: usize,
+        /// Number of the steps skipped, as the message couldn't be generated from the random bytes.
+        pub skipped: usize,
+    }
+
+    /// SplitMix64 generator of the bytes the messages are generated from.
+    struct SplitMix64(u64);
+
+    impl SplitMix64 {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+
+        fn bytes(&mut self, len: usize) -> Vec<u8> {
+            (0..len.div_ceil(8))
+                .flat_map(|_| self.next().to_le_bytes())
+                .take(len)
+                .collect()
+        }
+    }
+}
@@ -3,23 +3,46 @@
 //!
 //! Most of implementation lies in `sylvia-derive` crate which is reexported here.
 
+// Allows using the `sylvia` macros inside of this crate.
+extern crate self as sylvia;
+
+pub mod batch;
 pub mod builder;
 pub mod ctx;
+pub mod errors;
+pub mod events;
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+#[cfg(all(feature = "arbitrary", not(target_arch = "wasm32")))]
+pub mod fuzz;
 pub mod into_response;
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub mod multitest;
+pub mod ownable;
+pub mod pausable;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod schema;
 pub mod types;
+#[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+#[cfg(all(feature = "typescript", not(target_arch = "wasm32")))]
+pub mod typescript;
 pub mod utils;
+#[cfg_attr(docsrs, doc(cfg(feature = "vm")))]
+#[cfg(all(feature = "vm", not(target_arch = "wasm32")))]
+pub mod vm;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub use anyhow;
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+pub use cw2;
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub use cw_multi_test;
 pub use cw_utils;
-pub use sylvia_derive::{contract, entry_points, interface};
+pub use sylvia_derive::{contract, entry_points, error_codes, event, interface};
 pub use {
     cosmwasm_schema as cw_schema, cosmwasm_std as cw_std, schemars, serde,
     serde_cw_value as serde_value, serde_json_wasm as serde_json,
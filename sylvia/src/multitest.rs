@@ -49,25 +49,41 @@
 //!
 //! # fn main() {}
 //! ```
+//!
+//! ## Wasm execution
+//!
+//! The contracts stored with the generated `CodeId::store_code` are executed as native Rust
+//! code, so the gas usage and the limits of the virtual machine are not checked. With the `vm`
+//! feature enabled, `CodeId::store_wasm` stores the compiled `.wasm` executed by the
+//! `cosmwasm-vm` instead, and reports the gas used by every call. See the `vm` module.
 
-use std::cell::{Ref, RefCell, RefMut};
+#![allow(clippy::test_attr_in_doctest)]
+
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::rc::Rc;
 
 use cosmwasm_std::testing::MockApi;
+#[cfg(feature = "cosmwasm_1_2")]
+use cosmwasm_std::CodeInfoResponse;
 use cosmwasm_std::{
-    Addr, Api, BlockInfo, Coin, Empty, Querier, QuerierResult, QuerierWrapper, StdError, Storage,
+    Addr, Api, Binary, BlockInfo, Checksum, Coin, Deps, DepsMut, Empty, Env, Event, MessageInfo,
+    Order, Querier, QuerierResult, QuerierWrapper, Reply, Response, StdError, StdResult, Storage,
 };
-#[cfg(feature = "cosmwasm_1_2")]
-use cosmwasm_std::{CodeInfoResponse, StdResult};
 use cw_multi_test::{
-    Bank, BankKeeper, Distribution, DistributionKeeper, Executor, FailingModule, Gov,
+    Bank, BankKeeper, Contract, Distribution, DistributionKeeper, Executor, FailingModule, Gov,
     GovFailingModule, Ibc, IbcFailingModule, Module, Router, StakeKeeper, Staking, Stargate,
     StargateFailing, Wasm, WasmKeeper,
 };
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::types::{CustomMsg, CustomQuery};
+use crate::cw2::ContractVersion;
+use crate::errors::{DecodeError, ErrorCodes};
+use crate::events::TypedEvent;
+use crate::types::{CustomMsg, CustomQuery, FundsPolicy};
 
 /// Proxy to interact with a smart contract initialized on the [App].
 pub struct Proxy<'a, MtApp, Contract> {
@@ -94,6 +110,32 @@ impl<'a, MtApp, Contract> Proxy<'a, MtApp, Contract> {
     }
 }
 
+impl<MtApp, Contract> Proxy<'_, MtApp, Contract>
+where
+    App<MtApp>: Querier,
+{
+    /// Queries the [ContractVersion] stored by the contract.
+    pub fn contract_version(&self) -> StdResult<ContractVersion> {
+        let querier = QuerierWrapper::<Empty>::new(self.app);
+        crate::cw2::query_contract_info(&querier, &self.contract_addr)
+    }
+
+    /// Asserts that the contract stores the expected name and version.
+    #[track_caller]
+    pub fn assert_contract_version(&self, name: &str, version: &str) {
+        let stored = self
+            .contract_version()
+            .unwrap_or_else(|err| panic!("Failed to query contract version: {err}"));
+        assert_eq!(
+            stored,
+            ContractVersion {
+                contract: name.to_owned(),
+                version: version.to_owned(),
+            }
+        );
+    }
+}
+
 impl<'app, MtApp, Contract> From<(cosmwasm_std::Addr, &'app App<MtApp>)>
     for Proxy<'app, MtApp, Contract>
 {
@@ -105,6 +147,7 @@ impl<'app, MtApp, Contract> From<(cosmwasm_std::Addr, &'app App<MtApp>)>
 /// Wrapper around `cw_multi_test::App` to provide additional functionalities.
 pub struct App<MtApp> {
     app: RefCell<MtApp>,
+    codes: RefCell<Vec<Rc<Cell<bool>>>>,
 }
 
 impl<MtApp> Default for App<MtApp>
@@ -137,9 +180,7 @@ impl<ExecC, QueryC> App<cw_multi_test::BasicApp<ExecC, QueryC>> {
             &mut dyn Storage,
         ),
     {
-        App {
-            app: RefCell::new(cw_multi_test::custom_app(init_fn)),
-        }
+        Self::new(cw_multi_test::custom_app(init_fn))
     }
 }
 
@@ -147,6 +188,7 @@ impl<MtApp> App<MtApp> {
     pub fn new(app: MtApp) -> Self {
         Self {
             app: RefCell::new(app),
+            codes: RefCell::default(),
         }
     }
 
@@ -205,6 +247,74 @@ where
         self.app.borrow_mut().update_block(action)
     }
 
+    /// Captures the state of the chain, which can be brought back with [App::restore].
+    ///
+    /// The snapshot covers the storage of all the modules, including the contracts state,
+    /// their metadata and the bank balances, the block info and the codes stored with
+    /// [App::store_code]. Restoring the snapshot unloads the codes stored after it, but their
+    /// code ids are not reused.
+    pub fn snapshot(&self) -> AppSnapshot {
+        let app = self.app.borrow();
+        let storage = app.storage().range(None, None, Order::Ascending).collect();
+
+        AppSnapshot {
+            storage,
+            block: app.block_info(),
+            codes: self.codes.borrow().len(),
+        }
+    }
+
+    /// Brings back the state of the chain captured with [App::snapshot].
+    ///
+    /// The same snapshot can be restored multiple times to test different paths starting
+    /// from a common setup.
+    pub fn restore(&self, snapshot: &AppSnapshot) {
+        let mut app = self.app.borrow_mut();
+        let storage = app.storage_mut();
+        let keys: Vec<_> = storage.range_keys(None, None, Order::Ascending).collect();
+        keys.iter().for_each(|key| storage.remove(key));
+        snapshot
+            .storage
+            .iter()
+            .for_each(|(key, value)| storage.set(key, value));
+
+        app.set_block(snapshot.block.clone());
+
+        self.codes
+            .borrow()
+            .iter()
+            .enumerate()
+            .for_each(|(idx, loaded)| loaded.set(idx < snapshot.codes));
+    }
+
+    /// Runs the `action` and rolls the chain back to the state from before it.
+    ///
+    /// The state is restored even if the `action` panics.
+    ///
+    /// ```rust,ignore
+    /// let balance = app.branch(|app| {
+    ///     contract.withdraw(amount).call(&owner).unwrap();
+    ///     app.querier().query_balance(&owner, "atom").unwrap()
+    /// });
+    /// ```
+    pub fn branch<T>(&self, action: impl FnOnce(&Self) -> T) -> T {
+        let snapshot = self.snapshot();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| action(self)));
+        self.restore(&snapshot);
+        result.unwrap_or_else(|err| std::panic::resume_unwind(err))
+    }
+
+    /// Registers the contract `code` in the `WasmKeeper`, returning its code id.
+    ///
+    /// Codes stored this way are covered by [App::snapshot].
+    pub fn store_code(&self, code: Box<dyn Contract<CustomT::ExecT, CustomT::QueryT>>) -> u64 {
+        let loaded = Rc::new(Cell::new(true));
+        self.codes.borrow_mut().push(loaded.clone());
+        self.app
+            .borrow_mut()
+            .store_code(Box::new(SnapshotCode { code, loaded }))
+    }
+
     /// Returns [CodeInfoResponse] for the given `code_id`.
     #[cfg(feature = "cosmwasm_1_2")]
     pub fn code_info(&self, code_id: u64) -> StdResult<CodeInfoResponse> {
@@ -253,19 +363,139 @@ where
     }
 }
 
+/// State of the chain captured with [App::snapshot].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppSnapshot {
+    storage: Vec<(Vec<u8>, Vec<u8>)>,
+    block: BlockInfo,
+    codes: usize,
+}
+
+impl AppSnapshot {
+    /// Returns the block info at the moment of taking the snapshot.
+    pub fn block_info(&self) -> &BlockInfo {
+        &self.block
+    }
+}
+
+/// Code stored with [App::store_code].
+///
+/// The `WasmKeeper` doesn't allow removing the codes, so the codes stored after the restored
+/// snapshot are unloaded instead and fail every call.
+struct SnapshotCode<ExecC, QueryC> {
+    code: Box<dyn Contract<ExecC, QueryC>>,
+    loaded: Rc<Cell<bool>>,
+}
+
+impl<ExecC, QueryC> SnapshotCode<ExecC, QueryC> {
+    fn code(&self) -> anyhow::Result<&dyn Contract<ExecC, QueryC>> {
+        match self.loaded.get() {
+            true => Ok(self.code.as_ref()),
+            false => Err(StdError::generic_err(
+                "Code stored after the restored snapshot is not available",
+            )
+            .into()),
+        }
+    }
+}
+
+impl<ExecC, QueryC> Contract<ExecC, QueryC> for SnapshotCode<ExecC, QueryC>
+where
+    ExecC: cosmwasm_std::CustomMsg,
+    QueryC: cosmwasm_std::CustomQuery,
+{
+    fn execute(
+        &self,
+        deps: DepsMut<QueryC>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<ExecC>> {
+        self.code()?.execute(deps, env, info, msg)
+    }
+
+    fn instantiate(
+        &self,
+        deps: DepsMut<QueryC>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<ExecC>> {
+        self.code()?.instantiate(deps, env, info, msg)
+    }
+
+    fn query(&self, deps: Deps<QueryC>, env: Env, msg: Vec<u8>) -> anyhow::Result<Binary> {
+        self.code()?.query(deps, env, msg)
+    }
+
+    fn sudo(
+        &self,
+        deps: DepsMut<QueryC>,
+        env: Env,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<ExecC>> {
+        self.code()?.sudo(deps, env, msg)
+    }
+
+    fn reply(
+        &self,
+        deps: DepsMut<QueryC>,
+        env: Env,
+        msg: Reply,
+    ) -> anyhow::Result<Response<ExecC>> {
+        self.code()?.reply(deps, env, msg)
+    }
+
+    fn migrate(
+        &self,
+        deps: DepsMut<QueryC>,
+        env: Env,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<ExecC>> {
+        self.code()?.migrate(deps, env, msg)
+    }
+
+    fn checksum(&self) -> Option<Checksum> {
+        self.code.checksum()
+    }
+}
+
+/// Converts the error returned by the [App] into the error of the called contract.
+///
+/// Errors of the natively executed contracts are downcasted, while the errors of the contracts
+/// executed through the `cosmwasm-vm` are only available as the messages.
+#[doc(hidden)]
+pub fn contract_error<Error>(err: anyhow::Error) -> Error
+where
+    Error: From<StdError> + Debug + Display + Send + Sync + 'static,
+{
+    match err.downcast::<Error>() {
+        Ok(err) => err,
+        Err(err) => match err.downcast::<StdError>() {
+            Ok(err) => err.into(),
+            Err(err) => StdError::generic_err(err.to_string()).into(),
+        },
+    }
+}
+
 /// Intermiediate proxy to set additional information
 /// before sending an execute message.
+///
+/// `Data` is the type of the data set in the response, declared with
+/// `#[sv::msg(exec, data = Data)]` and decoded by [ExecProxy::call_typed].
 #[must_use]
-pub struct ExecProxy<'a, 'app, Error, Msg, MtApp, ExecC>
+pub struct ExecProxy<'a, 'app, Error, Msg, MtApp, ExecC, Data = ()>
 where
     Msg: Serialize + Debug,
     Error: Debug + Display + Send + Sync + 'static,
 {
     funds: &'a [Coin],
+    funds_policy: FundsPolicy,
+    decode_error: fn(&str) -> Option<Error>,
     contract_addr: &'a Addr,
     msg: Msg,
     app: &'app App<MtApp>,
-    phantom: PhantomData<(Error, ExecC)>,
+    phantom: PhantomData<(Error, ExecC, Data)>,
 }
 
 impl<'a, 'app, Error, Msg, MtApp, ExecC> ExecProxy<'a, 'app, Error, Msg, MtApp, ExecC>
@@ -278,21 +508,83 @@ where
     pub fn new(contract_addr: &'a Addr, msg: Msg, app: &'app App<MtApp>) -> Self {
         Self {
             funds: &[],
+            funds_policy: FundsPolicy::default(),
+            decode_error: |_| None,
             contract_addr,
             msg,
             app,
             phantom: PhantomData,
         }
     }
+}
+
+impl<'a, 'app, Error, Msg, MtApp, ExecC, Data> ExecProxy<'a, 'app, Error, Msg, MtApp, ExecC, Data>
+where
+    Msg: Serialize + Debug,
+    Error: From<StdError> + Debug + Display + Send + Sync + 'static,
+    ExecC: cosmwasm_std::CustomMsg + 'static,
+    MtApp: Executor<ExecC>,
+{
+    /// Sets the type of the data decoded by [ExecProxy::call_typed].
+    ///
+    /// Called by the generated proxies for methods marked with `#[sv::msg(exec, data = ...)]`.
+    pub fn with_data<NewData>(self) -> ExecProxy<'a, 'app, Error, Msg, MtApp, ExecC, NewData> {
+        ExecProxy {
+            funds: self.funds,
+            funds_policy: self.funds_policy,
+            decode_error: self.decode_error,
+            contract_addr: self.contract_addr,
+            msg: self.msg,
+            app: self.app,
+            phantom: PhantomData,
+        }
+    }
 
     /// Sets the funds to be sent with the execute message.
     pub fn with_funds(self, funds: &'a [Coin]) -> Self {
         Self { funds, ..self }
     }
 
+    /// Sets the [FundsPolicy] of the executed method.
+    ///
+    /// Called by the generated proxies for methods marked with `#[sv::funds(...)]`.
+    pub fn with_funds_policy(self, funds_policy: FundsPolicy) -> Self {
+        Self {
+            funds_policy,
+            ..self
+        }
+    }
+
+    /// Returns the [FundsPolicy] of the executed method.
+    pub fn funds_policy(&self) -> FundsPolicy {
+        self.funds_policy
+    }
+
+    /// Decodes the coded errors forwarded from the other contracts as the `Error`.
+    ///
+    /// Called by the generated proxies of contracts declaring `#[sv::error(ContractError, codes)]`.
+    pub fn with_error_codes(self) -> Self
+    where
+        Error: ErrorCodes,
+    {
+        Self {
+            decode_error: |err| err.decode_error(),
+            ..self
+        }
+    }
+
     /// Sends the execute message to the contract.
+    ///
+    /// Panics if funds are sent to the `nonpayable` method.
     #[track_caller]
     pub fn call(self, sender: &'a Addr) -> Result<cw_multi_test::AppResponse, Error> {
+        if self.funds_policy.is_nonpayable() && !self.funds.is_empty() {
+            panic!(
+                "Message {:?} is nonpayable, but funds {:?} were sent with it",
+                self.msg, self.funds
+            );
+        }
+
         (*self.app)
             .app_mut()
             .execute_contract(
@@ -304,6 +596,8 @@ where
             .map_err(|err| {
                 if err.is::<Error>() {
                     err.downcast::<Error>().unwrap()
+                } else if let Some(decoded) = (self.decode_error)(&format!("{err:?}")) {
+                    decoded
                 } else if err.is::<StdError>() {
                     err.downcast::<StdError>().unwrap().into()
                 } else {
@@ -311,6 +605,35 @@ where
                 }
             })
     }
+
+    /// Sends the execute message to the contract and decodes the data set in its response.
+    ///
+    /// Missing data is decoded as `null`, so it's accepted if `Data` is an `Option` or `()`.
+    #[track_caller]
+    pub fn call_typed(self, sender: &'a Addr) -> Result<ExecResponse<Data>, Error>
+    where
+        Data: DeserializeOwned,
+    {
+        let resp = self.call(sender)?;
+        let data = match resp.data {
+            Some(data) => cosmwasm_std::from_json(data)?,
+            None => cosmwasm_std::from_json(b"null").map_err(|_| {
+                StdError::generic_err("Missing data in the response of the execute message")
+            })?,
+        };
+
+        Ok(ExecResponse {
+            data,
+            events: resp.events,
+        })
+    }
+}
+
+/// Response of the execute message with its data decoded, returned by [ExecProxy::call_typed].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecResponse<Data> {
+    pub data: Data,
+    pub events: Vec<Event>,
 }
 
 /// Intermiediate proxy to set additional information
@@ -330,7 +653,7 @@ where
 impl<'a, 'app, Error, Msg, MtApp, ExecC> MigrateProxy<'a, 'app, Error, Msg, MtApp, ExecC>
 where
     Msg: Serialize + Debug,
-    Error: Debug + Display + Send + Sync + 'static,
+    Error: From<StdError> + Debug + Display + Send + Sync + 'static,
     ExecC: cosmwasm_std::CustomMsg + 'static,
     MtApp: Executor<ExecC>,
 {
@@ -358,7 +681,88 @@ where
                 &self.msg,
                 new_code_id,
             )
-            .map_err(|err| err.downcast().unwrap())
+            .map_err(contract_error)
+    }
+}
+
+/// Handler producing the response of the [MockMethod].
+pub type MockHandler<Args, Output> = Rc<dyn Fn(Args) -> Output>;
+
+/// Programmed response and the recorded calls of the single message of the generated mock.
+///
+/// `Args` is the tuple of the method arguments and `Output` is the type returned by the method.
+pub struct MockMethod<Args, Output> {
+    handler: Option<MockHandler<Args, Output>>,
+    calls: Vec<Binary>,
+}
+
+impl<Args, Output> Default for MockMethod<Args, Output> {
+    fn default() -> Self {
+        Self {
+            handler: None,
+            calls: vec![],
+        }
+    }
+}
+
+impl<Args, Output> MockMethod<Args, Output> {
+    /// Sets the handler producing the response to the message.
+    pub fn set_handler(&mut self, handler: impl Fn(Args) -> Output + 'static) {
+        self.handler = Some(Rc::new(handler));
+    }
+
+    /// Records the serialized arguments of the call and returns the handler to be called
+    /// with them.
+    ///
+    /// The handler is returned instead of being called, so the mock state isn't borrowed
+    /// while it runs.
+    pub fn record(
+        &mut self,
+        args: &impl Serialize,
+    ) -> StdResult<Option<MockHandler<Args, Output>>> {
+        self.calls.push(cosmwasm_std::to_json_binary(args)?);
+        Ok(self.handler.clone())
+    }
+
+    /// Arguments of the recorded calls in the order they were made.
+    pub fn calls(&self) -> Vec<Args>
+    where
+        Args: DeserializeOwned,
+    {
+        self.calls
+            .iter()
+            .map(|call| {
+                cosmwasm_std::from_json(call)
+                    .expect("Recorded call should deserialize to its arguments")
+            })
+            .collect()
+    }
+}
+
+/// Decoding of the [TypedEvent]s emitted while handling the message.
+pub trait TypedEvents {
+    /// Returns all events emitted while handling the message.
+    fn events(&self) -> &[Event];
+
+    /// Returns all events of type `E` in order of emission.
+    fn typed_events<E: TypedEvent>(&self) -> StdResult<Vec<E>> {
+        self.events()
+            .iter()
+            .filter(|event| E::matches(event))
+            .map(E::from_event)
+            .collect()
+    }
+}
+
+impl TypedEvents for cw_multi_test::AppResponse {
+    fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
+
+impl<Data> TypedEvents for ExecResponse<Data> {
+    fn events(&self) -> &[Event] {
+        &self.events
     }
 }
 
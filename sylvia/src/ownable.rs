@@ -0,0 +1,218 @@
+//! Ready-made ownership interface with the two step ownership transfer.
+//!
+//! The current owner proposes a new owner with `transfer_ownership`, which then has to
+//! accept it with `accept_ownership`. The owner can also give up the ownership
+//! with `renounce_ownership`. The ownership is stored under the [OWNERSHIP_KEY], prefixed with
+//! the `sv_` reserved by Sylvia, so it doesn't collide with the `ownership` state of the contract.
+//!
+//! ## Example usage
+//! ```rust
+//! use sylvia::ctx::{ExecCtx, InstantiateCtx};
+//! use sylvia::cw_std::{Empty, Response, StdError, StdResult};
+//! use sylvia::ownable::{self, Ownable};
+//!
+//! pub struct SvContract;
+//!
+//! #[sylvia::contract]
+//! #[sv::messages(sylvia::ownable as Ownable)]
+//! impl SvContract {
+//!     pub const fn new() -> Self {
+//!         Self
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+//!         ownable::initialize_owner(ctx.deps.storage, ctx.deps.api, Some(ctx.info.sender.as_str()))?;
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     fn update_config(&self, ctx: ExecCtx) -> StdResult<Response> {
+//!         ownable::ensure_owner(&ctx)?;
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     #[sv::guard(ownable::only_owner)]
+//!     fn update_other_config(&self, ctx: ExecCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//! }
+//!
+//! impl Ownable for SvContract {
+//!     type Error = StdError;
+//!     type ExecC = Empty;
+//!     type QueryC = Empty;
+//! }
+//! # fn main() {}
+//! ```
+
+use cosmwasm_std::{
+    from_json, to_json_vec, Addr, Api, CustomMsg, CustomQuery, Response, StdError, StdResult,
+    Storage,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::ctx::{ExecCtx, QueryCtx};
+use crate::interface;
+
+/// Storage key under which the [Ownership] is saved.
+pub const OWNERSHIP_KEY: &[u8] = b"sv_ownable_ownership";
+
+/// Ownership of the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct Ownership {
+    /// Current owner. `None` if the ownership was renounced.
+    pub owner: Option<Addr>,
+    /// Owner proposed by the current owner, waiting to accept the ownership.
+    pub pending_owner: Option<Addr>,
+}
+
+/// Saves the initial owner of the contract.
+pub fn initialize_owner(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    owner: Option<&str>,
+) -> StdResult<Ownership> {
+    let ownership = Ownership {
+        owner: owner.map(|owner| api.addr_validate(owner)).transpose()?,
+        pending_owner: None,
+    };
+    save_ownership(storage, &ownership)?;
+    Ok(ownership)
+}
+
+/// Loads the ownership of the contract.
+pub fn get_ownership(storage: &dyn Storage) -> StdResult<Ownership> {
+    storage
+        .get(OWNERSHIP_KEY)
+        .ok_or_else(|| StdError::not_found("sylvia::ownable::Ownership"))
+        .and_then(from_json)
+}
+
+/// Checks if the ownership was initialized with [initialize_owner].
+///
+/// Renounced ownership is still initialized.
+pub fn has_owner(storage: &dyn Storage) -> bool {
+    storage.get(OWNERSHIP_KEY).is_some()
+}
+
+/// Returns an error if the sender of the message is not the current owner.
+pub fn ensure_owner<Q: CustomQuery>(ctx: &ExecCtx<Q>) -> StdResult<()> {
+    assert_owner(ctx.deps.storage, &ctx.info.sender).map(|_| ())
+}
+
+/// [ensure_owner] in the form of the guard used with `#[sv::guard(sylvia::ownable::only_owner)]`.
+pub fn only_owner<Q: CustomQuery, Contract: ?Sized>(
+    ctx: &ExecCtx<Q>,
+    _contract: &Contract,
+) -> StdResult<()> {
+    ensure_owner(ctx)
+}
+
+/// Proposes the `new_owner`. Can be called only by the current owner.
+pub fn propose_owner(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    sender: &Addr,
+    new_owner: &str,
+) -> StdResult<Ownership> {
+    let mut ownership = assert_owner(storage, sender)?;
+    ownership.pending_owner = Some(api.addr_validate(new_owner)?);
+    save_ownership(storage, &ownership)?;
+    Ok(ownership)
+}
+
+/// Makes the pending owner the current one. Can be called only by the pending owner.
+pub fn accept_pending_owner(storage: &mut dyn Storage, sender: &Addr) -> StdResult<Ownership> {
+    let ownership = get_ownership(storage)?;
+    match ownership.pending_owner {
+        Some(pending_owner) if pending_owner == sender => {
+            let ownership = Ownership {
+                owner: Some(pending_owner),
+                pending_owner: None,
+            };
+            save_ownership(storage, &ownership)?;
+            Ok(ownership)
+        }
+        Some(_) => Err(StdError::generic_err("Caller is not the pending owner")),
+        None => Err(StdError::generic_err("No pending ownership transfer")),
+    }
+}
+
+/// Removes the current and pending owner. Can be called only by the current owner.
+pub fn renounce_owner(storage: &mut dyn Storage, sender: &Addr) -> StdResult<Ownership> {
+    assert_owner(storage, sender)?;
+    let ownership = Ownership::default();
+    save_ownership(storage, &ownership)?;
+    Ok(ownership)
+}
+
+fn assert_owner(storage: &dyn Storage, sender: &Addr) -> StdResult<Ownership> {
+    let ownership = get_ownership(storage)?;
+    match &ownership.owner {
+        Some(owner) if owner == sender => Ok(ownership),
+        Some(_) => Err(StdError::generic_err("Caller is not the contract owner")),
+        None => Err(StdError::generic_err(
+            "Contract ownership has been renounced",
+        )),
+    }
+}
+
+fn save_ownership(storage: &mut dyn Storage, ownership: &Ownership) -> StdResult<()> {
+    storage.set(OWNERSHIP_KEY, &to_json_vec(ownership)?);
+    Ok(())
+}
+
+/// Interface exposing the ownership messages.
+///
+/// All methods are implemented by default, so the contract has to
+/// define only the associated types.
+#[interface]
+pub trait Ownable {
+    type Error: From<StdError>;
+    type ExecC: CustomMsg;
+    type QueryC: CustomQuery;
+
+    /// Proposes the new owner. Can be called only by the current owner.
+    #[sv::msg(exec)]
+    fn transfer_ownership(
+        &self,
+        ctx: ExecCtx<Self::QueryC>,
+        new_owner: String,
+    ) -> Result<Response<Self::ExecC>, Self::Error> {
+        propose_owner(ctx.deps.storage, ctx.deps.api, &ctx.info.sender, &new_owner)?;
+        Ok(Response::new()
+            .add_attribute("action", "transfer_ownership")
+            .add_attribute("pending_owner", new_owner))
+    }
+
+    /// Accepts the ownership. Can be called only by the pending owner.
+    #[sv::msg(exec)]
+    fn accept_ownership(
+        &self,
+        ctx: ExecCtx<Self::QueryC>,
+    ) -> Result<Response<Self::ExecC>, Self::Error> {
+        accept_pending_owner(ctx.deps.storage, &ctx.info.sender)?;
+        Ok(Response::new()
+            .add_attribute("action", "accept_ownership")
+            .add_attribute("owner", ctx.info.sender))
+    }
+
+    /// Gives up the ownership. Can be called only by the current owner.
+    #[sv::msg(exec)]
+    fn renounce_ownership(
+        &self,
+        ctx: ExecCtx<Self::QueryC>,
+    ) -> Result<Response<Self::ExecC>, Self::Error> {
+        renounce_owner(ctx.deps.storage, &ctx.info.sender)?;
+        Ok(Response::new().add_attribute("action", "renounce_ownership"))
+    }
+
+    /// Returns the current and pending owner.
+    #[sv::msg(query)]
+    fn ownership(&self, ctx: QueryCtx<Self::QueryC>) -> Result<Ownership, Self::Error> {
+        Ok(get_ownership(ctx.deps.storage)?)
+    }
+}
@@ -0,0 +1,158 @@
+//! Ready-made interface allowing to pause the contract.
+//!
+//! Contract using `#[sv::features(pausable)]` rejects all `exec` messages while paused,
+//! except the ones marked with `#[sv::allow_when_paused]`. The `pause` and `unpause`
+//! messages of the [Pausable] interface are always allowed.
+//!
+//! The [Pausable] interface has to be listed in the `#[sv::messages(..)]` of the contract.
+//!
+//! By default only the owner set with [crate::ownable::initialize_owner] can pause the contract,
+//! so the contract has to store its ownership even if it does not expose the [crate::ownable::Ownable]
+//! interface. Override the [Pausable::ensure_pauser] method to use a different role.
+//! The state is stored under the [PAUSED_KEY], prefixed with the `sv_` reserved by Sylvia,
+//! so it doesn't collide with the `paused` state of the contract.
+//!
+//! ## Example usage
+//! ```rust
+//! use sylvia::ctx::{ExecCtx, InstantiateCtx};
+//! use sylvia::cw_std::{Empty, Response, StdError, StdResult};
+//! use sylvia::ownable;
+//! use sylvia::pausable::Pausable;
+//!
+//! pub struct SvContract;
+//!
+//! #[sylvia::contract]
+//! #[sv::features(pausable)]
+//! #[sv::messages(sylvia::pausable as Pausable)]
+//! impl SvContract {
+//!     pub const fn new() -> Self {
+//!         Self
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+//!         ownable::initialize_owner(ctx.deps.storage, ctx.deps.api, Some(ctx.info.sender.as_str()))?;
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     fn transfer(&self, ctx: ExecCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     #[sv::allow_when_paused]
+//!     fn withdraw(&self, ctx: ExecCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//! }
+//!
+//! impl Pausable for SvContract {
+//!     type Error = StdError;
+//!     type ExecC = Empty;
+//!     type QueryC = Empty;
+//! }
+//! # fn main() {}
+//! ```
+
+use cosmwasm_std::{
+    from_json, to_json_vec, CustomMsg, CustomQuery, Response, StdError, StdResult, Storage,
+};
+
+use crate::ctx::{ExecCtx, QueryCtx};
+use crate::interface;
+use crate::types::InterfaceId;
+
+/// Storage key under which the paused state is saved.
+pub const PAUSED_KEY: &[u8] = b"sv_pausable_paused";
+
+/// Loads the paused state. Contract that was never paused is not paused.
+pub fn is_paused(storage: &dyn Storage) -> StdResult<bool> {
+    storage.get(PAUSED_KEY).map_or(Ok(false), from_json)
+}
+
+/// Saves the paused state.
+pub fn set_paused(storage: &mut dyn Storage, paused: bool) -> StdResult<()> {
+    storage.set(PAUSED_KEY, &to_json_vec(&paused)?);
+    Ok(())
+}
+
+/// Returns an error if the contract is paused.
+///
+/// Called by the generated dispatch of the contracts using `#[sv::features(pausable)]`.
+pub fn ensure_not_paused(storage: &dyn Storage) -> StdResult<()> {
+    match is_paused(storage)? {
+        true => Err(StdError::generic_err("Contract is paused")),
+        false => Ok(()),
+    }
+}
+
+/// Implemented by the `exec` messages which can be dispatched while the contract is paused.
+///
+/// Generated for the `exec` messages of all the interfaces and of the `pausable` contracts.
+pub trait AllowWhenPaused {
+    fn allowed_when_paused(&self) -> bool;
+}
+
+/// Compile time assert that the [Pausable] interface is implemented by the contract.
+///
+/// Called by the contracts using `#[sv::features(pausable)]` with their `sv::INTERFACE_IDS`,
+/// as without the [Pausable] interface the contract could never be unpaused.
+/// The interface is recognized by its id, so it can be listed under any path.
+pub const fn assert_pausable_listed(interface_ids: &[InterfaceId]) {
+    konst::for_range! {i in 0..interface_ids.len() =>
+        if interface_ids[i].hash() == sv::INTERFACE_ID.hash() {
+            return;
+        }
+    }
+    panic!("Contract using `sv::features(pausable)` requires `#[sv::messages(sylvia::pausable as Pausable)]` attribute.");
+}
+
+/// Interface exposing the pause messages.
+///
+/// All methods are implemented by default, so the contract has to
+/// define only the associated types.
+#[interface]
+pub trait Pausable {
+    type Error: From<StdError>;
+    type ExecC: CustomMsg;
+    type QueryC: CustomQuery;
+
+    /// Returns an error if the sender is not allowed to pause the contract.
+    ///
+    /// By default checks if the sender is the contract owner, failing if the ownership
+    /// was never initialized with [crate::ownable::initialize_owner].
+    fn ensure_pauser(&self, ctx: &ExecCtx<Self::QueryC>) -> Result<(), Self::Error> {
+        if !crate::ownable::has_owner(ctx.deps.storage) {
+            return Err(StdError::generic_err(
+                "Pausable requires the owner set with `sylvia::ownable::initialize_owner` or an overridden `ensure_pauser`",
+            )
+            .into());
+        }
+        Ok(crate::ownable::ensure_owner(ctx)?)
+    }
+
+    /// Pauses the contract.
+    #[sv::msg(exec)]
+    #[sv::allow_when_paused]
+    fn pause(&self, ctx: ExecCtx<Self::QueryC>) -> Result<Response<Self::ExecC>, Self::Error> {
+        self.ensure_pauser(&ctx)?;
+        set_paused(ctx.deps.storage, true)?;
+        Ok(Response::new().add_attribute("action", "pause"))
+    }
+
+    /// Unpauses the contract.
+    #[sv::msg(exec)]
+    #[sv::allow_when_paused]
+    fn unpause(&self, ctx: ExecCtx<Self::QueryC>) -> Result<Response<Self::ExecC>, Self::Error> {
+        self.ensure_pauser(&ctx)?;
+        set_paused(ctx.deps.storage, false)?;
+        Ok(Response::new().add_attribute("action", "unpause"))
+    }
+
+    /// Returns `true` if the contract is paused.
+    #[sv::msg(query)]
+    fn paused(&self, ctx: QueryCtx<Self::QueryC>) -> Result<bool, Self::Error> {
+        Ok(is_paused(ctx.deps.storage)?)
+    }
+}
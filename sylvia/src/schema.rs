@@ -0,0 +1,105 @@
+//! Schema generation driven by the [ContractApi] implementation.
+//!
+//! Replaces the hand-written `cosmwasm_schema::write_api!` call in the schema binary.
+//! The `instantiate`, `exec`, `query` and query responses schemas are always generated.
+//! The `migrate` and `sudo` schemas are generated only if the contract defines such messages.
+//!
+//! ## Example usage
+//! ```rust,ignore
+//! use sylvia::schema::write_contract_api;
+//!
+//! fn main() {
+//!     write_contract_api::<my_contract::Contract>(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+//! }
+//! ```
+
+use std::fs::{create_dir_all, write};
+use std::path::Path;
+
+use cosmwasm_schema::{remove_schemas, schema_for, Api, QueryResponses};
+use schemars::JsonSchema;
+
+use crate::types::ContractApi;
+
+/// Builds the [Api] of the contract.
+pub fn contract_api<Contract>(name: &str, version: &str) -> Api
+where
+    Contract: ContractApi,
+    Contract::Instantiate: JsonSchema,
+    Contract::ContractExec: JsonSchema,
+    Contract::ContractQuery: JsonSchema + QueryResponses,
+    Contract::Migrate: JsonSchema,
+    Contract::ContractSudo: JsonSchema,
+{
+    let migrate = Contract::HAS_MIGRATE.then(|| schema_for!(Contract::Migrate));
+    let sudo = Contract::HAS_SUDO.then(|| schema_for!(Contract::ContractSudo));
+
+    Api {
+        contract_name: name.to_owned(),
+        contract_version: version.to_owned(),
+        instantiate: Some(schema_for!(Contract::Instantiate)),
+        execute: Some(schema_for!(Contract::ContractExec)),
+        query: Some(schema_for!(Contract::ContractQuery)),
+        migrate,
+        sudo,
+        responses: Some(
+            Contract::ContractQuery::response_schemas()
+                .expect("Query responses should not contain duplicated messages"),
+        ),
+    }
+}
+
+/// Writes the schema of the contract to the `schema` directory of the current directory.
+///
+/// The layout is the same as the one generated by the `cosmwasm_schema::write_api!`.
+///
+/// # Panics
+///
+/// Panics if the schema could not be written.
+pub fn write_contract_api<Contract>(name: &str, version: &str)
+where
+    Contract: ContractApi,
+    Contract::Instantiate: JsonSchema,
+    Contract::ContractExec: JsonSchema,
+    Contract::ContractQuery: JsonSchema + QueryResponses,
+    Contract::Migrate: JsonSchema,
+    Contract::ContractSudo: JsonSchema,
+{
+    let out_dir = std::env::current_dir().unwrap().join("schema");
+    write_contract_api_into::<Contract>(&out_dir, name, version);
+}
+
+/// Writes the schema of the contract to the `out_dir` directory.
+///
+/// Full api is saved as `<name>.json` and every message as a separate file in the `raw` subdirectory.
+///
+/// # Panics
+///
+/// Panics if the schema could not be written.
+pub fn write_contract_api_into<Contract>(out_dir: &Path, name: &str, version: &str)
+where
+    Contract: ContractApi,
+    Contract::Instantiate: JsonSchema,
+    Contract::ContractExec: JsonSchema,
+    Contract::ContractQuery: JsonSchema + QueryResponses,
+    Contract::Migrate: JsonSchema,
+    Contract::ContractSudo: JsonSchema,
+{
+    create_dir_all(out_dir).unwrap();
+    remove_schemas(out_dir).unwrap();
+
+    let api = contract_api::<Contract>(name, version).render();
+
+    let path = out_dir.join(format!("{name}.json"));
+    write(&path, api.to_string().unwrap() + "\n").unwrap();
+    println!("Exported the full API as {}", path.display());
+
+    let raw_dir = out_dir.join("raw");
+    create_dir_all(&raw_dir).unwrap();
+
+    for (filename, json) in api.to_schema_files().unwrap() {
+        let path = raw_dir.join(filename);
+        write(&path, json + "\n").unwrap();
+        println!("Exported {}", path.display());
+    }
+}
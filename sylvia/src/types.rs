@@ -1,6 +1,10 @@
 //! Module providing utilities to build and use sylvia contracts.
 
-use cosmwasm_std::{Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, WasmMsg};
+use cosmwasm_std::{
+    Binary, Coin, ContractResult, CosmosMsg, Deps, DepsMut, Empty, Env, IbcAcknowledgement, IbcMsg,
+    IbcReceiveResponse, IbcTimeout, MessageInfo, QueryRequest, StdError, StdResult, SubMsg,
+    SystemResult, WasmMsg, WasmQuery,
+};
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -152,6 +156,55 @@ impl<'a, C: cosmwasm_std::CustomQuery, Contract: ?Sized> BoundQuerier<'a, C, Con
     }
 }
 
+impl<'a, C: cosmwasm_std::CustomQuery, Contract: ?Sized> BoundQuerier<'a, C, Contract> {
+    /// Creates the [QueryBatch](crate::batch::QueryBatch) sent through the underlying querier.
+    ///
+    /// Queries added to the batch can be sent to this or any other contract.
+    pub fn batch(&self) -> crate::batch::QueryBatch<'a, C> {
+        crate::batch::QueryBatch::new(self.querier)
+    }
+
+    /// Queries the ids of the interfaces implemented by the contract.
+    pub fn supported_interfaces(&self) -> StdResult<Vec<String>> {
+        self.querier.query_wasm_smart(
+            self.contract,
+            &SupportedInterfacesQuery::SupportedInterfaces {},
+        )
+    }
+
+    /// Checks if the contract implements the `Interface`.
+    ///
+    /// Contract not using `#[sv::features(supported_interfaces)]` fails the query, which is reported
+    /// as not supporting any interface. The contract error is redacted on chain, so any error
+    /// returned by the contract is treated this way, as the generated query itself cannot fail.
+    /// Errors of the querier, e.g. if the contract doesn't exist, are returned.
+    ///
+    /// ```rust,ignore
+    /// let supports_counter = BoundQuerier::<_, Empty>::borrowed(&addr, &deps.querier)
+    ///     .supports::<dyn Counter<Error = StdError>>()?;
+    /// ```
+    pub fn supports<Interface: self::Interface + ?Sized>(&self) -> StdResult<bool> {
+        let request: QueryRequest<C> = WasmQuery::Smart {
+            contract_addr: self.contract.to_string(),
+            msg: cosmwasm_std::to_json_binary(&SupportedInterfacesQuery::SupportedInterfaces {})?,
+        }
+        .into();
+        let ids: Vec<String> = match self
+            .querier
+            .raw_query(&cosmwasm_std::to_json_vec(&request)?)
+        {
+            SystemResult::Err(system_err) => {
+                return Err(StdError::generic_err(format!(
+                    "Querier system error: {system_err}"
+                )))
+            }
+            SystemResult::Ok(ContractResult::Err(_)) => return Ok(false),
+            SystemResult::Ok(ContractResult::Ok(value)) => cosmwasm_std::from_json(value)?,
+        };
+        Ok(ids.contains(&Interface::ID.to_string()))
+    }
+}
+
 impl<'a, C: cosmwasm_std::CustomQuery, Contract> From<&'a BoundQuerier<'a, C, Contract>>
     for BoundQuerier<'a, C, Contract>
 {
@@ -161,7 +214,11 @@ impl<'a, C: cosmwasm_std::CustomQuery, Contract> From<&'a BoundQuerier<'a, C, Co
 }
 
 pub struct EmptyExecutorBuilderState;
-pub struct ReadyExecutorBuilderState;
+/// State of the [ExecutorBuilder] ready to build the message.
+///
+/// `Data` is the type of the data set in the response of the executed method,
+/// declared with `#[sv::msg(exec, data = Data)]`.
+pub struct ReadyExecutorBuilderState<Data = ()>(std::marker::PhantomData<Data>);
 
 /// This structure represents a collection of execution methods for
 /// a Sylvia contract or interface. An instance of this structure
@@ -298,7 +355,7 @@ impl<T, Contract: ?Sized> ExecutorBuilder<(T, Contract)> {
     }
 }
 
-impl ExecutorBuilder<ReadyExecutorBuilderState> {
+impl<Data> ExecutorBuilder<ReadyExecutorBuilderState<Data>> {
     pub fn new(contract: String, funds: Vec<Coin>, msg: Binary) -> Self {
         Self {
             contract,
@@ -317,6 +374,192 @@ impl ExecutorBuilder<ReadyExecutorBuilderState> {
     }
 }
 
+/// Marks the messages which can be sent as the submessage handled by the reply handler
+/// decoding the data of the reply to `Data`.
+///
+/// Every message accepts any `Data`, except for the [ExecutorBuilder] of the method declaring
+/// its data with `#[sv::msg(exec, data = Data)]`, which accepts only the matching reply handlers.
+pub trait ExecReplyData<Data> {}
+
+impl<C, Data> ExecReplyData<Data> for SubMsg<C> {}
+
+impl<C, Data> ExecReplyData<Data> for CosmosMsg<C> {}
+
+impl<Data> ExecReplyData<Data> for WasmMsg {}
+
+impl<Data> ExecReplyData<Data> for ExecutorBuilder<ReadyExecutorBuilderState<Data>> {}
+
+/// This structure represents a collection of typed IBC packets of a Sylvia contract.
+///
+/// [IbcPacketBuilder] implements the `IbcPacketSender` trait generated by the `contract` macro,
+/// encompassing all `sv::msg(ibc_packet)` methods of the specified contract.
+/// Every method of the trait encodes the packet and wraps it in the
+/// [IbcMsg::SendPacket] message.
+///
+/// ```rust
+/// pub mod counterparty {
+///     # use cosmwasm_std::{Response, StdResult};
+///     # use sylvia::contract;
+///     # use sylvia::ctx::{IbcPacketCtx, InstantiateCtx};
+///     pub struct CounterpartyContract;
+///
+///     #[contract]
+///     impl CounterpartyContract {
+///         pub fn new() -> Self { Self }
+///
+///         #[sv::msg(instantiate)]
+///         fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+///             Ok(Response::new())
+///         }
+///
+///         #[sv::msg(ibc_packet)]
+///         fn ping(&self, ctx: IbcPacketCtx, value: u32) -> StdResult<u32> {
+///             Ok(value)
+///         }
+///     }
+/// }
+/// # use counterparty::CounterpartyContract;
+/// # use counterparty::sv::IbcPacketSender;
+/// # use cosmwasm_std::{IbcBasicResponse, IbcTimeout, StdResult, Timestamp};
+/// # use sylvia::types::IbcPacketBuilder;
+///
+/// fn send_ping(channel_id: &str) -> StdResult<IbcBasicResponse> {
+///     let timeout = IbcTimeout::with_timestamp(Timestamp::from_seconds(3600));
+///     let msg = IbcPacketBuilder::<CounterpartyContract>::new(channel_id, timeout).ping(42)?;
+///     Ok(IbcBasicResponse::new().add_attribute("packet", format!("{msg:?}")))
+/// }
+/// ```
+pub struct IbcPacketBuilder<Contract: ?Sized> {
+    channel_id: String,
+    timeout: IbcTimeout,
+    _phantom: std::marker::PhantomData<Contract>,
+}
+
+impl<Contract: ?Sized> IbcPacketBuilder<Contract> {
+    /// Creates an instance of the structure based
+    /// on the channel the packets will be sent through.
+    pub fn new(channel_id: impl Into<String>, timeout: impl Into<IbcTimeout>) -> Self {
+        Self {
+            channel_id: channel_id.into(),
+            timeout: timeout.into(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the channel id set in this builder.
+    pub fn channel_id(&self) -> &str {
+        &self.channel_id
+    }
+
+    /// Returns the timeout set in this builder.
+    pub fn timeout(&self) -> &IbcTimeout {
+        &self.timeout
+    }
+
+    /// Encodes the packet and builds the [IbcMsg::SendPacket] message.
+    pub fn build(self, packet: &impl Serialize) -> StdResult<IbcMsg> {
+        Ok(IbcMsg::SendPacket {
+            channel_id: self.channel_id,
+            data: cosmwasm_std::to_json_binary(packet)?,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Typed acknowledgement of the IBC packet.
+///
+/// Serialized the same way as the ICS-20 acknowledgement, so `{"result": ...}` in case of success
+/// and `{"error": "..."}` in case of failure.
+/// The `ibc_packet_receive` entry point generated for the `sv::msg(ibc_packet)` methods writes
+/// the result of the handler as the acknowledgement, and the error acknowledgement if the incoming
+/// packet cannot be decoded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PacketAck<T> {
+    Result(T),
+    Error(String),
+}
+
+impl<T> PacketAck<T> {
+    /// Creates a successful acknowledgement.
+    pub fn success(result: T) -> Self {
+        Self::Result(result)
+    }
+
+    /// Creates an error acknowledgement.
+    pub fn error(error: impl Into<String>) -> Self {
+        Self::Error(error.into())
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Result(_))
+    }
+
+    /// Converts the acknowledgement into [Result].
+    pub fn into_result(self) -> Result<T, String> {
+        match self {
+            Self::Result(result) => Ok(result),
+            Self::Error(error) => Err(error),
+        }
+    }
+}
+
+impl<T: Serialize> PacketAck<T> {
+    /// Encodes the acknowledgement so it can be passed to the
+    /// [IbcReceiveResponse].
+    pub fn to_binary(&self) -> StdResult<Binary> {
+        cosmwasm_std::to_json_binary(self)
+    }
+}
+
+impl<T: DeserializeOwned> PacketAck<T> {
+    /// Decodes the acknowledgement received in the `ibc_packet_ack` entry point.
+    pub fn from_ack(ack: &IbcAcknowledgement) -> StdResult<Self> {
+        cosmwasm_std::from_json(&ack.data)
+    }
+}
+
+/// Successful result of the `sv::msg(ibc_packet)` handler sending the messages, attributes
+/// and events together with the acknowledgement.
+///
+/// Handlers returning other types only write them as the [PacketAck::Result].
+/// The macro recognizes the [PacketResponse] by its name in the handler signature,
+/// so it cannot be returned through a type alias.
+///
+/// ```rust
+/// # use sylvia::ctx::IbcPacketCtx;
+/// # use sylvia::cw_std::{IbcReceiveResponse, StdResult};
+/// # use sylvia::types::PacketResponse;
+/// # struct Contract;
+/// # impl Contract {
+/// fn transfer(&self, ctx: IbcPacketCtx, amount: u128) -> StdResult<PacketResponse<u128>> {
+///     let response = IbcReceiveResponse::without_ack().add_attribute("relayer", ctx.relayer);
+///     Ok(PacketResponse::new(amount, response))
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PacketResponse<T, C = Empty> {
+    pub ack: T,
+    pub response: IbcReceiveResponse<C>,
+}
+
+impl<T, C> PacketResponse<T, C> {
+    /// Creates the response, its acknowledgement is replaced with the `ack`.
+    pub fn new(ack: T, response: IbcReceiveResponse<C>) -> Self {
+        Self { ack, response }
+    }
+}
+
+impl<T: Serialize, C> PacketResponse<T, C> {
+    /// Converts into the [IbcReceiveResponse] with the `ack` written as the [PacketAck::Result].
+    pub fn into_receive_response(self) -> StdResult<IbcReceiveResponse<C>> {
+        let Self { ack, mut response } = self;
+        response.acknowledgement = Some(PacketAck::success(ack).to_binary()?);
+        Ok(response)
+    }
+}
+
 /// Represents a contract on the chain and acts as a gateway to communicate with it.
 ///
 /// # Example
@@ -596,6 +839,442 @@ impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for SudoCtx<'
     }
 }
 
+/// Data of the reply to the instantiate submessage.
+///
+/// Passed to the reply handler parameter marked with `#[sv::data(instantiate = T)]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstantiateReplyData<T> {
+    /// Address of the instantiated contract.
+    pub contract_address: cosmwasm_std::Addr,
+    /// Data set by the instantiated contract deserialized to `T`.
+    pub data: T,
+}
+
+/// Funds policy of the `exec` or `instantiate` method.
+///
+/// Set with the `#[sv::funds(...)]` attribute and checked by the generated `dispatch`
+/// before calling the method.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FundsPolicy {
+    /// Accepts any funds. Optionally restricted to a single denom.
+    Payable(Option<&'static str>),
+    /// Rejects any funds.
+    Nonpayable,
+    /// Requires non zero funds in exactly one, given denom.
+    MustPay(&'static str),
+}
+
+impl Default for FundsPolicy {
+    fn default() -> Self {
+        Self::Payable(None)
+    }
+}
+
+impl FundsPolicy {
+    /// Checks if funds sent with the message satisfy the policy.
+    pub fn check(&self, info: &MessageInfo) -> StdResult<()> {
+        let result = match self {
+            Self::Payable(None) => Ok(()),
+            Self::Payable(Some(denom)) => cw_utils::may_pay(info, denom).map(|_| ()),
+            Self::Nonpayable => cw_utils::nonpayable(info),
+            Self::MustPay(denom) => cw_utils::must_pay(info, denom).map(|_| ()),
+        };
+        result.map_err(|err| StdError::generic_err(err.to_string()))
+    }
+
+    /// Returns `true` if the policy rejects any funds.
+    pub fn is_nonpayable(&self) -> bool {
+        matches!(self, Self::Nonpayable)
+    }
+}
+
+/// Stable identifier of the [interface](crate::interface).
+///
+/// Consists of the interface name and the hash of its messages signatures, so changing any of
+/// the messages results in a different id.
+/// Displayed as `<name>:<hash>`, with the hash in the hexadecimal form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterfaceId {
+    name: &'static str,
+    hash: u64,
+}
+
+impl InterfaceId {
+    pub const fn new(name: &'static str, signature: &str) -> Self {
+        Self {
+            name,
+            hash: crate::utils::interface_id_hash(name, signature),
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl std::fmt::Display for InterfaceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{:016x}", self.name, self.hash)
+    }
+}
+
+/// Implemented by the [interface](crate::interface) macro for the `dyn Interface` type.
+///
+/// Allows to check if the remote contract implements the interface using
+/// [BoundQuerier::supports].
+pub trait Interface {
+    const ID: InterfaceId;
+}
+
+/// Query exposed by the [contract](crate::contract) using `#[sv::features(supported_interfaces)]`
+/// returning the ids of the implemented interfaces.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SupportedInterfacesQuery {
+    SupportedInterfaces {},
+}
+
+impl SupportedInterfacesQuery {
+    pub const fn messages() -> [&'static str; 1] {
+        ["supported_interfaces"]
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl cosmwasm_schema::QueryResponses for SupportedInterfacesQuery {
+    fn response_schemas_impl() -> std::collections::BTreeMap<String, schemars::schema::RootSchema> {
+        std::collections::BTreeMap::from([(
+            "supported_interfaces".to_owned(),
+            cosmwasm_schema::schema_for!(Vec<String>),
+        )])
+    }
+}
+
+/// Validation of the addresses received as strings in the message.
+///
+/// Used by the generated dispatch to convert parameters marked with the `sv::addr` attribute into
+/// the validated [Addr](cosmwasm_std::Addr) before passing them to the method.
+pub trait ValidateAddr {
+    type Validated;
+
+    fn validate(
+        self,
+        field: &'static str,
+        api: &dyn cosmwasm_std::Api,
+    ) -> Result<Self::Validated, InvalidAddr>;
+}
+
+impl ValidateAddr for String {
+    type Validated = cosmwasm_std::Addr;
+
+    fn validate(
+        self,
+        field: &'static str,
+        api: &dyn cosmwasm_std::Api,
+    ) -> Result<Self::Validated, InvalidAddr> {
+        api.addr_validate(&self).map_err(|err| InvalidAddr {
+            field,
+            addr: self,
+            reason: err.to_string(),
+        })
+    }
+}
+
+impl<T: ValidateAddr> ValidateAddr for Option<T> {
+    type Validated = Option<T::Validated>;
+
+    fn validate(
+        self,
+        field: &'static str,
+        api: &dyn cosmwasm_std::Api,
+    ) -> Result<Self::Validated, InvalidAddr> {
+        self.map(|addr| addr.validate(field, api)).transpose()
+    }
+}
+
+impl<T: ValidateAddr> ValidateAddr for Vec<T> {
+    type Validated = Vec<T::Validated>;
+
+    fn validate(
+        self,
+        field: &'static str,
+        api: &dyn cosmwasm_std::Api,
+    ) -> Result<Self::Validated, InvalidAddr> {
+        self.into_iter()
+            .map(|addr| addr.validate(field, api))
+            .collect()
+    }
+}
+
+/// Error of the [ValidateAddr] naming the message field with the invalid address.
+///
+/// The generated dispatch converts it with [From] into the error type of the message,
+/// so the contract error can keep it as a separate variant. [StdError] stores it as the
+/// [StdError::GenericErr].
+///
+/// ```rust
+/// use sylvia::cw_std::StdError;
+/// use sylvia::types::InvalidAddr;
+///
+/// #[derive(thiserror::Error, Debug, PartialEq)]
+/// pub enum ContractError {
+///     #[error(transparent)]
+///     Std(#[from] StdError),
+///     #[error(transparent)]
+///     InvalidAddr(#[from] InvalidAddr),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidAddr {
+    pub field: &'static str,
+    pub addr: String,
+    /// Message of the error returned by the [Api::addr_validate](cosmwasm_std::Api::addr_validate).
+    pub reason: String,
+}
+
+impl std::fmt::Display for InvalidAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid address `{}` in the `{}` field: {}",
+            self.addr, self.field, self.reason
+        )
+    }
+}
+
+impl std::error::Error for InvalidAddr {}
+
+impl From<InvalidAddr> for StdError {
+    fn from(err: InvalidAddr) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+/// Checks of the message parameters marked with the `sv::validate` attribute.
+///
+/// Used by the generated dispatch before passing the parameters to the method.
+/// Every check returns the [StdError::GenericErr] naming the invalid parameter.
+pub struct FieldValidator<'a, T: ?Sized> {
+    field: &'static str,
+    value: &'a T,
+}
+
+impl<'a, T: ?Sized> FieldValidator<'a, T> {
+    pub fn new(field: &'static str, value: &'a T) -> Self {
+        Self { field, value }
+    }
+
+    pub fn min<U>(self, min: U) -> StdResult<Self>
+    where
+        T: PartialOrd<U>,
+        U: std::fmt::Display,
+    {
+        match *self.value < min {
+            true => Err(self.error(format!("must be at least {min}"))),
+            false => Ok(self),
+        }
+    }
+
+    pub fn max<U>(self, max: U) -> StdResult<Self>
+    where
+        T: PartialOrd<U>,
+        U: std::fmt::Display,
+    {
+        match *self.value > max {
+            true => Err(self.error(format!("must be at most {max}"))),
+            false => Ok(self),
+        }
+    }
+
+    pub fn length(self, min: Option<usize>, max: Option<usize>) -> StdResult<Self>
+    where
+        T: FieldLength,
+    {
+        let length = self.value.field_length();
+        match (min, max) {
+            (Some(min), _) if length < min => {
+                Err(self.error(format!("length must be at least {min}")))
+            }
+            (_, Some(max)) if length > max => {
+                Err(self.error(format!("length must be at most {max}")))
+            }
+            _ => Ok(self),
+        }
+    }
+
+    pub fn chars(self, allowed: impl Fn(char) -> bool) -> StdResult<Self>
+    where
+        T: AsRef<str>,
+    {
+        match self.value.as_ref().chars().find(|c| !allowed(*c)) {
+            Some(c) => Err(self.error(format!("character `{c}` is not allowed"))),
+            None => Ok(self),
+        }
+    }
+
+    fn error(&self, reason: String) -> StdError {
+        StdError::generic_err(format!("Invalid `{}`: {reason}", self.field))
+    }
+}
+
+/// Length of the parameter checked by the `sv::validate(length(..))` rule.
+///
+/// Strings are measured in characters, the same way as the `maxLength` of the JSON schema.
+pub trait FieldLength {
+    fn field_length(&self) -> usize;
+}
+
+impl FieldLength for str {
+    fn field_length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl FieldLength for String {
+    fn field_length(&self) -> usize {
+        self.as_str().field_length()
+    }
+}
+
+impl<T> FieldLength for [T] {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> FieldLength for Vec<T> {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl FieldLength for cosmwasm_std::Binary {
+    fn field_length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Limit of the page of the query marked with `#[sv::msg(query, paginated)]`.
+///
+/// Used by the generated dispatch to clamp the `limit` sent in the message, falling back
+/// to the `default` if the limit is missing.
+pub fn page_limit(limit: Option<u32>, default: u32, max: u32) -> u32 {
+    limit.unwrap_or(default).min(max)
+}
+
+/// Response of the query marked with `#[sv::msg(query, paginated)]`.
+///
+/// Splits the response into the items of the page and the `start_after` of the next page,
+/// which is `None` after the last page.
+pub trait Paginated {
+    type Item;
+    type Cursor;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<Self::Cursor>);
+}
+
+/// Generic response of the paginated query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Page<T, C> {
+    pub items: Vec<T>,
+    pub next_start_after: Option<C>,
+}
+
+impl<T, C> Page<T, C> {
+    /// Creates the page out of the items loaded with the `limit`.
+    ///
+    /// The page is considered the last one if it contains fewer items than the `limit`,
+    /// otherwise the `cursor` of the last item becomes the `start_after` of the next page.
+    ///
+    /// ```rust
+    /// use sylvia::types::{Page, Paginated};
+    ///
+    /// let members = vec!["alice".to_owned(), "bob".to_owned()];
+    /// let page = Page::from_items(members, 2, |member| member.clone());
+    /// assert_eq!(page.next_start_after, Some("bob".to_owned()));
+    ///
+    /// let (items, next) = Page::from_items(vec![1, 2], 3, |item| *item).into_page();
+    /// assert_eq!((items, next), (vec![1, 2], None));
+    /// ```
+    pub fn from_items(items: Vec<T>, limit: u32, cursor: impl FnOnce(&T) -> C) -> Self {
+        let next_start_after = match items.len() < limit as usize {
+            true => None,
+            false => items.last().map(cursor),
+        };
+        Self {
+            items,
+            next_start_after,
+        }
+    }
+}
+
+impl<T, C> Paginated for Page<T, C> {
+    type Item = T;
+    type Cursor = C;
+
+    fn into_page(self) -> (Vec<T>, Option<C>) {
+        (self.items, self.next_start_after)
+    }
+}
+
+/// Iterator over the items of all the pages of the paginated query.
+///
+/// Returned by the `<query>_iter` methods generated on the `Querier` traits and the multitest
+/// proxies. The next page is queried once the items of the previous one are consumed.
+/// The iteration stops after the last page or the first error.
+pub struct PageIter<'a, T, E = StdError> {
+    items: std::vec::IntoIter<T>,
+    next_page: Box<dyn FnMut() -> Option<Result<Vec<T>, E>> + 'a>,
+}
+
+impl<'a, T, E> PageIter<'a, T, E> {
+    /// Creates the iterator calling the `query` with the `start_after` of the consecutive pages.
+    pub fn new<R>(mut query: impl FnMut(Option<R::Cursor>) -> Result<R, E> + 'a) -> Self
+    where
+        R: Paginated<Item = T>,
+        R::Cursor: 'a,
+    {
+        // `None` once the last page has been queried.
+        let mut start_after = Some(None);
+        let next_page = move || {
+            let page = query(start_after.take()?);
+            Some(page.map(|page| {
+                let (items, next_start_after) = page.into_page();
+                if !items.is_empty() {
+                    start_after = next_start_after.map(Some);
+                }
+                items
+            }))
+        };
+
+        Self {
+            items: vec![].into_iter(),
+            next_page: Box::new(next_page),
+        }
+    }
+}
+
+impl<T, E> Iterator for PageIter<'_, T, E> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+            match (self.next_page)()? {
+                Ok(items) => self.items = items.into_iter(),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
 /// Set of trait bounds for custom messages.
 pub trait CustomMsg: cosmwasm_std::CustomMsg + DeserializeOwned {}
 
@@ -632,4 +1311,18 @@ pub trait ContractApi {
     type Remote<'remote>;
     type CustomMsg: CustomMsg;
     type CustomQuery: CustomQuery;
+    /// `true` if the contract defines the `sv::msg(migrate)` method.
+    /// Otherwise [ContractApi::Migrate] is `Empty`.
+    const HAS_MIGRATE: bool = false;
+    /// `true` if the contract or any of its interfaces defines the `sv::msg(sudo)` method.
+    /// Otherwise [ContractApi::ContractSudo] has no variants.
+    const HAS_SUDO: bool = false;
+}
+
+/// Api trait for access to the generated `IbcPacketMsg`.
+///
+/// Implemented by the `contract` macro next to the [ContractApi], as `Empty` if the contract
+/// doesn't define any `sv::msg(ibc_packet)` methods.
+pub trait IbcPacketApi {
+    type IbcPacket;
 }
@@ -0,0 +1,420 @@
+//! TypeScript client generation.
+//!
+//! With the `typescript` feature every contract implements [TypeScriptApi], built from
+//! the same messages as the generated `BoundQuerier` and `ExecutorBuilder`.
+//! The generated module contains the types of the messages and query responses,
+//! a query client mirroring the `BoundQuerier` and an exec client mirroring the `ExecutorBuilder`,
+//! for the contract and every interface it implements.
+//!
+//! The clients are using the [CosmJS](https://github.com/cosmos/cosmjs) library.
+//!
+//! ## Example usage
+//! ```rust,ignore
+//! use sylvia::typescript::write_contract_module;
+//!
+//! fn main() {
+//!     write_contract_module::<my_contract::Contract>("ts/Contract.ts".as_ref());
+//! }
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use convert_case::{Case, Casing};
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::{InstanceType, Schema, SchemaObject, SingleOrVec};
+use schemars::JsonSchema;
+
+/// Implemented by the `sylvia::contract` macro.
+pub trait TypeScriptApi {
+    /// Name of the contract type.
+    const NAME: &'static str;
+
+    /// Returns the TypeScript type of the instantiate message.
+    fn ts_instantiate(gen: &mut TsGenerator) -> String;
+
+    /// Returns clients of the contract and of the interfaces it implements.
+    fn ts_clients(gen: &mut TsGenerator) -> Vec<TsClient>;
+}
+
+/// Converts the JSON schemas of the Rust types into the TypeScript types.
+///
+/// Types with the named schema are collected and emitted as separate declarations.
+pub struct TsGenerator {
+    gen: SchemaGenerator,
+}
+
+impl Default for TsGenerator {
+    fn default() -> Self {
+        Self {
+            gen: SchemaSettings::draft07().into_generator(),
+        }
+    }
+}
+
+impl TsGenerator {
+    /// Returns the TypeScript type of `T`.
+    pub fn ts_type<T: JsonSchema>(&mut self) -> String {
+        let schema = self.gen.subschema_for::<T>();
+        render_type(&schema)
+    }
+
+    /// Emits declarations of all the named types used so far.
+    pub fn emit_declarations(&self) -> String {
+        let mut out = String::new();
+        for (name, schema) in self.gen.definitions() {
+            let name = type_name(name);
+            let doc = schema_description(schema)
+                .map(|doc| emit_doc(&doc, ""))
+                .unwrap_or_default();
+            let declaration = match schema {
+                Schema::Object(object) if is_plain_object(object) => {
+                    format!("export interface {name} {}", render_object(object))
+                }
+                schema => format!("export type {name} = {};", render_type(schema)),
+            };
+            let _ = writeln!(out, "{doc}{declaration}\n");
+        }
+        out
+    }
+}
+
+/// Single message of the [TsClient].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsMethod {
+    /// Name of the message as it is serialized.
+    pub name: String,
+    /// Fields of the message with their TypeScript types.
+    pub params: Vec<(String, String)>,
+    /// TypeScript type of the query response.
+    pub response: Option<String>,
+}
+
+impl TsMethod {
+    pub fn new(name: &str, params: Vec<(&str, String)>, response: Option<String>) -> Self {
+        Self {
+            name: name.to_owned(),
+            params: params
+                .into_iter()
+                .map(|(name, ty)| (name.to_owned(), ty))
+                .collect(),
+            response,
+        }
+    }
+
+    fn emit_msg(&self) -> String {
+        let fields = self
+            .params
+            .iter()
+            .map(|(name, _)| format!("{name}: {}", name.to_case(Case::Camel)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match fields.is_empty() {
+            true => format!("{{ {}: {{}} }}", self.name),
+            false => format!("{{ {}: {{ {fields} }} }}", self.name),
+        }
+    }
+
+    fn emit_msg_type(&self) -> String {
+        let fields = self
+            .params
+            .iter()
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        match fields.is_empty() {
+            true => format!("{{ {}: {{}} }}", self.name),
+            false => format!("{{ {}: {{ {fields} }} }}", self.name),
+        }
+    }
+
+    /// Name of the trailing parameter with the funds, fee and memo of the exec message,
+    /// not colliding with the fields of the message.
+    fn options_param(&self) -> String {
+        let mut options = "options".to_owned();
+        while self
+            .params
+            .iter()
+            .any(|(name, _)| name.to_case(Case::Camel) == options)
+        {
+            options.push('_');
+        }
+        options
+    }
+
+    fn emit_params(&self) -> Vec<String> {
+        self.params
+            .iter()
+            .map(|(name, ty)| format!("{}: {ty}", name.to_case(Case::Camel)))
+            .collect()
+    }
+}
+
+/// Query and exec messages of the contract or of the interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsClient {
+    pub name: String,
+    pub queries: Vec<TsMethod>,
+    pub execs: Vec<TsMethod>,
+}
+
+impl TsClient {
+    pub fn new(name: &str, queries: Vec<TsMethod>, execs: Vec<TsMethod>) -> Self {
+        Self {
+            name: name.to_owned(),
+            queries,
+            execs,
+        }
+    }
+
+    /// Emits the message types and the clients.
+    pub fn emit(&self) -> String {
+        let Self {
+            name,
+            queries,
+            execs,
+        } = self;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "export type {name}QueryMsg = {};\n",
+            emit_union(queries.iter().map(TsMethod::emit_msg_type))
+        );
+        let _ = writeln!(
+            out,
+            "export type {name}ExecMsg = {};\n",
+            emit_union(execs.iter().map(TsMethod::emit_msg_type))
+        );
+
+        let _ = writeln!(out, "export class {name}QueryClient {{");
+        let _ = writeln!(
+            out,
+            "  constructor(readonly client: CosmWasmClient, readonly contractAddress: string) {{}}"
+        );
+        for query in queries {
+            let method = query.name.to_case(Case::Camel);
+            let params = query.emit_params().join(", ");
+            let response = query.response.as_deref().unwrap_or("unknown");
+            let _ = writeln!(out);
+            let _ = writeln!(
+                out,
+                "  async {method}({params}): Promise<{response}> {{\n    return this.client.queryContractSmart(this.contractAddress, {});\n  }}",
+                query.emit_msg()
+            );
+        }
+        let _ = writeln!(out, "}}\n");
+
+        let _ = writeln!(
+            out,
+            "export class {name}Client extends {name}QueryClient {{"
+        );
+        let _ = writeln!(
+            out,
+            "  constructor(readonly signingClient: SigningCosmWasmClient, readonly sender: string, contractAddress: string) {{\n    super(signingClient, contractAddress);\n  }}"
+        );
+        for exec in execs {
+            let method = exec.name.to_case(Case::Camel);
+            let options = exec.options_param();
+            let mut params = exec.emit_params();
+            params.push(format!(
+                "{options}: {{ funds?: readonly Coin[]; fee?: StdFee | \"auto\" | number; memo?: string }} = {{}}"
+            ));
+            let params = params.join(", ");
+            let _ = writeln!(out);
+            let _ = writeln!(
+                out,
+                "  async {method}({params}): Promise<ExecuteResult> {{\n    return this.signingClient.execute(this.sender, this.contractAddress, {}, {options}.fee ?? \"auto\", {options}.memo, {options}.funds);\n  }}",
+                exec.emit_msg()
+            );
+        }
+        let _ = writeln!(out, "}}");
+
+        out
+    }
+}
+
+/// Generates the TypeScript module of the contract.
+pub fn contract_module<Contract: TypeScriptApi>() -> String {
+    let mut gen = TsGenerator::default();
+    let instantiate = Contract::ts_instantiate(&mut gen);
+    let clients = Contract::ts_clients(&mut gen);
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "// This file was generated by sylvia from the `{}` contract. Do not edit it manually.\n",
+        Contract::NAME
+    );
+    let _ = writeln!(
+        out,
+        "import {{ CosmWasmClient, ExecuteResult, SigningCosmWasmClient }} from \"@cosmjs/cosmwasm-stargate\";"
+    );
+    let _ = writeln!(out, "import {{ Coin, StdFee }} from \"@cosmjs/amino\";\n");
+    out.push_str(&gen.emit_declarations());
+    if instantiate != "InstantiateMsg" {
+        let _ = writeln!(out, "export type InstantiateMsg = {instantiate};\n");
+    }
+    let clients = clients.iter().map(TsClient::emit).collect::<Vec<_>>();
+    out.push_str(&clients.join("\n"));
+    out
+}
+
+/// Writes the TypeScript module of the contract to the `path`.
+///
+/// # Panics
+///
+/// Panics if the module could not be written.
+pub fn write_contract_module<Contract: TypeScriptApi>(path: &Path) {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).unwrap();
+    }
+    std::fs::write(path, contract_module::<Contract>()).unwrap();
+    println!("Exported the TypeScript client as {}", path.display());
+}
+
+fn emit_union(types: impl Iterator<Item = String>) -> String {
+    let types: Vec<_> = types.collect();
+    match types.is_empty() {
+        true => "never".to_owned(),
+        false => types.join(" | "),
+    }
+}
+
+fn emit_doc(doc: &str, indent: &str) -> String {
+    let lines = doc
+        .lines()
+        .map(|line| format!("{indent} * {line}").trim_end().to_owned())
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{indent}/**\n{lines}\n{indent} */\n")
+}
+
+/// Replaces characters not allowed in the TypeScript identifiers.
+fn type_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn schema_description(schema: &Schema) -> Option<String> {
+    match schema {
+        Schema::Object(object) => object.metadata.as_ref()?.description.clone(),
+        Schema::Bool(_) => None,
+    }
+}
+
+fn is_plain_object(object: &SchemaObject) -> bool {
+    object.reference.is_none()
+        && object.subschemas.is_none()
+        && object.enum_values.is_none()
+        && object.has_type(InstanceType::Object)
+        && object
+            .object
+            .as_ref()
+            .is_some_and(|validation| !validation.properties.is_empty())
+}
+
+fn render_type(schema: &Schema) -> String {
+    match schema {
+        Schema::Bool(true) => "unknown".to_owned(),
+        Schema::Bool(false) => "never".to_owned(),
+        Schema::Object(object) => render_object_schema(object),
+    }
+}
+
+fn render_object_schema(object: &SchemaObject) -> String {
+    if let Some(reference) = &object.reference {
+        let name = reference.rsplit('/').next().unwrap_or(reference);
+        return type_name(name);
+    }
+
+    if let Some(value) = &object.const_value {
+        return value.to_string();
+    }
+
+    if let Some(values) = &object.enum_values {
+        return emit_union(values.iter().map(ToString::to_string));
+    }
+
+    if let Some(subschemas) = &object.subschemas {
+        if let Some(all_of) = &subschemas.all_of {
+            return all_of
+                .iter()
+                .map(|schema| wrap_union(render_type(schema)))
+                .collect::<Vec<_>>()
+                .join(" & ");
+        }
+        if let Some(variants) = subschemas.any_of.as_ref().or(subschemas.one_of.as_ref()) {
+            let mut seen = BTreeSet::new();
+            return emit_union(
+                variants
+                    .iter()
+                    .map(render_type)
+                    .filter(|ty| seen.insert(ty.clone())),
+            );
+        }
+    }
+
+    let types: Vec<InstanceType> = match &object.instance_type {
+        Some(SingleOrVec::Single(ty)) => vec![**ty],
+        Some(SingleOrVec::Vec(types)) => types.clone(),
+        None => return "unknown".to_owned(),
+    };
+
+    emit_union(types.into_iter().map(|ty| match ty {
+        InstanceType::Null => "null".to_owned(),
+        InstanceType::Boolean => "boolean".to_owned(),
+        InstanceType::Integer | InstanceType::Number => "number".to_owned(),
+        InstanceType::String => "string".to_owned(),
+        InstanceType::Array => render_array(object),
+        InstanceType::Object => render_object(object),
+    }))
+}
+
+fn render_array(object: &SchemaObject) -> String {
+    match object.array.as_ref().and_then(|array| array.items.as_ref()) {
+        Some(SingleOrVec::Single(item)) => format!("{}[]", wrap_union(render_type(item))),
+        Some(SingleOrVec::Vec(items)) => format!(
+            "[{}]",
+            items.iter().map(render_type).collect::<Vec<_>>().join(", ")
+        ),
+        None => "unknown[]".to_owned(),
+    }
+}
+
+fn render_object(object: &SchemaObject) -> String {
+    let Some(validation) = &object.object else {
+        return "Record<string, unknown>".to_owned();
+    };
+
+    if validation.properties.is_empty() {
+        return match validation.additional_properties.as_deref() {
+            Some(Schema::Bool(false)) => "{}".to_owned(),
+            Some(schema) => format!("Record<string, {}>", render_type(schema)),
+            None => "Record<string, unknown>".to_owned(),
+        };
+    }
+
+    let mut out = "{\n".to_owned();
+    for (name, schema) in &validation.properties {
+        if let Some(doc) = schema_description(schema) {
+            out.push_str(&emit_doc(&doc, "  "));
+        }
+        let optional = match validation.required.contains(name) {
+            true => "",
+            false => "?",
+        };
+        let _ = writeln!(out, "  {name}{optional}: {};", render_type(schema));
+    }
+    out.push('}');
+    out
+}
+
+fn wrap_union(ty: String) -> String {
+    match ty.contains(" | ") || ty.contains(" & ") {
+        true => format!("({ty})"),
+        false => ty,
+    }
+}
@@ -1,3 +1,5 @@
+use crate::types::InterfaceId;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum State {
     // Ongoing arrays can be compared to other arrays.
@@ -119,6 +121,120 @@ const fn should_end<const N: usize>(states: &[State; N]) -> bool {
     true
 }
 
+/// Computes the reply id for the handler pinned with `#[sv::msg(reply, id = hash)]`.
+///
+/// The value is the [fnv1a] hash of the handler name, so it doesn't change when other
+/// handlers are added, removed or reordered.
+/// ```
+///     const ID: u64 = sylvia::utils::reply_id_hash("instantiate_remote");
+///     assert_eq!(ID, sylvia::utils::reply_id_hash("instantiate_remote"));
+/// ```
+pub const fn reply_id_hash(handler: &str) -> u64 {
+    fnv1a(FNV_OFFSET_BASIS, handler.as_bytes())
+}
+
+/// Compile time [fnv1a] hash of the interface name and its messages signature.
+/// Used to build the [InterfaceId].
+/// ```
+///     const HASH: u64 = sylvia::utils::interface_id_hash("Counter", "query count() -> uint64");
+///     assert_ne!(HASH, sylvia::utils::interface_id_hash("Counter", "query count() -> uint32"));
+/// ```
+pub const fn interface_id_hash(name: &str, signature: &str) -> u64 {
+    let hash = fnv1a(FNV_OFFSET_BASIS, name.as_bytes());
+    let hash = fnv1a(hash, b":");
+    fnv1a(hash, signature.as_bytes())
+}
+
+/// Computes the default code of the error variant marked with `#[sylvia::error_codes]`.
+///
+/// The value is the [fnv1a] hash of the variant name folded to 32 bits, so it doesn't change
+/// when other variants are added, removed or reordered.
+/// ```
+///     const CODE: u32 = sylvia::utils::error_code_hash("Unauthorized");
+///     assert_ne!(CODE, sylvia::utils::error_code_hash("Frozen"));
+/// ```
+pub const fn error_code_hash(variant: &str) -> u32 {
+    let hash = fnv1a(FNV_OFFSET_BASIS, variant.as_bytes());
+    (hash ^ (hash >> 32)) as u32
+}
+
+/// Initial value of the [fnv1a] hash.
+pub const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+/// 64-bit FNV-1a hash of the `bytes`, starting from the `hash` of the preceding bytes
+/// or the [FNV_OFFSET_BASIS].
+///
+/// It's the only hash used to derive the values from the names: the [reply_id_hash],
+/// the [interface_id_hash] and the [error_code_hash]. These values are stored on chain
+/// and sent to other contracts, so this function and the way the values are derived from it
+/// must never change, otherwise the deployed contracts would stop matching them.
+/// ```
+///     use sylvia::utils::{fnv1a, FNV_OFFSET_BASIS};
+///
+///     assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xaf63dc4c8601ec8c);
+///     assert_eq!(fnv1a(fnv1a(FNV_OFFSET_BASIS, b"a"), b"b"), fnv1a(FNV_OFFSET_BASIS, b"ab"));
+/// ```
+pub const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    konst::for_range! {i in 0..bytes.len() =>
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Compile time reply ids uniqueness assert.
+/// Will panic! in case the same reply id is used by more than one handler.
+/// ```
+///     const _: () = sylvia::utils::assert_unique_reply_ids(&[0, 1, 42]);
+/// ```
+pub const fn assert_unique_reply_ids(reply_ids: &[u64]) {
+    konst::for_range! {i in 0..reply_ids.len() =>
+        konst::for_range! {j in (i + 1)..reply_ids.len() =>
+            if reply_ids[i] == reply_ids[j] {
+                panic!("Reply id is used by more than one handler!");
+            }
+        }
+    }
+}
+
+/// Compile time error codes uniqueness assert.
+/// Will panic! in case the same code is assigned to more than one variant of the error.
+/// ```
+///     const _: () = sylvia::utils::assert_unique_error_codes(&[
+///         1,
+///         sylvia::utils::error_code_hash("Unauthorized"),
+///     ]);
+/// ```
+pub const fn assert_unique_error_codes(codes: &[u32]) {
+    konst::for_range! {i in 0..codes.len() =>
+        konst::for_range! {j in (i + 1)..codes.len() =>
+            if codes[i] == codes[j] {
+                panic!("Error code is used by more than one variant!");
+            }
+        }
+    }
+}
+
+/// Compile time interface ids uniqueness assert.
+/// Will panic! in case two interfaces implemented by the contract share the same id.
+/// ```
+///     use sylvia::types::InterfaceId;
+///
+///     const _: () = sylvia::utils::assert_unique_interface_ids(&[
+///         InterfaceId::new("Counter", "query count() -> uint64"),
+///         InterfaceId::new("Admin", "query admin() -> String"),
+///     ]);
+/// ```
+pub const fn assert_unique_interface_ids(interface_ids: &[InterfaceId]) {
+    konst::for_range! {i in 0..interface_ids.len() =>
+        konst::for_range! {j in (i + 1)..interface_ids.len() =>
+            if interface_ids[i].hash() == interface_ids[j].hash() {
+                panic!("Interface id is used by more than one interface!");
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,4 +349,51 @@ mod tests {
 
         assert_no_intersection(msgs);
     }
+
+    #[test]
+    fn reply_id_hash() {
+        assert_eq!(super::reply_id_hash(""), 0xcbf29ce484222325);
+        assert_eq!(super::reply_id_hash("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(
+            super::reply_id_hash("handler_one"),
+            super::reply_id_hash("handler_two")
+        );
+    }
+
+    #[test]
+    fn unique_reply_ids() {
+        super::assert_unique_reply_ids(&[]);
+        super::assert_unique_reply_ids(&[0, 1, 42]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicated_reply_ids() {
+        super::assert_unique_reply_ids(&[0, 42, 1, 42]);
+    }
+
+    #[test]
+    fn interface_id_hash() {
+        assert_eq!(
+            super::interface_id_hash("Counter", "query count() -> uint64"),
+            super::interface_id_hash("Counter", "query count() -> uint64")
+        );
+        assert_ne!(
+            super::interface_id_hash("Counter", "query count() -> uint64"),
+            super::interface_id_hash("Counter", "query count() -> uint32")
+        );
+        assert_ne!(
+            super::interface_id_hash("Counter", ""),
+            super::interface_id_hash("Other", "")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicated_interface_ids() {
+        super::assert_unique_interface_ids(&[
+            InterfaceId::new("Counter", "query count() -> uint64"),
+            InterfaceId::new("Counter", "query count() -> uint64"),
+        ]);
+    }
 }
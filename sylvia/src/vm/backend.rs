@@ -0,0 +1,264 @@
+//! Backend of the virtual machine borrowing the storage, api and querier of the
+//! `cw_multi_test::App` for a single call.
+//!
+//! The `cosmwasm-vm` requires the `'static` backend, so the borrows are kept as raw pointers.
+//! They never leave this module. The bridges can only be created by [with_instance], which
+//! drops the instance using them before returning, so the borrowed values always outlive it.
+
+use cosmwasm_std::{
+    to_json_vec, Api, Binary, Checksum, ContractResult, Order, Querier, Record, Storage,
+    SystemResult,
+};
+use cosmwasm_vm::{
+    Backend, BackendApi, BackendError, BackendResult, Cache, GasInfo, GasReport, Instance,
+    InstanceOptions, VmResult,
+};
+
+use super::{
+    GAS_COST_CANONICALIZE, GAS_COST_HUMANIZE, GAS_COST_LAST_ITERATION, GAS_COST_QUERY_FLAT,
+    GAS_COST_QUERY_RESPONSE_MULTIPLIER, GAS_COST_RANGE,
+};
+
+/// Cache of the compiled contracts executed with the bridged backend.
+pub type BridgeCache = Cache<ApiBridge, StorageBridge, QuerierBridge>;
+
+/// Instance of the contract executed with the bridged backend.
+pub type BridgeInstance = Instance<ApiBridge, StorageBridge, QuerierBridge>;
+
+/// Storage of the contract borrowed for a single call.
+pub enum StorageRef<'a> {
+    /// Storage of the query, which can't be modified.
+    ReadOnly(&'a dyn Storage),
+    /// Storage of the other entry points.
+    Mutable(&'a mut dyn Storage),
+}
+
+/// Creates the instance of the contract backed by the borrowed `storage`, `api` and `querier`
+/// and runs the `action` on it.
+///
+/// The instance is dropped before returning, so the borrows outlive it. Returns the result
+/// of the `action` together with the gas used by it.
+pub fn with_instance<T>(
+    cache: &BridgeCache,
+    checksum: &Checksum,
+    options: InstanceOptions,
+    storage: StorageRef<'_>,
+    api: &dyn Api,
+    querier: &dyn Querier,
+    action: impl FnOnce(&mut BridgeInstance) -> VmResult<ContractResult<T>>,
+) -> VmResult<(VmResult<ContractResult<T>>, GasReport)> {
+    // SAFETY: The backend is used only by the instance created below, which is dropped
+    // before the borrows end.
+    let backend = unsafe {
+        Backend {
+            api: ApiBridge::new(api),
+            storage: StorageBridge::new(storage),
+            querier: QuerierBridge::new(querier),
+        }
+    };
+
+    let mut instance = cache.get_instance(checksum, backend, options)?;
+    let result = action(&mut instance);
+    let report = instance.create_gas_report();
+    drop(instance);
+
+    Ok((result, report))
+}
+
+/// Storage of the contract borrowed by [with_instance].
+pub struct StorageBridge {
+    storage: StoragePtr,
+    iterators: Vec<std::vec::IntoIter<Record>>,
+}
+
+enum StoragePtr {
+    ReadOnly(*const (dyn Storage + 'static)),
+    Mutable(*mut (dyn Storage + 'static)),
+}
+
+impl StorageBridge {
+    /// # Safety
+    ///
+    /// The bridge must be dropped before the borrow of the `storage` ends.
+    unsafe fn new(storage: StorageRef<'_>) -> Self {
+        // Only the lifetimes are erased.
+        let storage = match storage {
+            StorageRef::ReadOnly(storage) => StoragePtr::ReadOnly(std::mem::transmute::<
+                *const dyn Storage,
+                *const (dyn Storage + 'static),
+            >(storage)),
+            StorageRef::Mutable(storage) => StoragePtr::Mutable(std::mem::transmute::<
+                *mut dyn Storage,
+                *mut (dyn Storage + 'static),
+            >(storage)),
+        };
+        Self {
+            storage,
+            iterators: vec![],
+        }
+    }
+
+    fn get_ref(&self) -> &dyn Storage {
+        // SAFETY: The storage outlives the bridge, see [StorageBridge::new].
+        unsafe {
+            match self.storage {
+                StoragePtr::ReadOnly(storage) => &*storage,
+                StoragePtr::Mutable(storage) => &*storage,
+            }
+        }
+    }
+
+    fn get_mut(&mut self) -> Result<&mut dyn Storage, BackendError> {
+        match self.storage {
+            // SAFETY: The storage outlives the bridge, see [StorageBridge::new].
+            StoragePtr::Mutable(storage) => Ok(unsafe { &mut *storage }),
+            StoragePtr::ReadOnly(_) => Err(BackendError::user_err(
+                "Storage is read-only during the query",
+            )),
+        }
+    }
+}
+
+impl cosmwasm_vm::Storage for StorageBridge {
+    fn get(&self, key: &[u8]) -> BackendResult<Option<Vec<u8>>> {
+        let gas_info = GasInfo::with_externally_used(key.len() as u64);
+        (Ok(self.get_ref().get(key)), gas_info)
+    }
+
+    fn scan(
+        &mut self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> BackendResult<u32> {
+        let gas_info = GasInfo::with_externally_used(GAS_COST_RANGE);
+        let records: Vec<_> = self.get_ref().range(start, end, order).collect();
+        self.iterators.push(records.into_iter());
+        (Ok(self.iterators.len() as u32 - 1), gas_info)
+    }
+
+    fn next(&mut self, iterator_id: u32) -> BackendResult<Option<Record>> {
+        let Some(iterator) = self.iterators.get_mut(iterator_id as usize) else {
+            return (
+                Err(BackendError::iterator_does_not_exist(iterator_id)),
+                GasInfo::free(),
+            );
+        };
+
+        match iterator.next() {
+            Some((key, value)) => {
+                let gas_info = GasInfo::with_externally_used((key.len() + value.len()) as u64);
+                (Ok(Some((key, value))), gas_info)
+            }
+            None => (
+                Ok(None),
+                GasInfo::with_externally_used(GAS_COST_LAST_ITERATION),
+            ),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) -> BackendResult<()> {
+        let gas_info = GasInfo::with_externally_used((key.len() + value.len()) as u64);
+        let result = self.get_mut().map(|storage| storage.set(key, value));
+        (result, gas_info)
+    }
+
+    fn remove(&mut self, key: &[u8]) -> BackendResult<()> {
+        let gas_info = GasInfo::with_externally_used(key.len() as u64);
+        let result = self.get_mut().map(|storage| storage.remove(key));
+        (result, gas_info)
+    }
+}
+
+/// Api of the `cw_multi_test::App` borrowed by [with_instance].
+#[derive(Clone, Copy)]
+pub struct ApiBridge(*const (dyn Api + 'static));
+
+// SAFETY: The `BackendApi` has to be `Send`, but the instance using the bridge is created and
+// dropped by [with_instance] on the calling thread, and the virtual machine doesn't move it
+// to any other thread in the meantime.
+unsafe impl Send for ApiBridge {}
+
+impl ApiBridge {
+    /// # Safety
+    ///
+    /// The bridge and all its copies must be dropped before the borrow of the `api` ends.
+    unsafe fn new(api: &dyn Api) -> Self {
+        // Only the lifetime is erased.
+        Self(std::mem::transmute::<
+            *const dyn Api,
+            *const (dyn Api + 'static),
+        >(api))
+    }
+
+    fn api(&self) -> &dyn Api {
+        // SAFETY: The api outlives the bridge, see [ApiBridge::new].
+        unsafe { &*self.0 }
+    }
+}
+
+impl BackendApi for ApiBridge {
+    fn addr_validate(&self, input: &str) -> BackendResult<()> {
+        let result = self
+            .api()
+            .addr_validate(input)
+            .map(|_| ())
+            .map_err(|err| BackendError::user_err(err.to_string()));
+        let gas_info = GasInfo::with_cost(GAS_COST_CANONICALIZE + GAS_COST_HUMANIZE);
+        (result, gas_info)
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> BackendResult<Vec<u8>> {
+        let result = self
+            .api()
+            .addr_canonicalize(human)
+            .map(Vec::from)
+            .map_err(|err| BackendError::user_err(err.to_string()));
+        (result, GasInfo::with_cost(GAS_COST_CANONICALIZE))
+    }
+
+    fn addr_humanize(&self, canonical: &[u8]) -> BackendResult<String> {
+        let result = self
+            .api()
+            .addr_humanize(&canonical.into())
+            .map(String::from)
+            .map_err(|err| BackendError::user_err(err.to_string()));
+        (result, GasInfo::with_cost(GAS_COST_HUMANIZE))
+    }
+}
+
+/// Querier of the `cw_multi_test::App` borrowed by [with_instance].
+pub struct QuerierBridge(*const (dyn Querier + 'static));
+
+impl QuerierBridge {
+    /// # Safety
+    ///
+    /// The bridge must be dropped before the borrow of the `querier` ends.
+    unsafe fn new(querier: &dyn Querier) -> Self {
+        // Only the lifetime is erased.
+        Self(std::mem::transmute::<
+            *const dyn Querier,
+            *const (dyn Querier + 'static),
+        >(querier))
+    }
+}
+
+impl cosmwasm_vm::Querier for QuerierBridge {
+    fn query_raw(
+        &self,
+        request: &[u8],
+        gas_limit: u64,
+    ) -> BackendResult<SystemResult<ContractResult<Binary>>> {
+        // SAFETY: The querier outlives the bridge, see [QuerierBridge::new].
+        let response = unsafe { &*self.0 }.raw_query(request);
+        let response_len = to_json_vec(&response).map_or(0, |response| response.len());
+        let gas_info = GasInfo::with_externally_used(
+            GAS_COST_QUERY_FLAT + GAS_COST_QUERY_RESPONSE_MULTIPLIER * response_len as u64,
+        );
+
+        match gas_info.externally_used > gas_limit {
+            true => (Err(BackendError::out_of_gas()), gas_info),
+            false => (Ok(response), gas_info),
+        }
+    }
+}
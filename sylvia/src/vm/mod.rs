@@ -0,0 +1,319 @@
+//! Execution of the compiled contracts in the MultiTest.
+//!
+//! [WasmContract] runs the contract's `.wasm` through the `cosmwasm-vm`, the same virtual
+//! machine the chain uses, while the rest of the chain is simulated by the `cw_multi_test::App`.
+//! Gas limits, wasm validation and the serialization done on the contract boundary are the same
+//! as on the chain, so the issues hidden by the native execution show up in the tests.
+//!
+//! The `contract` macro generates the `CodeId::store_wasm` function storing the compiled contract,
+//! which is then used with the same proxies as the code stored with `CodeId::store_code`.
+//!
+//! ## Example usage
+//! ```rust,ignore
+//! const WASM: &[u8] = include_bytes!("../artifacts/counter.wasm");
+//!
+//! let app = App::default();
+//! let code_id = match std::env::var("SYLVIA_TEST_WASM") {
+//!     Ok(_) => CodeId::store_wasm(&app, WASM),
+//!     Err(_) => CodeId::store_code(&app),
+//! };
+//!
+//! let contract = code_id.instantiate(0).call(&owner).unwrap();
+//! contract.increment().call(&owner).unwrap();
+//!
+//! for record in code_id.gas_log().records() {
+//!     println!("{} {}: {}", record.contract, record.entry_point, record.gas_used);
+//! }
+//! ```
+//!
+//! Contract errors are returned by the virtual machine as strings, so they are converted into
+//! `StdError::GenericErr`, unless the contract error implements `ErrorCodes` and is decoded with
+//! `ExecProxy::with_error_codes`.
+//!
+//! ## Toolchain
+//!
+//! The `wasmer` used by the `cosmwasm-vm` 2.1 calls the `__rust_probestack` in the compiled code,
+//! which is no longer exported by the recent Rust toolchains. Tests using this module fail to link
+//! on them, so they have to be built with the toolchain exporting the symbol, e.g. the `1.78.0`
+//! used by the CI.
+
+mod backend;
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{
+    Addr, Api, Binary, Checksum, ContractResult, CustomMsg, CustomQuery, Deps, DepsMut, Env,
+    MessageInfo, Querier, Reply, Response, StdError,
+};
+use cosmwasm_vm::{
+    call_execute, call_instantiate, call_migrate, call_query, call_reply, call_sudo,
+    capabilities_from_csv, CacheOptions, InstanceOptions, Size, VmResult,
+};
+use serde::de::DeserializeOwned;
+
+use backend::{with_instance, BridgeCache, BridgeInstance, StorageRef};
+
+/// Number of the CosmWasm gas units in the single Cosmos SDK gas unit, as set by the `wasmd`.
+pub const GAS_MULTIPLIER: u64 = 140_000;
+
+/// Default gas limit of a single call, in the CosmWasm gas units.
+pub const DEFAULT_GAS_LIMIT: u64 = 100_000_000 * GAS_MULTIPLIER;
+
+/// Capabilities available to the executed contracts.
+const CAPABILITIES: &str = "iterator,staking,stargate,cosmwasm_1_1,cosmwasm_1_2,cosmwasm_1_3,cosmwasm_1_4,cosmwasm_2_0,cosmwasm_2_1";
+
+/// Gas costs of the operations metered outside of the virtual machine,
+/// the same as in the `cosmwasm-vm` testing backend.
+const GAS_COST_CANONICALIZE: u64 = 5 * GAS_MULTIPLIER;
+const GAS_COST_HUMANIZE: u64 = 4 * GAS_MULTIPLIER;
+const GAS_COST_RANGE: u64 = 11;
+const GAS_COST_LAST_ITERATION: u64 = 37;
+const GAS_COST_QUERY_FLAT: u64 = 100_000;
+const GAS_COST_QUERY_RESPONSE_MULTIPLIER: u64 = 100;
+
+static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Gas used by a single call of the contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasRecord {
+    /// Address of the called contract.
+    pub contract: Addr,
+    /// Name of the called entry point, e.g. `execute`.
+    pub entry_point: &'static str,
+    /// Used gas in the CosmWasm gas units.
+    pub gas_used: u64,
+}
+
+impl GasRecord {
+    /// Used gas in the Cosmos SDK gas units, without the gas of the transaction itself.
+    pub fn sdk_gas_used(&self) -> u64 {
+        self.gas_used.div_ceil(GAS_MULTIPLIER)
+    }
+}
+
+/// Gas used by the calls of the contracts executed through the [WasmContract].
+///
+/// Clones share the records, so the log returned by the `CodeId` is updated by the calls made
+/// after getting it. Logs of the codes stored natively stay empty.
+#[derive(Clone, Debug, Default)]
+pub struct GasLog(Rc<RefCell<Vec<GasRecord>>>);
+
+impl GasLog {
+    /// Returns the records of all the calls, in the order of execution.
+    pub fn records(&self) -> Vec<GasRecord> {
+        self.0.borrow().clone()
+    }
+
+    /// Returns the record of the last call.
+    pub fn last(&self) -> Option<GasRecord> {
+        self.0.borrow().last().cloned()
+    }
+
+    /// Returns the gas used by all the calls, in the CosmWasm gas units.
+    pub fn total(&self) -> u64 {
+        self.0.borrow().iter().map(|record| record.gas_used).sum()
+    }
+
+    /// Removes all the records.
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear()
+    }
+
+    fn push(&self, record: GasRecord) {
+        self.0.borrow_mut().push(record)
+    }
+}
+
+/// Compiled contract executed by the `cosmwasm-vm` in the `cw_multi_test::App`.
+pub struct WasmContract {
+    cache: BridgeCache,
+    checksum: Checksum,
+    base_dir: PathBuf,
+    gas_limit: u64,
+    gas_log: GasLog,
+}
+
+impl WasmContract {
+    /// Validates and compiles the `wasm` code the same way the chain does when it's stored.
+    pub fn new(wasm: &[u8]) -> VmResult<Self> {
+        let base_dir = std::env::temp_dir().join(format!(
+            "sylvia-vm-{}-{}",
+            std::process::id(),
+            CACHE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let options = CacheOptions::new(
+            &base_dir,
+            capabilities_from_csv(CAPABILITIES),
+            Size::mebi(64),
+            Size::mebi(32),
+        );
+
+        // SAFETY: The cache directory is created for this contract only and removed with it,
+        // so the compiled modules stored there are not shared with anyone else.
+        let cache = unsafe { BridgeCache::new(options) }?;
+        let checksum = match cache.store_code(wasm, true, true) {
+            Ok(checksum) => checksum,
+            Err(err) => {
+                let _ = std::fs::remove_dir_all(&base_dir);
+                return Err(err);
+            }
+        };
+
+        Ok(Self {
+            cache,
+            checksum,
+            base_dir,
+            gas_limit: DEFAULT_GAS_LIMIT,
+            gas_log: GasLog::default(),
+        })
+    }
+
+    /// Sets the gas limit of every call, in the CosmWasm gas units.
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Returns the log of the gas used by the calls of the contract.
+    pub fn gas_log(&self) -> GasLog {
+        self.gas_log.clone()
+    }
+
+    fn call<T>(
+        &self,
+        entry_point: &'static str,
+        env: &Env,
+        storage: StorageRef<'_>,
+        api: &dyn Api,
+        querier: &dyn Querier,
+        action: impl FnOnce(&mut BridgeInstance) -> VmResult<ContractResult<T>>,
+    ) -> AnyResult<T> {
+        let options = InstanceOptions {
+            gas_limit: self.gas_limit,
+        };
+        let (result, report) = with_instance(
+            &self.cache,
+            &self.checksum,
+            options,
+            storage,
+            api,
+            querier,
+            action,
+        )?;
+
+        self.gas_log.push(GasRecord {
+            contract: env.contract.address.clone(),
+            entry_point,
+            gas_used: report.limit - report.remaining,
+        });
+
+        match result? {
+            ContractResult::Ok(value) => Ok(value),
+            ContractResult::Err(err) => Err(StdError::generic_err(err).into()),
+        }
+    }
+}
+
+impl Drop for WasmContract {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.base_dir);
+    }
+}
+
+impl<ExecC, QueryC> cw_multi_test::Contract<ExecC, QueryC> for WasmContract
+where
+    ExecC: CustomMsg + DeserializeOwned,
+    QueryC: CustomQuery,
+{
+    fn execute(
+        &self,
+        deps: DepsMut<QueryC>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> AnyResult<Response<ExecC>> {
+        let storage = StorageRef::Mutable(deps.storage);
+        self.call(
+            "execute",
+            &env,
+            storage,
+            deps.api,
+            &*deps.querier,
+            |instance| call_execute(instance, &env, &info, &msg),
+        )
+    }
+
+    fn instantiate(
+        &self,
+        deps: DepsMut<QueryC>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> AnyResult<Response<ExecC>> {
+        let storage = StorageRef::Mutable(deps.storage);
+        self.call(
+            "instantiate",
+            &env,
+            storage,
+            deps.api,
+            &*deps.querier,
+            |instance| call_instantiate(instance, &env, &info, &msg),
+        )
+    }
+
+    fn query(&self, deps: Deps<QueryC>, env: Env, msg: Vec<u8>) -> AnyResult<Binary> {
+        let storage = StorageRef::ReadOnly(deps.storage);
+        self.call(
+            "query",
+            &env,
+            storage,
+            deps.api,
+            &*deps.querier,
+            |instance| call_query(instance, &env, &msg),
+        )
+    }
+
+    fn sudo(&self, deps: DepsMut<QueryC>, env: Env, msg: Vec<u8>) -> AnyResult<Response<ExecC>> {
+        let storage = StorageRef::Mutable(deps.storage);
+        self.call(
+            "sudo",
+            &env,
+            storage,
+            deps.api,
+            &*deps.querier,
+            |instance| call_sudo(instance, &env, &msg),
+        )
+    }
+
+    fn reply(&self, deps: DepsMut<QueryC>, env: Env, msg: Reply) -> AnyResult<Response<ExecC>> {
+        let storage = StorageRef::Mutable(deps.storage);
+        self.call(
+            "reply",
+            &env,
+            storage,
+            deps.api,
+            &*deps.querier,
+            |instance| call_reply(instance, &env, &msg),
+        )
+    }
+
+    fn migrate(&self, deps: DepsMut<QueryC>, env: Env, msg: Vec<u8>) -> AnyResult<Response<ExecC>> {
+        let storage = StorageRef::Mutable(deps.storage);
+        self.call(
+            "migrate",
+            &env,
+            storage,
+            deps.api,
+            &*deps.querier,
+            |instance| call_migrate(instance, &env, &msg),
+        )
+    }
+
+    fn checksum(&self) -> Option<Checksum> {
+        Some(self.checksum)
+    }
+}
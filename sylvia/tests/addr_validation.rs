@@ -0,0 +1,240 @@
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Addr, Response, StdError, StdResult};
+
+pub mod registry {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Addr, Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Registry {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn register(
+            &self,
+            ctx: ExecCtx,
+            #[sv::addr] members: Vec<Addr>,
+        ) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn is_member(&self, ctx: QueryCtx, #[sv::addr] addr: Addr) -> Result<bool, Self::Error>;
+    }
+}
+
+pub fn not_frozen<Contract>(_ctx: &ExecCtx, _contract: &Contract) -> StdResult<()> {
+    Ok(())
+}
+
+pub struct Contract {
+    admin: Item<Addr>,
+    members: Item<Vec<Addr>>,
+}
+
+#[sylvia::contract]
+#[sv::messages(registry as Registry)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            admin: Item::new("admin"),
+            members: Item::new("members"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, #[sv::addr] admin: Addr) -> StdResult<Response> {
+        self.admin.save(ctx.deps.storage, &admin)?;
+        self.members.save(ctx.deps.storage, &vec![])?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::guard(not_frozen)]
+    fn set_admin(&self, ctx: ExecCtx, #[sv::addr] admin: Addr) -> StdResult<Response> {
+        self.admin.save(ctx.deps.storage, &admin)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn is_admin(&self, ctx: QueryCtx, #[sv::addr] addr: Option<Addr>) -> StdResult<bool> {
+        let admin = self.admin.load(ctx.deps.storage)?;
+        Ok(addr.is_some_and(|addr| addr == admin))
+    }
+}
+
+impl registry::Registry for Contract {
+    type Error = StdError;
+
+    fn register(&self, ctx: ExecCtx, members: Vec<Addr>) -> StdResult<Response> {
+        self.members.save(ctx.deps.storage, &members)?;
+        Ok(Response::new())
+    }
+
+    fn is_member(&self, ctx: QueryCtx, addr: Addr) -> StdResult<bool> {
+        Ok(self.members.load(ctx.deps.storage)?.contains(&addr))
+    }
+}
+
+pub mod typed {
+    use sylvia::ctx::{ExecCtx, InstantiateCtx};
+    use sylvia::cw_std::{Addr, Response, StdError};
+    use sylvia::types::InvalidAddr;
+
+    #[derive(thiserror::Error, Debug, PartialEq)]
+    pub enum ContractError {
+        #[error(transparent)]
+        Std(#[from] StdError),
+        #[error(transparent)]
+        InvalidAddr(#[from] InvalidAddr),
+    }
+
+    pub struct TypedContract;
+
+    #[sylvia::contract]
+    #[sv::error(ContractError)]
+    impl TypedContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> Result<Response, ContractError> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn set_admin(
+            &self,
+            _ctx: ExecCtx,
+            #[sv::addr] _admin: Addr,
+        ) -> Result<Response, ContractError> {
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn messages_carry_strings() {
+    let _ = sv::InstantiateMsg::new("admin".to_owned());
+    let _ = sv::ExecMsg::set_admin("admin".to_owned());
+    let _ = sv::QueryMsg::is_admin(None);
+    let _ = registry::sv::ExecMsg::register(vec!["member".to_owned()]);
+    let _ = registry::sv::QueryMsg::is_member("member".to_owned());
+}
+
+#[cfg(all(test, feature = "mt"))]
+mod tests {
+    use cw_multi_test::IntoBech32;
+    use sylvia::cw_std::testing::MockApi;
+    use sylvia::cw_std::{Api, StdError};
+    use sylvia::multitest::App;
+    use sylvia::types::{InvalidAddr, ValidateAddr};
+
+    use crate::registry::sv::mt::RegistryProxy;
+    use crate::sv::mt::{CodeId, ContractProxy};
+
+    #[test]
+    fn addresses_are_validated() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+
+        let owner = "owner".into_bech32();
+        let admin = "admin".into_bech32();
+        let member = "member".into_bech32();
+        let reason = MockApi::default()
+            .addr_validate("invalid")
+            .unwrap_err()
+            .to_string();
+        let invalid_err = |field| {
+            StdError::from(InvalidAddr {
+                field,
+                addr: "invalid".to_owned(),
+                reason: reason.clone(),
+            })
+        };
+
+        let err = code_id
+            .instantiate("invalid".to_owned())
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(err, invalid_err("admin"));
+
+        let contract = code_id.instantiate(admin.to_string()).call(&owner).unwrap();
+        assert!(contract.is_admin(Some(admin.to_string())).unwrap());
+        assert!(!contract.is_admin(None).unwrap());
+
+        let err = contract.is_admin(Some("invalid".to_owned())).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err(format!("Querier contract error: {}", invalid_err("addr")))
+        );
+
+        let err = contract
+            .set_admin("invalid".to_owned())
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(err, invalid_err("admin"));
+        contract.set_admin(owner.to_string()).call(&owner).unwrap();
+        assert!(contract.is_admin(Some(owner.to_string())).unwrap());
+
+        let err = contract
+            .register(vec![member.to_string(), "invalid".to_owned()])
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(err, invalid_err("members"));
+        contract
+            .register(vec![member.to_string()])
+            .call(&owner)
+            .unwrap();
+        assert!(contract.is_member(member.to_string()).unwrap());
+        assert!(!contract.is_member(owner.to_string()).unwrap());
+    }
+
+    #[test]
+    fn invalid_addr_is_converted_into_contract_error() {
+        use crate::typed::sv::mt::{CodeId, TypedContractProxy};
+        use crate::typed::ContractError;
+
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+
+        let owner = "owner".into_bech32();
+        let contract = code_id.instantiate().call(&owner).unwrap();
+
+        let err = contract
+            .set_admin("invalid".to_owned())
+            .call(&owner)
+            .unwrap_err();
+        let ContractError::InvalidAddr(err) = err else {
+            panic!("Expected InvalidAddr, got {err:?}");
+        };
+        assert_eq!(err.field, "_admin");
+        assert_eq!(err.addr, "invalid");
+    }
+
+    #[test]
+    fn invalid_addr_names_the_field() {
+        let api = MockApi::default();
+        let valid = "member".into_bech32().to_string();
+
+        let err = vec![valid.clone(), "invalid".to_owned()]
+            .validate("members", &api)
+            .unwrap_err();
+        assert_eq!(err.field, "members");
+        assert_eq!(err.addr, "invalid");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Invalid address `invalid` in the `members` field: {}",
+                err.reason
+            )
+        );
+
+        assert_eq!(
+            Some(valid.clone()).validate("admin", &api).unwrap(),
+            Some(api.addr_validate(&valid).unwrap())
+        );
+    }
+}
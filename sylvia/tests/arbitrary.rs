@@ -0,0 +1,421 @@
+#![cfg(all(feature = "arbitrary", feature = "mt"))]
+
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{ensure, Addr, Response, StdError, StdResult, Uint128};
+use sylvia::{contract, entry_points};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Config {
+    pub paused: bool,
+}
+
+// Messages implement `Arbitrary` only if all of their fields do
+impl<'a> arbitrary::Arbitrary<'a> for Config {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            paused: u.arbitrary()?,
+        })
+    }
+}
+
+pub mod burnable {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError, Uint128};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Burnable {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn burn(&self, ctx: ExecCtx, amount: Uint128) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Token {
+    balances: Map<Addr, Uint128>,
+    supply: Item<Uint128>,
+    leaky: Item<bool>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::messages(burnable)]
+impl Token {
+    pub const fn new() -> Self {
+        Self {
+            balances: Map::new("balances"),
+            supply: Item::new("supply"),
+            leaky: Item::new("leaky"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, leaky: bool) -> StdResult<Response> {
+        self.supply.save(ctx.deps.storage, &Uint128::zero())?;
+        self.leaky.save(ctx.deps.storage, &leaky)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn mint(&self, ctx: ExecCtx, recipient: Addr, amount: Uint128) -> StdResult<Response> {
+        self.balances
+            .update(ctx.deps.storage, recipient, |balance| {
+                balance
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .map_err(StdError::from)
+            })?;
+        self.supply.update(ctx.deps.storage, |supply| {
+            supply.checked_add(amount).map_err(StdError::from)
+        })?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn transfer(
+        &self,
+        ctx: ExecCtx,
+        recipient: Addr,
+        amount: Uint128,
+        memo: Option<String>,
+    ) -> StdResult<Response> {
+        self.balances
+            .update(ctx.deps.storage, ctx.info.sender, |balance| {
+                balance
+                    .unwrap_or_default()
+                    .checked_sub(amount)
+                    .map_err(StdError::from)
+            })?;
+        self.balances
+            .update(ctx.deps.storage, recipient, |balance| {
+                balance
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .map_err(StdError::from)
+            })?;
+        let resp = Response::new();
+        Ok(match memo {
+            Some(memo) => resp.add_attribute("memo", memo),
+            None => resp,
+        })
+    }
+
+    #[sv::msg(exec)]
+    fn configure(&self, _ctx: ExecCtx, config: Config) -> StdResult<Response> {
+        ensure!(
+            !config.paused,
+            StdError::generic_err("Pausing not supported")
+        );
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn balance(&self, ctx: QueryCtx, account: Addr) -> StdResult<Uint128> {
+        self.balances
+            .may_load(ctx.deps.storage, account)
+            .map(Option::unwrap_or_default)
+    }
+
+    #[sv::msg(query)]
+    fn total_supply(&self, ctx: QueryCtx) -> StdResult<Uint128> {
+        self.supply.load(ctx.deps.storage)
+    }
+}
+
+impl burnable::Burnable for Token {
+    type Error = StdError;
+
+    fn burn(&self, ctx: ExecCtx, amount: Uint128) -> StdResult<Response> {
+        self.balances
+            .update(ctx.deps.storage, ctx.info.sender, |balance| {
+                balance
+                    .unwrap_or_default()
+                    .checked_sub(amount)
+                    .map_err(StdError::from)
+            })?;
+        if !self.leaky.load(ctx.deps.storage)? {
+            self.supply.update(ctx.deps.storage, |supply| {
+                supply.checked_sub(amount).map_err(StdError::from)
+            })?;
+        }
+        Ok(Response::new())
+    }
+}
+
+pub mod vault {
+    use cw_storage_plus::{Item, Map};
+    use sylvia::contract;
+    use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::cw_std::{coins, Addr, BankMsg, Response, StdError, StdResult, Uint128};
+
+    pub const DENOM: &str = "uatom";
+
+    pub struct Vault {
+        deposits: Map<Addr, Uint128>,
+        total: Item<Uint128>,
+    }
+
+    #[contract]
+    impl Vault {
+        pub const fn new() -> Self {
+            Self {
+                deposits: Map::new("deposits"),
+                total: Item::new("total"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+            self.total.save(ctx.deps.storage, &Uint128::zero())?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        #[sv::funds(must_pay(DENOM))]
+        fn deposit(&self, ctx: ExecCtx, #[sv::addr] beneficiary: Addr) -> StdResult<Response> {
+            let amount = cw_utils::must_pay(&ctx.info, DENOM)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            self.deposits
+                .update(ctx.deps.storage, beneficiary, |deposit| {
+                    deposit
+                        .unwrap_or_default()
+                        .checked_add(amount)
+                        .map_err(StdError::from)
+                })?;
+            self.total.update(ctx.deps.storage, |total| {
+                total.checked_add(amount).map_err(StdError::from)
+            })?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        #[sv::funds(nonpayable)]
+        fn withdraw(&self, ctx: ExecCtx, amount: Uint128) -> StdResult<Response> {
+            self.deposits
+                .update(ctx.deps.storage, ctx.info.sender.clone(), |deposit| {
+                    deposit
+                        .unwrap_or_default()
+                        .checked_sub(amount)
+                        .map_err(StdError::from)
+                })?;
+            self.total.update(ctx.deps.storage, |total| {
+                total.checked_sub(amount).map_err(StdError::from)
+            })?;
+            Ok(Response::new().add_message(BankMsg::Send {
+                to_address: ctx.info.sender.to_string(),
+                amount: coins(amount.u128(), DENOM),
+            }))
+        }
+
+        #[sv::msg(query)]
+        fn total(&self, ctx: QueryCtx) -> StdResult<Uint128> {
+            self.total.load(ctx.deps.storage)
+        }
+    }
+}
+
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use cw_multi_test::IntoBech32;
+    use serde::Serialize;
+    use sylvia::cw_std::{coin, ensure_eq, StdError, StdResult, Uint128};
+    use sylvia::fuzz::{
+        account, arbitrary_funds, ExecFunds, ExecSequence, ACCOUNTS, DENOMS, MAX_FUNDS,
+    };
+    use sylvia::multitest::App;
+    use sylvia::types::{BoundQuerier, FundsPolicy};
+
+    use crate::sv::mt::CodeId;
+    use crate::sv::{ContractExecMsg, ExecMsg, InstantiateMsg, Querier};
+    use crate::vault::sv::mt::{CodeId as VaultCodeId, VaultProxy};
+    use crate::vault::sv::{
+        ContractExecMsg as VaultContractExecMsg, ExecMsg as VaultExecMsg, Querier as _,
+    };
+    use crate::{vault, Token};
+
+    fn supply_matches_balances(
+        querier: &BoundQuerier<'_, sylvia::cw_std::Empty, Token>,
+    ) -> StdResult<()> {
+        let supply = querier.total_supply()?;
+        let balances = (0..ACCOUNTS)
+            .map(|idx| querier.balance(account(idx)))
+            .sum::<StdResult<Uint128>>()?;
+        ensure_eq!(
+            supply,
+            balances,
+            StdError::generic_err("Supply doesn't match the balances")
+        );
+        Ok(())
+    }
+
+    fn fund_accounts(app: &App<cw_multi_test::BasicApp>) {
+        app.app_mut().init_modules(|router, _, storage| {
+            for idx in 0..ACCOUNTS {
+                let funds = DENOMS
+                    .iter()
+                    .map(|denom| coin(100 * MAX_FUNDS, *denom))
+                    .collect();
+                router
+                    .bank
+                    .init_balance(storage, &account(idx), funds)
+                    .unwrap();
+            }
+        });
+    }
+
+    #[test]
+    fn generated_messages() {
+        let accounts: Vec<_> = (0..ACCOUNTS).map(account).collect();
+        let bytes: Vec<u8> = (0..4096u32).map(|idx| (idx * 7919 % 251) as u8).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        let mut configures = 0;
+        while !u.is_empty() {
+            let Ok(msg) = ExecMsg::arbitrary(&mut u) else {
+                break;
+            };
+            match msg {
+                ExecMsg::Mint { recipient, .. } | ExecMsg::Transfer { recipient, .. } => {
+                    assert!(accounts.contains(&recipient))
+                }
+                ExecMsg::Configure { .. } => configures += 1,
+            }
+        }
+        assert!(configures > 0);
+
+        let mut u = Unstructured::new(&bytes);
+        InstantiateMsg::arbitrary(&mut u).unwrap();
+        ContractExecMsg::arbitrary(&mut u).unwrap();
+    }
+
+    #[test]
+    fn supply_invariant() {
+        let app = App::<cw_multi_test::BasicApp>::default();
+        let owner = "owner".into_bech32();
+        fund_accounts(&app);
+
+        let contract = CodeId::store_code(&app)
+            .instantiate(false)
+            .call(&owner)
+            .unwrap();
+
+        for seed in 0..4 {
+            let report = ExecSequence::new(&contract)
+                .with_seed(seed)
+                .with_steps(50)
+                .run::<ContractExecMsg>(supply_matches_balances);
+            assert!(report.executed > 0);
+            assert!(report.failed > 0);
+        }
+    }
+
+    #[test]
+    fn addr_fields_and_funds() {
+        let accounts: Vec<_> = (0..ACCOUNTS).map(|idx| account(idx).to_string()).collect();
+        let bytes: Vec<u8> = (0..4096u32).map(|idx| (idx * 7919 % 251) as u8).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        let mut deposits = 0;
+        for _ in 0..32 {
+            let msg = VaultExecMsg::arbitrary(&mut u).unwrap();
+            let funds = arbitrary_funds(msg.funds_policy(), &mut u).unwrap();
+            match msg {
+                VaultExecMsg::Deposit { beneficiary } => {
+                    deposits += 1;
+                    assert!(accounts.contains(&beneficiary));
+                    assert_eq!(funds.len(), 1);
+                    assert_eq!(funds[0].denom, vault::DENOM);
+                    assert!(!funds[0].amount.is_zero());
+                    assert!(funds[0].amount.u128() <= MAX_FUNDS);
+                }
+                VaultExecMsg::Withdraw { .. } => assert!(funds.is_empty()),
+            }
+        }
+        assert!(deposits > 0);
+    }
+
+    #[test]
+    fn funds_invariant() {
+        let app = App::<cw_multi_test::BasicApp>::default();
+        let owner = "owner".into_bech32();
+        fund_accounts(&app);
+
+        let contract = VaultCodeId::store_code(&app)
+            .instantiate()
+            .call(&owner)
+            .unwrap();
+
+        let report = ExecSequence::new(&contract)
+            .with_steps(50)
+            .run::<VaultContractExecMsg>(|querier| {
+                let total = querier.total()?;
+                let balance = app
+                    .querier()
+                    .query_balance(&contract.contract_addr, vault::DENOM)?;
+                ensure_eq!(
+                    total,
+                    balance.amount,
+                    StdError::generic_err("Total doesn't match the balance")
+                );
+                Ok(())
+            });
+        assert!(report.executed > 0);
+        assert!(!contract.total().unwrap().is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invariant violated")]
+    fn broken_invariant() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+
+        let contract = CodeId::store_code(&app)
+            .instantiate(true)
+            .call(&owner)
+            .unwrap();
+
+        ExecSequence::new(&contract)
+            .with_steps(500)
+            .run::<ContractExecMsg>(supply_matches_balances);
+    }
+
+    /// Message which can't be generated from any bytes.
+    #[derive(Serialize, Debug)]
+    struct Unsupported;
+
+    impl<'a> Arbitrary<'a> for Unsupported {
+        fn arbitrary(_u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+            Err(arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    impl ExecFunds for Unsupported {
+        fn funds_policy(&self) -> FundsPolicy {
+            FundsPolicy::Nonpayable
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "None of the 10 messages of the sequence with seed 3 could be generated"
+    )]
+    fn nothing_generated() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+
+        let contract = CodeId::store_code(&app)
+            .instantiate(false)
+            .call(&owner)
+            .unwrap();
+
+        ExecSequence::new(&contract)
+            .with_seed(3)
+            .with_steps(10)
+            .run::<Unsupported>(supply_matches_balances);
+    }
+}
@@ -0,0 +1,231 @@
+#![cfg(feature = "mt")]
+
+use cw_storage_plus::Item;
+use sylvia::contract;
+use sylvia::ctx::{InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Addr, Response, StdResult};
+use sylvia::types::Remote;
+
+use crate::counter::sv::Querier;
+
+pub mod counter {
+    use cw_storage_plus::Item;
+    use sylvia::contract;
+    use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError, StdResult};
+
+    pub struct Counter {
+        count: Item<u64>,
+    }
+
+    #[contract]
+    #[sv::features(supported_interfaces)]
+    impl Counter {
+        pub const fn new() -> Self {
+            Self {
+                count: Item::new("count"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+            self.count
+                .update(ctx.deps.storage, |count| Ok::<_, StdError>(count + 1))?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+
+        #[sv::msg(query)]
+        fn fail(&self, _ctx: QueryCtx) -> StdResult<u64> {
+            Err(StdError::generic_err("Query failed"))
+        }
+    }
+}
+
+pub struct Aggregator {
+    remotes: Item<(
+        Remote<'static, counter::Counter>,
+        Remote<'static, counter::Counter>,
+    )>,
+}
+
+#[contract]
+impl Aggregator {
+    pub const fn new() -> Self {
+        Self {
+            remotes: Item::new("remotes"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, first: Addr, second: Addr) -> StdResult<Response> {
+        self.remotes
+            .save(ctx.deps.storage, &(Remote::new(first), Remote::new(second)))?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn total(&self, ctx: QueryCtx) -> StdResult<u64> {
+        let (first, second) = self.remotes.load(ctx.deps.storage)?;
+        let (first, second, failed) = first
+            .querier(&ctx.deps.querier)
+            .batch()
+            .memoized()
+            .query(|querier| first.querier(querier).count())
+            .query(|querier| second.querier(querier).count())
+            .query(|querier| second.querier(querier).fail())
+            .execute();
+
+        // Failure of a single query doesn't affect the others
+        Ok(first? + second? + failed.unwrap_or_default())
+    }
+}
+
+mod tests {
+    use std::cell::Cell;
+
+    use cw_multi_test::IntoBech32;
+    use sylvia::batch::{QueryBatch, QueryCache};
+    use sylvia::cw_std::{Empty, Querier, QuerierResult, QuerierWrapper, StdError};
+    use sylvia::multitest::App;
+    use sylvia::types::BoundQuerier;
+
+    use crate::counter::sv::mt::{CodeId as CounterCodeId, CounterProxy};
+    use crate::counter::sv::Querier as _;
+    use crate::counter::Counter;
+    use crate::sv::mt::{AggregatorProxy, CodeId as AggregatorCodeId};
+
+    /// Querier counting the queries sent to the contracts.
+    struct CountingQuerier<'a> {
+        querier: &'a dyn Querier,
+        queries: Cell<usize>,
+    }
+
+    impl Querier for CountingQuerier<'_> {
+        fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+            self.queries.set(self.queries.get() + 1);
+            self.querier.raw_query(bin_request)
+        }
+    }
+
+    #[test]
+    fn batch_returns_typed_results() {
+        let app = App::default();
+        let code_id = CounterCodeId::store_code(&app);
+        let owner = "owner".into_bech32();
+
+        let first = code_id.instantiate(1).call(&owner).unwrap();
+        let second = code_id.instantiate(2).call(&owner).unwrap();
+
+        let querier = app.querier();
+        let (first_count, second_count, failed, interfaces) = QueryBatch::new(&querier)
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&first.contract_addr, querier).count()
+            })
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&second.contract_addr, querier).count()
+            })
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&second.contract_addr, querier).fail()
+            })
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&first.contract_addr, querier)
+                    .supported_interfaces()
+                    .map(|interfaces| interfaces.len())
+            })
+            .execute();
+
+        assert_eq!(first_count, Ok(1));
+        assert_eq!(second_count, Ok(2));
+        assert!(
+            matches!(failed, Err(StdError::GenericErr { msg, .. }) if msg.contains("Query failed"))
+        );
+        assert_eq!(interfaces, Ok(0));
+
+        let aggregator = AggregatorCodeId::store_code(&app)
+            .instantiate(first.contract_addr.clone(), second.contract_addr.clone())
+            .call(&owner)
+            .unwrap();
+        assert_eq!(aggregator.total().unwrap(), 3);
+    }
+
+    #[test]
+    fn identical_queries_are_memoized() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+        let counter = CounterCodeId::store_code(&app)
+            .instantiate(5)
+            .call(&owner)
+            .unwrap();
+
+        let app_querier = app.querier();
+        let counting = CountingQuerier {
+            querier: &*app_querier,
+            queries: Cell::new(0),
+        };
+        let querier = QuerierWrapper::<Empty>::new(&counting);
+        let bound = BoundQuerier::<_, Counter>::borrowed(&counter.contract_addr, &querier);
+
+        let (first, second) = bound
+            .batch()
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&counter.contract_addr, querier).count()
+            })
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&counter.contract_addr, querier).count()
+            })
+            .execute();
+        assert_eq!((first, second), (Ok(5), Ok(5)));
+        assert_eq!(counting.queries.get(), 2);
+
+        let (first, second) = bound
+            .batch()
+            .memoized()
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&counter.contract_addr, querier).count()
+            })
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&counter.contract_addr, querier).count()
+            })
+            .execute();
+        assert_eq!((first, second), (Ok(5), Ok(5)));
+        assert_eq!(counting.queries.get(), 3);
+
+        // Cache is shared by the batches
+        let cache = QueryCache::default();
+        for _ in 0..2 {
+            let (count,) = bound
+                .batch()
+                .with_cache(&cache)
+                .query(|querier| {
+                    BoundQuerier::<_, Counter>::borrowed(&counter.contract_addr, querier).count()
+                })
+                .execute();
+            assert_eq!(count, Ok(5));
+        }
+        assert_eq!(counting.queries.get(), 4);
+        assert_eq!(cache.len(), 1);
+
+        // Cached response doesn't see the state changed after it was cached
+        counter.increment().call(&owner).unwrap();
+        let (cached, fresh) = QueryBatch::new(&querier)
+            .with_cache(&cache)
+            .query(|querier| {
+                BoundQuerier::<_, Counter>::borrowed(&counter.contract_addr, querier).count()
+            })
+            .query(|_| bound.count())
+            .execute();
+        assert_eq!((cached, fresh), (Ok(5), Ok(6)));
+        assert_eq!(counter.count().unwrap(), 6);
+    }
+}
@@ -0,0 +1,269 @@
+use cw_storage_plus::Item;
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx, ReplyCtx};
+use sylvia::cw_std::{Addr, Binary, Response, StdError, StdResult, Uint128};
+use sylvia::errors::{CodedError, DecodeError, EncodedError, ErrorCodes};
+use sylvia::types::Remote;
+
+use crate::callee::sv::Executor;
+use crate::sv::SubMsgMethods;
+
+#[sylvia::error_codes]
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    #[sv::code(1)]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized {sender}")]
+    #[sv::code(2)]
+    Unauthorized { sender: String },
+
+    #[error("Insufficient funds, needed {0}, got {1}")]
+    InsufficientFunds(Uint128, Uint128),
+
+    #[error("Contract is frozen")]
+    Frozen,
+}
+
+pub mod callee {
+    use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdResult, Uint128};
+    use sylvia::{contract, entry_points};
+
+    use crate::ContractError;
+
+    pub struct Callee;
+
+    #[entry_points]
+    #[contract]
+    #[sv::error(ContractError, codes)]
+    impl Callee {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn withdraw(&self, _ctx: ExecCtx, amount: Uint128) -> Result<Response, ContractError> {
+            Err(ContractError::InsufficientFunds(amount, Uint128::zero()))
+        }
+
+        #[sv::msg(exec)]
+        fn freeze(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+            Err(ContractError::Frozen)
+        }
+
+        #[sv::msg(query)]
+        fn admin(&self, _ctx: QueryCtx, sender: String) -> Result<String, ContractError> {
+            Err(ContractError::Unauthorized { sender })
+        }
+    }
+}
+
+pub struct Caller {
+    remote: Item<Remote<'static, callee::Callee>>,
+    last_error: Item<u32>,
+}
+
+#[contract]
+#[sv::error(ContractError, codes)]
+#[sv::features(replies)]
+impl Caller {
+    pub const fn new() -> Self {
+        Self {
+            remote: Item::new("remote"),
+            last_error: Item::new("last_error"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, remote: Addr) -> StdResult<Response> {
+        self.remote.save(ctx.deps.storage, &Remote::new(remote))?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn withdraw(&self, ctx: ExecCtx, amount: Uint128) -> Result<Response, ContractError> {
+        let msg = self
+            .remote
+            .load(ctx.deps.storage)?
+            .executor()
+            .withdraw(amount)?
+            .build();
+        Ok(Response::new().add_message(msg))
+    }
+
+    #[sv::msg(exec)]
+    fn try_withdraw(&self, ctx: ExecCtx, amount: Uint128) -> Result<Response, ContractError> {
+        let submsg = self
+            .remote
+            .load(ctx.deps.storage)?
+            .executor()
+            .withdraw(amount)?
+            .build()
+            .failed(Binary::default())?;
+        Ok(Response::new().add_submessage(submsg))
+    }
+
+    #[sv::msg(query)]
+    fn last_error(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.last_error.load(ctx.deps.storage)
+    }
+
+    #[sv::msg(reply, reply_on=error)]
+    fn failed(
+        &self,
+        ctx: ReplyCtx,
+        error: String,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> Result<Response, ContractError> {
+        let code = error
+            .decode_error::<ContractError>()
+            .ok_or_else(|| StdError::generic_err("Unknown error"))?
+            .code();
+        self.last_error.save(ctx.deps.storage, &code)?;
+        Ok(Response::new())
+    }
+}
+
+#[test]
+fn codes() {
+    let codes = ContractError::CODES;
+    assert_eq!(codes[0], ("Std", 1));
+    assert_eq!(codes[1], ("Unauthorized", 2));
+    assert_eq!(codes[2].0, "InsufficientFunds");
+    assert_eq!(codes[3].0, "Frozen");
+    assert_ne!(codes[2].1, codes[3].1);
+
+    let err = ContractError::Unauthorized {
+        sender: "owner".to_owned(),
+    };
+    assert_eq!(err.code(), 2);
+    assert_eq!(ContractError::Frozen.code(), codes[3].1);
+}
+
+#[test]
+fn encoding() {
+    let errors = [
+        ContractError::Std(StdError::generic_err("Storage corrupted")),
+        ContractError::Unauthorized {
+            sender: "owner".to_owned(),
+        },
+        ContractError::InsufficientFunds(Uint128::new(100), Uint128::new(10)),
+        ContractError::Frozen,
+    ];
+
+    for err in errors {
+        let encoded = CodedError::new(err).to_string();
+        let decoded = encoded.decode_error::<ContractError>().unwrap();
+        assert_eq!(encoded, CodedError::new(decoded).to_string());
+    }
+
+    let encoded = CodedError::new(ContractError::Frozen).to_string();
+    let parsed = EncodedError::parse(&format!("Forwarded: {encoded}")).unwrap();
+    assert_eq!(parsed.name, "ContractError");
+    assert_eq!(parsed.message, "Contract is frozen");
+    assert_eq!(parsed.data, None);
+
+    // Plain error messages and messages of other error types are not decoded
+    assert_eq!("Contract is frozen".decode_error::<ContractError>(), None);
+    let other = encoded.replace("ContractError", "OtherError");
+    assert_eq!(other.decode_error::<ContractError>(), None);
+}
+
+#[cfg(all(test, feature = "mt"))]
+mod tests {
+    use cw_multi_test::IntoBech32;
+    use sylvia::cw_std::Uint128;
+    use sylvia::errors::{DecodeError, ErrorCodes};
+    use sylvia::multitest::App;
+    use sylvia::types::BoundQuerier;
+
+    use crate::callee::sv::mt::{CalleeProxy, CodeId as CalleeCodeId};
+    use crate::callee::sv::Querier;
+    use crate::sv::mt::{CallerProxy, CodeId as CallerCodeId};
+    use crate::ContractError;
+
+    #[test]
+    fn exec_errors_are_typed() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+
+        let callee = CalleeCodeId::store_code(&app)
+            .instantiate()
+            .call(&owner)
+            .unwrap();
+
+        let err = callee.freeze().call(&owner).unwrap_err();
+        assert_eq!(err, ContractError::Frozen);
+
+        let caller = CallerCodeId::store_code(&app)
+            .instantiate(callee.contract_addr.clone())
+            .call(&owner)
+            .unwrap();
+
+        // Error forwarded from the callee is decoded as the contract error
+        let err = caller.withdraw(Uint128::new(5)).call(&owner).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientFunds(Uint128::new(5), Uint128::zero())
+        );
+    }
+
+    #[test]
+    fn query_errors_are_decoded() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+
+        let callee = CalleeCodeId::store_code(&app)
+            .instantiate()
+            .call(&owner)
+            .unwrap();
+
+        let expected = ContractError::Unauthorized {
+            sender: "alice".to_owned(),
+        };
+
+        // Queries fail with the `StdError` carrying the encoded error
+        let Err(ContractError::Std(err)) = callee.admin("alice".to_owned()) else {
+            panic!("Expected the query to fail with the `StdError`");
+        };
+        assert_eq!(err.decode_error::<ContractError>(), Some(expected));
+
+        let querier = app.querier();
+        let querier =
+            BoundQuerier::<_, crate::callee::Callee>::borrowed(&callee.contract_addr, &querier);
+        let err = querier.admin("bob".to_owned()).unwrap_err();
+        assert_eq!(
+            err.decode_error::<ContractError>(),
+            Some(ContractError::Unauthorized {
+                sender: "bob".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn reply_errors_are_decoded() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+
+        let callee = CalleeCodeId::store_code(&app)
+            .instantiate()
+            .call(&owner)
+            .unwrap();
+        let caller = CallerCodeId::store_code(&app)
+            .instantiate(callee.contract_addr.clone())
+            .call(&owner)
+            .unwrap();
+
+        caller.try_withdraw(Uint128::new(5)).call(&owner).unwrap();
+
+        let expected = ContractError::InsufficientFunds(Uint128::zero(), Uint128::zero());
+        assert_eq!(caller.last_error().unwrap(), expected.code());
+    }
+}
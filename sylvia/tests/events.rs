@@ -0,0 +1,208 @@
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_std::{Addr, Event, Response, StdError, StdResult, Uint128};
+use sylvia::events::{AddTypedEvent, TypedEvent};
+
+#[sylvia::event(name = "transfer")]
+#[derive(Debug, PartialEq)]
+pub struct Transfer {
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub memo: Option<String>,
+}
+
+#[sylvia::event]
+#[derive(Debug, PartialEq)]
+pub struct TokenMinted {
+    pub r#type: String,
+    pub amount: u64,
+}
+
+#[sylvia::event]
+#[derive(Debug, PartialEq)]
+pub struct Frozen;
+
+pub mod token {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Token {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        #[sv::events(crate::Transfer)]
+        fn transfer(
+            &self,
+            ctx: ExecCtx,
+            recipient: String,
+            amount: u64,
+            memo: Option<String>,
+        ) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[sylvia::contract]
+#[sv::messages(token as Token)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    #[sv::events(TokenMinted)]
+    fn instantiate(&self, _ctx: InstantiateCtx, amount: u64) -> StdResult<Response> {
+        let minted = TokenMinted {
+            r#type: "initial".to_owned(),
+            amount,
+        };
+        Ok(Response::new().add_typed_event(minted))
+    }
+
+    #[sv::msg(exec)]
+    #[sv::events(TokenMinted, Frozen)]
+    fn mint(&self, _ctx: ExecCtx, amount: u64) -> StdResult<Response> {
+        let minted = TokenMinted {
+            r#type: "mint".to_owned(),
+            amount,
+        };
+        Ok(Response::new()
+            .add_typed_event(minted)
+            .add_event(Frozen)
+            .add_event(Event::new("untyped")))
+    }
+}
+
+impl token::Token for Contract {
+    type Error = StdError;
+
+    fn transfer(
+        &self,
+        _ctx: ExecCtx,
+        recipient: String,
+        amount: u64,
+        memo: Option<String>,
+    ) -> StdResult<Response> {
+        let transfer = Transfer {
+            recipient: Addr::unchecked(recipient),
+            amount: Uint128::from(amount),
+            memo,
+        };
+        Ok(Response::new().add_typed_event(transfer))
+    }
+}
+
+#[test]
+fn event_conversion() {
+    assert_eq!(Transfer::EVENT_TYPE, "transfer");
+    assert_eq!(TokenMinted::EVENT_TYPE, "token_minted");
+    assert_eq!(Frozen::EVENT_TYPE, "frozen");
+
+    let transfer = Transfer {
+        recipient: Addr::unchecked("recipient"),
+        amount: Uint128::new(100),
+        memo: Some("memo".to_owned()),
+    };
+    let event: Event = transfer.into();
+    assert_eq!(
+        event,
+        Event::new("transfer")
+            .add_attribute("recipient", "recipient")
+            .add_attribute("amount", "100")
+            .add_attribute("memo", "memo")
+    );
+
+    let minted = TokenMinted {
+        r#type: "mint".to_owned(),
+        amount: 5,
+    };
+    let event = minted.into_event();
+    assert_eq!(event.attributes[0].key, "type");
+
+    // Events emitted by the contract are prefixed by the chain
+    let event = Event::new("wasm-token_minted")
+        .add_attribute("_contract_address", "contract")
+        .add_attribute("type", "mint")
+        .add_attribute("amount", "5");
+    assert!(TokenMinted::matches(&event));
+    assert!(!Transfer::matches(&event));
+    assert_eq!(
+        TokenMinted::from_event(&event).unwrap(),
+        TokenMinted {
+            r#type: "mint".to_owned(),
+            amount: 5,
+        }
+    );
+
+    let err = Transfer::from_event(&Event::new("transfer")).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Missing event attribute `recipient`")
+    );
+    let event = Event::new("token_minted")
+        .add_attribute("type", "mint")
+        .add_attribute("amount", "five");
+    assert!(TokenMinted::from_event(&event).is_err());
+}
+
+#[test]
+fn declared_events() {
+    assert_eq!(
+        sv::message_events(),
+        [
+            ("instantiate", vec!["token_minted"]),
+            ("mint", vec!["token_minted", "frozen"]),
+        ]
+    );
+    assert_eq!(
+        token::sv::message_events(),
+        [("transfer", vec!["transfer"])]
+    );
+}
+
+#[cfg(all(test, feature = "mt"))]
+mod tests {
+    use cw_multi_test::IntoBech32;
+    use sylvia::cw_std::{Addr, Uint128};
+    use sylvia::multitest::{App, TypedEvents};
+
+    use crate::sv::mt::{CodeId, ContractProxy};
+    use crate::token::sv::mt::TokenProxy;
+    use crate::{Frozen, TokenMinted, Transfer};
+
+    #[test]
+    fn decode_emitted_events() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_bech32();
+
+        let contract = code_id.instantiate(10).call(&owner).unwrap();
+
+        let resp = contract.mint(5).call(&owner).unwrap();
+        assert_eq!(
+            resp.typed_events::<TokenMinted>().unwrap(),
+            [TokenMinted {
+                r#type: "mint".to_owned(),
+                amount: 5,
+            }]
+        );
+        assert_eq!(resp.typed_events::<Frozen>().unwrap(), [Frozen]);
+        assert_eq!(resp.typed_events::<Transfer>().unwrap(), []);
+
+        let resp = contract
+            .transfer("recipient".to_owned(), 3, None)
+            .call(&owner)
+            .unwrap();
+        assert_eq!(
+            resp.typed_events::<Transfer>().unwrap(),
+            [Transfer {
+                recipient: Addr::unchecked("recipient"),
+                amount: Uint128::new(3),
+                memo: None,
+            }]
+        );
+    }
+}
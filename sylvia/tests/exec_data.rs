@@ -0,0 +1,222 @@
+use cosmwasm_schema::cw_serde;
+use cw_storage_plus::Item;
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_std::{to_json_binary, Response, StdResult, Uint128};
+use sylvia::events::AddTypedEvent;
+
+#[cw_serde]
+pub struct MintResult {
+    pub minted: Uint128,
+    pub total_supply: Uint128,
+}
+
+#[sylvia::event]
+#[derive(Debug, PartialEq)]
+pub struct Mint {
+    pub amount: Uint128,
+}
+
+pub mod token {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError, StdResult, Uint128};
+    use sylvia::interface;
+
+    use crate::MintResult;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Token {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec, data = MintResult)]
+        fn mint(&self, ctx: ExecCtx, amount: Uint128) -> StdResult<Response>;
+    }
+}
+
+pub struct Contract {
+    total_supply: Item<Uint128>,
+}
+
+#[contract]
+#[sv::messages(token)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            total_supply: Item::new("total_supply"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        self.total_supply.save(ctx.deps.storage, &Uint128::zero())?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec, data = Option<Uint128>)]
+    fn burn(&self, ctx: ExecCtx, amount: Uint128) -> StdResult<Response> {
+        let total_supply = self.total_supply.load(ctx.deps.storage)?;
+        if amount > total_supply {
+            return Ok(Response::new());
+        }
+        let total_supply = total_supply - amount;
+        self.total_supply.save(ctx.deps.storage, &total_supply)?;
+        Ok(Response::new().set_data(to_json_binary(&Some(total_supply))?))
+    }
+
+    #[sv::msg(exec, data = Uint128)]
+    fn missing_data(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl token::Token for Contract {
+    type Error = sylvia::cw_std::StdError;
+
+    fn mint(&self, ctx: ExecCtx, amount: Uint128) -> StdResult<Response> {
+        let total_supply = self
+            .total_supply
+            .update(ctx.deps.storage, |supply| StdResult::Ok(supply + amount))?;
+        let data = MintResult {
+            minted: amount,
+            total_supply,
+        };
+        Ok(Response::new()
+            .add_typed_event(Mint { amount })
+            .set_data(to_json_binary(&data)?))
+    }
+}
+
+pub mod minter {
+    use cw_storage_plus::Item;
+    use sylvia::contract;
+    use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx, ReplyCtx};
+    use sylvia::cw_std::{Addr, Binary, Response, StdResult, Uint128};
+    use sylvia::types::Remote;
+
+    use crate::token::sv::Executor;
+    use crate::{Contract, MintResult};
+    use sv::SubMsgMethods;
+
+    pub struct Minter {
+        token: Item<Remote<'static, Contract>>,
+        last_mint: Item<MintResult>,
+    }
+
+    #[contract]
+    #[sv::features(replies)]
+    impl Minter {
+        pub const fn new() -> Self {
+            Self {
+                token: Item::new("token"),
+                last_mint: Item::new("last_mint"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx, token: Addr) -> StdResult<Response> {
+            self.token.save(ctx.deps.storage, &Remote::new(token))?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn mint(&self, ctx: ExecCtx, amount: Uint128) -> StdResult<Response> {
+            let submsg = self
+                .token
+                .load(ctx.deps.storage)?
+                .executor()
+                .mint(amount)?
+                .minted(Binary::default())?;
+            Ok(Response::new().add_submessage(submsg))
+        }
+
+        #[sv::msg(query)]
+        fn last_mint(&self, ctx: QueryCtx) -> StdResult<MintResult> {
+            self.last_mint.load(ctx.deps.storage)
+        }
+
+        // Reply handler decodes the data declared by the executed method, so the submessage
+        // can be created straight from the `ExecutorBuilder`
+        #[sv::msg(reply, reply_on=success)]
+        fn minted(
+            &self,
+            ctx: ReplyCtx,
+            #[sv::data] data: MintResult,
+            #[sv::payload(raw)] _payload: Binary,
+        ) -> StdResult<Response> {
+            self.last_mint.save(ctx.deps.storage, &data)?;
+            Ok(Response::new())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mt"))]
+mod tests {
+    use cw_multi_test::IntoBech32;
+    use sylvia::cw_std::{StdError, Uint128};
+    use sylvia::multitest::{App, ExecResponse, TypedEvents};
+
+    use crate::minter::sv::mt::{CodeId as MinterCodeId, MinterProxy};
+    use crate::sv::mt::{CodeId, ContractProxy};
+    use crate::token::sv::mt::TokenProxy;
+    use crate::{Mint, MintResult};
+
+    #[test]
+    fn call_typed() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+        let contract = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+
+        let resp = contract.mint(Uint128::new(100)).call_typed(&owner).unwrap();
+        assert_eq!(
+            resp.data,
+            MintResult {
+                minted: Uint128::new(100),
+                total_supply: Uint128::new(100),
+            }
+        );
+        let mints: Vec<Mint> = resp.typed_events().unwrap();
+        assert_eq!(
+            mints,
+            [Mint {
+                amount: Uint128::new(100)
+            }]
+        );
+
+        // Methods without the data can still be called the usual way
+        let resp = contract.mint(Uint128::new(50)).call(&owner).unwrap();
+        assert!(resp.data.is_some());
+
+        let ExecResponse { data, .. } = contract.burn(Uint128::new(30)).call_typed(&owner).unwrap();
+        assert_eq!(data, Some(Uint128::new(120)));
+        let resp = contract.burn(Uint128::new(500)).call_typed(&owner).unwrap();
+        assert_eq!(resp.data, None);
+
+        let err = contract.missing_data().call_typed(&owner).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Missing data in the response of the execute message")
+        );
+    }
+
+    #[test]
+    fn reply_data() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+        let token = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+
+        let minter = MinterCodeId::store_code(&app)
+            .instantiate(token.contract_addr.clone())
+            .call(&owner)
+            .unwrap();
+        minter.mint(Uint128::new(10)).call(&owner).unwrap();
+
+        assert_eq!(
+            minter.last_mint().unwrap(),
+            MintResult {
+                minted: Uint128::new(10),
+                total_supply: Uint128::new(10),
+            }
+        );
+    }
+}
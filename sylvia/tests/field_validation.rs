@@ -0,0 +1,279 @@
+use cosmwasm_std::testing::MockApi;
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Binary, Response, StdError, StdResult, Uint128};
+
+pub mod metadata {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Metadata {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn set_tags(
+            &self,
+            ctx: ExecCtx,
+            #[sv::validate(length(max = 3))] tags: Vec<String>,
+        ) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn tag(
+            &self,
+            ctx: QueryCtx,
+            #[sv::validate(max = 2)] index: u32,
+        ) -> Result<Option<String>, Self::Error>;
+    }
+}
+
+pub fn not_reserved(name: &str) -> StdResult<()> {
+    match name {
+        "reserved" => Err(StdError::generic_err("Name is reserved")),
+        _ => Ok(()),
+    }
+}
+
+pub fn only_owner<Contract>(ctx: &ExecCtx, _contract: &Contract) -> StdResult<()> {
+    match ctx.info.sender == MockApi::default().addr_make("owner") {
+        true => Ok(()),
+        false => Err(StdError::generic_err("Unauthorized")),
+    }
+}
+
+pub struct Contract {
+    name: Item<String>,
+    tags: Item<Vec<String>>,
+}
+
+#[sylvia::contract]
+#[sv::messages(metadata as Metadata)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            name: Item::new("name"),
+            tags: Item::new("tags"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(
+        &self,
+        ctx: InstantiateCtx,
+        #[sv::validate(length(min = 3, max = 50), custom = not_reserved)] name: String,
+        #[sv::validate(length(min = 3, max = 12), chars(alphabetic, "-"))] symbol: String,
+        #[sv::validate(max = 18)] decimals: u8,
+    ) -> StdResult<Response> {
+        let _ = (symbol, decimals);
+        self.name.save(ctx.deps.storage, &name)?;
+        self.tags.save(ctx.deps.storage, &vec![])?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn transfer(
+        &self,
+        _ctx: ExecCtx,
+        #[sv::validate(min = Uint128::one())] amount: Uint128,
+        #[sv::validate(non_empty, length(max = 16))] memo: Option<String>,
+    ) -> StdResult<Response> {
+        let _ = (amount, memo);
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn set_logo(
+        &self,
+        _ctx: ExecCtx,
+        #[sv::validate(length(min = 2, max = 30))] logo: Binary,
+    ) -> StdResult<Response> {
+        let _ = logo;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::guard(only_owner)]
+    fn rename(
+        &self,
+        ctx: ExecCtx,
+        #[sv::validate(custom = not_reserved)] name: String,
+    ) -> StdResult<Response> {
+        self.name.save(ctx.deps.storage, &name)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn name(&self, ctx: QueryCtx) -> StdResult<String> {
+        self.name.load(ctx.deps.storage)
+    }
+}
+
+impl metadata::Metadata for Contract {
+    type Error = StdError;
+
+    fn set_tags(&self, ctx: ExecCtx, tags: Vec<String>) -> StdResult<Response> {
+        self.tags.save(ctx.deps.storage, &tags)?;
+        Ok(Response::new())
+    }
+
+    fn tag(&self, ctx: QueryCtx, index: u32) -> StdResult<Option<String>> {
+        let tags = self.tags.load(ctx.deps.storage)?;
+        Ok(tags.get(index as usize).cloned())
+    }
+}
+
+#[test]
+fn rules_in_schema() {
+    let api = sylvia::schema::contract_api::<Contract>("contract", "1.0.0")
+        .render()
+        .to_string()
+        .unwrap();
+
+    // Rules of the `name` and `symbol`
+    assert_eq!(api.matches("\"minLength\": 3").count(), 2);
+    assert!(api.contains("\"maxLength\": 50"));
+    assert!(api.contains("\"pattern\": \"^[a-zA-Z\\\\-]*$\""));
+    // Rules of the `decimals` and `index`
+    assert!(api.contains("\"maximum\": 18.0"));
+    assert!(api.contains("\"maximum\": 2.0"));
+    // Rules of the `memo` and `tags`
+    assert!(api.contains("\"minLength\": 1"));
+    assert!(api.contains("\"maxLength\": 16"));
+    assert!(api.contains("\"maxItems\": 3"));
+    // Byte bounds of the `logo` are converted to the length of its base64 string
+    assert!(api.contains("\"minLength\": 4"));
+    assert!(api.contains("\"maxLength\": 40"));
+    assert!(!api.contains("\"maxLength\": 30"));
+    // Bounds which are not literals are not part of the schema
+    assert!(!api.contains("\"minimum\": 1.0"));
+}
+
+#[cfg(all(test, feature = "mt"))]
+mod tests {
+    use cw_multi_test::IntoBech32;
+    use sylvia::cw_std::{StdError, Uint128};
+    use sylvia::multitest::App;
+
+    use crate::metadata::sv::mt::MetadataProxy;
+    use crate::sv::mt::{CodeId, ContractProxy};
+
+    #[test]
+    fn parameters_are_validated() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_bech32();
+
+        let cases = [
+            ("to", "TKN", 6, "Invalid `name`: length must be at least 3"),
+            ("reserved", "TKN", 6, "Name is reserved"),
+            (
+                "Token",
+                "TK",
+                6,
+                "Invalid `symbol`: length must be at least 3",
+            ),
+            (
+                "Token",
+                "TK1",
+                6,
+                "Invalid `symbol`: character `1` is not allowed",
+            ),
+            ("Token", "TKN", 19, "Invalid `decimals`: must be at most 18"),
+        ];
+        for (name, symbol, decimals, err) in cases {
+            let result = code_id
+                .instantiate(name.to_owned(), symbol.to_owned(), decimals)
+                .call(&owner)
+                .unwrap_err();
+            assert_eq!(result, StdError::generic_err(err));
+        }
+
+        let contract = code_id
+            .instantiate("Token".to_owned(), "T-KN".to_owned(), 18)
+            .call(&owner)
+            .unwrap();
+        assert_eq!(contract.name().unwrap(), "Token");
+
+        let err = contract
+            .transfer(Uint128::zero(), None)
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Invalid `amount`: must be at least 1")
+        );
+        let err = contract
+            .transfer(Uint128::one(), Some("".to_owned()))
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Invalid `memo`: length must be at least 1")
+        );
+        let err = contract
+            .transfer(Uint128::one(), Some("x".repeat(17)))
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Invalid `memo`: length must be at most 16")
+        );
+        contract
+            .transfer(Uint128::one(), None)
+            .call(&owner)
+            .unwrap();
+        contract
+            .transfer(Uint128::one(), Some("a".to_owned()))
+            .call(&owner)
+            .unwrap();
+
+        let tags = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+        let err = contract
+            .set_tags([tags.clone(), vec!["d".to_owned()]].concat())
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Invalid `tags`: length must be at most 3")
+        );
+        contract.set_tags(tags).call(&owner).unwrap();
+
+        assert_eq!(contract.tag(2).unwrap(), Some("c".to_owned()));
+        let err = contract.tag(3).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err(
+                "Querier contract error: Generic error: Invalid `index`: must be at most 2"
+            )
+        );
+    }
+    #[test]
+    fn guards_are_checked_before_validation() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_bech32();
+        let stranger = "stranger".into_bech32();
+
+        let contract = code_id
+            .instantiate("Token".to_owned(), "TKN".to_owned(), 6)
+            .call(&owner)
+            .unwrap();
+
+        let err = contract
+            .rename("reserved".to_owned())
+            .call(&stranger)
+            .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Unauthorized"));
+
+        let err = contract
+            .rename("reserved".to_owned())
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Name is reserved"));
+
+        contract.rename("Coin".to_owned()).call(&owner).unwrap();
+        assert_eq!(contract.name().unwrap(), "Coin");
+    }
+}
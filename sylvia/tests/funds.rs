@@ -0,0 +1,247 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
+use cosmwasm_std::{coin, coins, Addr, Coin};
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+use sylvia::multitest::App;
+use sylvia::types::FundsPolicy;
+
+const DENOM: &str = "uatom";
+
+pub mod donations {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Donations {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        #[sv::funds(must_pay(crate::DENOM))]
+        fn donate(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct FundsContract;
+
+#[sylvia::contract]
+#[sv::funds(nonpayable)]
+#[sv::messages(donations as Donations)]
+impl FundsContract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn noop(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::funds(must_pay(DENOM))]
+    fn deposit(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::funds(payable("uatom"))]
+    fn tip(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::funds(payable)]
+    fn anything(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn balance(&self, ctx: QueryCtx) -> StdResult<Vec<Coin>> {
+        ctx.deps
+            .querier
+            .query_all_balances(ctx.env.contract.address)
+    }
+}
+
+impl donations::Donations for FundsContract {
+    type Error = StdError;
+
+    fn donate(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn app_with_funds(owner: &Addr) -> App<cw_multi_test::App> {
+    App::new(cw_multi_test::App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, owner, vec![coin(1000, DENOM), coin(1000, "uosmo")])
+            .unwrap();
+    }))
+}
+
+#[test]
+fn funds_policies_metadata() {
+    assert_eq!(
+        sv::funds_policies(),
+        [
+            ("instantiate", FundsPolicy::Nonpayable),
+            ("noop", FundsPolicy::Nonpayable),
+            ("deposit", FundsPolicy::MustPay(DENOM)),
+            ("tip", FundsPolicy::Payable(Some(DENOM))),
+            ("anything", FundsPolicy::Payable(None)),
+        ]
+    );
+
+    let schema = cosmwasm_std::to_json_string(&sylvia::schemars::schema_for!(sv::ExecMsg)).unwrap();
+    assert!(schema.contains("Funds policy: does not accept funds."));
+    assert!(schema.contains("Funds policy: requires `DENOM` funds."));
+    assert!(schema.contains("Funds policy: accepts only `uatom` funds."));
+    assert!(schema.contains("Funds policy: accepts any funds."));
+}
+
+#[test]
+fn dispatch_checks_funds() {
+    let mut deps = mock_dependencies();
+    let sender = MockApi::default().addr_make("sender");
+    let contract = FundsContract::new();
+
+    let err = sv::InstantiateMsg {}
+        .dispatch(
+            &contract,
+            (
+                deps.as_mut(),
+                mock_env(),
+                message_info(&sender, &coins(1, DENOM)),
+            ),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("This message does no accept funds")
+    );
+
+    let err = sv::ExecMsg::noop()
+        .dispatch(
+            &contract,
+            (
+                deps.as_mut(),
+                mock_env(),
+                message_info(&sender, &coins(1, DENOM)),
+            ),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("This message does no accept funds")
+    );
+
+    sv::ExecMsg::noop()
+        .dispatch(
+            &contract,
+            (deps.as_mut(), mock_env(), message_info(&sender, &[])),
+        )
+        .unwrap();
+}
+
+#[test]
+fn multitest_funds() {
+    use donations::sv::mt::DonationsProxy;
+    use sv::mt::{CodeId, FundsContractProxy};
+
+    let owner = MockApi::default().addr_make("owner");
+    let app = app_with_funds(&owner);
+    let code_id = CodeId::store_code(&app);
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    // must_pay
+    let err = contract.deposit().call(&owner).unwrap_err();
+    assert_eq!(err, StdError::generic_err("No funds sent"));
+    let err = contract
+        .deposit()
+        .with_funds(&coins(10, "uosmo"))
+        .call(&owner)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Must send reserve token 'uatom'")
+    );
+    contract
+        .deposit()
+        .with_funds(&coins(10, DENOM))
+        .call(&owner)
+        .unwrap();
+
+    // payable in denom
+    contract.tip().call(&owner).unwrap();
+    contract
+        .tip()
+        .with_funds(&coins(10, DENOM))
+        .call(&owner)
+        .unwrap();
+    let err = contract
+        .tip()
+        .with_funds(&coins(10, "uosmo"))
+        .call(&owner)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Received unsupported denom 'uosmo'")
+    );
+
+    // payable
+    contract
+        .anything()
+        .with_funds(&coins(10, "uosmo"))
+        .call(&owner)
+        .unwrap();
+
+    // interface
+    assert_eq!(
+        contract.donate().funds_policy(),
+        FundsPolicy::MustPay(DENOM)
+    );
+    contract
+        .donate()
+        .with_funds(&coins(10, DENOM))
+        .call(&owner)
+        .unwrap();
+
+    assert_eq!(
+        contract.balance().unwrap(),
+        vec![coin(30, DENOM), coin(10, "uosmo")]
+    );
+}
+
+#[test]
+#[should_panic(expected = "is nonpayable, but funds")]
+fn funds_sent_to_nonpayable_exec() {
+    use sv::mt::{CodeId, FundsContractProxy};
+
+    let owner = MockApi::default().addr_make("owner");
+    let app = app_with_funds(&owner);
+    let code_id = CodeId::store_code(&app);
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+    let _ = contract.noop().with_funds(&coins(10, DENOM)).call(&owner);
+}
+
+#[test]
+#[should_panic(expected = "is nonpayable, but funds")]
+fn funds_sent_to_nonpayable_instantiate() {
+    let owner = MockApi::default().addr_make("owner");
+    let app = app_with_funds(&owner);
+    let code_id = sv::mt::CodeId::store_code(&app);
+
+    let _ = code_id
+        .instantiate()
+        .with_funds(&coins(10, DENOM))
+        .call(&owner);
+}
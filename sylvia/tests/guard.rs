@@ -0,0 +1,176 @@
+use cosmwasm_std::testing::MockApi;
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Addr, Response, StdError, StdResult};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("Unauthorized")]
+    Unauthorized,
+    #[error("Paused")]
+    Paused,
+}
+
+pub mod whitelist {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Addr, Response, StdError};
+    use sylvia::interface;
+
+    /// Guard declared next to the interface.
+    /// Uses the interface query to check if the sender is a member.
+    pub fn only_member<C: Whitelist>(ctx: &ExecCtx, contract: &C) -> Result<(), C::Error> {
+        let query_ctx = QueryCtx::from((ctx.deps.as_ref(), ctx.env.clone()));
+        match contract.is_member(query_ctx, ctx.info.sender.clone())? {
+            true => Ok(()),
+            false => Err(StdError::generic_err("Not a member").into()),
+        }
+    }
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Whitelist {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        #[sv::guard(only_member)]
+        fn add_member(&self, ctx: ExecCtx, member: Addr) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn is_member(&self, ctx: QueryCtx, addr: Addr) -> Result<bool, Self::Error>;
+    }
+}
+
+pub struct GuardedContract {
+    admin: Item<Addr>,
+    members: Item<Vec<Addr>>,
+    paused: Item<bool>,
+    counter: Item<u32>,
+}
+
+fn only_admin(ctx: &ExecCtx, contract: &GuardedContract) -> Result<(), ContractError> {
+    if contract.admin.load(ctx.deps.storage)? != ctx.info.sender {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn not_paused(ctx: &ExecCtx, contract: &GuardedContract) -> Result<(), ContractError> {
+    if contract.paused.load(ctx.deps.storage)? {
+        return Err(ContractError::Paused);
+    }
+    Ok(())
+}
+
+#[sylvia::contract]
+#[sv::error(ContractError)]
+#[sv::messages(whitelist as Whitelist)]
+impl GuardedContract {
+    pub const fn new() -> Self {
+        Self {
+            admin: Item::new("admin"),
+            members: Item::new("members"),
+            paused: Item::new("paused"),
+            counter: Item::new("counter"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> Result<Response, ContractError> {
+        self.admin.save(ctx.deps.storage, &ctx.info.sender)?;
+        self.members
+            .save(ctx.deps.storage, &vec![ctx.info.sender])?;
+        self.paused.save(ctx.deps.storage, &false)?;
+        self.counter.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::guard(only_admin)]
+    fn pause(&self, ctx: ExecCtx, paused: bool) -> Result<Response, ContractError> {
+        self.paused.save(ctx.deps.storage, &paused)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::guard(not_paused)]
+    #[sv::guard(crate::only_admin)]
+    fn increment(&self, ctx: ExecCtx) -> Result<Response, ContractError> {
+        self.counter
+            .update(ctx.deps.storage, |c| -> StdResult<_> { Ok(c + 1) })?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn unguarded(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn counter(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.counter.load(ctx.deps.storage)
+    }
+}
+
+impl whitelist::Whitelist for GuardedContract {
+    type Error = ContractError;
+
+    fn add_member(&self, ctx: ExecCtx, member: Addr) -> Result<Response, ContractError> {
+        self.members.update(ctx.deps.storage, |mut members| {
+            members.push(member);
+            StdResult::Ok(members)
+        })?;
+        Ok(Response::new())
+    }
+
+    fn is_member(&self, ctx: QueryCtx, addr: Addr) -> Result<bool, ContractError> {
+        Ok(self.members.load(ctx.deps.storage)?.contains(&addr))
+    }
+}
+
+#[test]
+fn guards_are_called_before_the_method() {
+    use sv::mt::{CodeId, GuardedContractProxy};
+    use whitelist::sv::mt::WhitelistProxy;
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let admin = MockApi::default().addr_make("admin");
+    let stranger = MockApi::default().addr_make("stranger");
+
+    let contract = code_id.instantiate().call(&admin).unwrap();
+
+    let err = contract.pause(true).call(&stranger).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    contract.unguarded().call(&stranger).unwrap();
+
+    contract.increment().call(&admin).unwrap();
+    assert_eq!(contract.counter().unwrap(), 1);
+
+    let err = contract.increment().call(&stranger).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // Guards are called in the order of declaration
+    contract.pause(true).call(&admin).unwrap();
+    let err = contract.increment().call(&stranger).unwrap_err();
+    assert_eq!(err, ContractError::Paused);
+    assert_eq!(contract.counter().unwrap(), 1);
+
+    // Interface guard
+    let err = contract
+        .add_member(stranger.clone())
+        .call(&stranger)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("Not a member"))
+    );
+
+    contract.add_member(stranger.clone()).call(&admin).unwrap();
+    assert!(contract.is_member(stranger.clone()).unwrap());
+    contract.add_member(admin.clone()).call(&stranger).unwrap();
+}
@@ -0,0 +1,266 @@
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{
+    Ibc3ChannelOpenResponse, IbcAcknowledgement, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcEndpoint, IbcOrder,
+    IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse,
+    IbcTimeout, Timestamp,
+};
+use cw_storage_plus::Item;
+use sylvia::ctx::{
+    IbcChannelCloseCtx, IbcChannelConnectCtx, IbcChannelOpenCtx, IbcPacketAckCtx,
+    IbcPacketReceiveCtx, IbcPacketTimeoutCtx, InstantiateCtx, QueryCtx,
+};
+use sylvia::cw_std::{Binary, Response, StdError, StdResult};
+use sylvia::{contract, entry_points};
+
+const IBC_VERSION: &str = "sv-ibc-1";
+
+pub mod overrides {
+    use sylvia::cw_std::{
+        entry_point, DepsMut, Env, IbcBasicResponse, IbcChannelCloseMsg, StdResult,
+    };
+
+    #[entry_point]
+    pub fn ibc_channel_close(
+        _deps: DepsMut,
+        _env: Env,
+        _msg: IbcChannelCloseMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        Ok(IbcBasicResponse::new().add_attribute("action", "overridden_close"))
+    }
+}
+
+pub struct IbcContract {
+    pub(crate) counter: Item<u32>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::override_entry_point(ibc_channel_close=crate::overrides::ibc_channel_close(sylvia::cw_std::IbcChannelCloseMsg))]
+impl IbcContract {
+    pub const fn new() -> Self {
+        Self {
+            counter: Item::new("counter"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        self.counter.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn counter(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.counter.load(ctx.deps.storage)
+    }
+
+    #[sv::msg(ibc_channel_open)]
+    fn channel_open(
+        &self,
+        _ctx: IbcChannelOpenCtx,
+        msg: IbcChannelOpenMsg,
+    ) -> StdResult<IbcChannelOpenResponse> {
+        if msg.channel().version != IBC_VERSION {
+            return Err(StdError::generic_err("Invalid channel version"));
+        }
+        Ok(Some(Ibc3ChannelOpenResponse {
+            version: IBC_VERSION.to_owned(),
+        }))
+    }
+
+    #[sv::msg(ibc_channel_connect)]
+    fn channel_connect(
+        &self,
+        ctx: IbcChannelConnectCtx,
+        msg: IbcChannelConnectMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        self.counter
+            .update(ctx.deps.storage, |c| -> StdResult<_> { Ok(c + 1) })?;
+        Ok(IbcBasicResponse::new()
+            .add_attribute("action", "channel_connect")
+            .add_attribute("channel", &msg.channel().endpoint.channel_id))
+    }
+
+    #[sv::msg(ibc_channel_close)]
+    #[allow(dead_code)]
+    fn channel_close(
+        &self,
+        _ctx: IbcChannelCloseCtx,
+        _msg: IbcChannelCloseMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        Ok(IbcBasicResponse::new().add_attribute("action", "channel_close"))
+    }
+
+    #[sv::msg(ibc_packet_receive)]
+    fn packet_receive(
+        &self,
+        ctx: IbcPacketReceiveCtx,
+        msg: IbcPacketReceiveMsg,
+    ) -> StdResult<IbcReceiveResponse> {
+        self.counter
+            .update(ctx.deps.storage, |c| -> StdResult<_> { Ok(c + 1) })?;
+        Ok(IbcReceiveResponse::new(msg.packet.data).add_attribute("action", "packet_receive"))
+    }
+
+    #[sv::msg(ibc_packet_ack)]
+    fn packet_ack(
+        &self,
+        ctx: IbcPacketAckCtx,
+        msg: IbcPacketAckMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        self.counter
+            .update(ctx.deps.storage, |c| -> StdResult<_> { Ok(c + 1) })?;
+        Ok(IbcBasicResponse::new().add_attribute("ack", msg.acknowledgement.data.to_string()))
+    }
+
+    #[sv::msg(ibc_packet_timeout)]
+    fn packet_timeout(
+        &self,
+        ctx: IbcPacketTimeoutCtx,
+        _msg: IbcPacketTimeoutMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        self.counter
+            .update(ctx.deps.storage, |c| -> StdResult<_> { Ok(c + 10) })?;
+        Ok(IbcBasicResponse::new().add_attribute("action", "packet_timeout"))
+    }
+}
+
+fn mock_channel(version: &str) -> IbcChannel {
+    IbcChannel::new(
+        IbcEndpoint {
+            port_id: "my_port".to_owned(),
+            channel_id: "channel-1".to_owned(),
+        },
+        IbcEndpoint {
+            port_id: "their_port".to_owned(),
+            channel_id: "channel-7".to_owned(),
+        },
+        IbcOrder::Unordered,
+        version,
+        "connection-2",
+    )
+}
+
+fn mock_packet(data: Binary) -> IbcPacket {
+    IbcPacket::new(
+        data,
+        IbcEndpoint {
+            port_id: "their_port".to_owned(),
+            channel_id: "channel-7".to_owned(),
+        },
+        IbcEndpoint {
+            port_id: "my_port".to_owned(),
+            channel_id: "channel-1".to_owned(),
+        },
+        1,
+        IbcTimeout::with_timestamp(Timestamp::from_seconds(100)),
+    )
+}
+
+#[test]
+fn ibc_entry_points() {
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+
+    use crate::entry_points::{
+        ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_receive,
+        ibc_packet_timeout, instantiate, query,
+    };
+    use crate::overrides::ibc_channel_close;
+    use crate::sv::{ContractQueryMsg, InstantiateMsg, QueryMsg};
+
+    let mut deps = mock_dependencies();
+    let sender = MockApi::default().addr_make("sender");
+    let relayer = MockApi::default().addr_make("relayer");
+    let query_counter = |deps: cosmwasm_std::Deps| -> u32 {
+        let msg = ContractQueryMsg::IbcContract(QueryMsg::Counter {});
+        cosmwasm_std::from_json(query(deps, mock_env(), msg).unwrap()).unwrap()
+    };
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sender, &[]),
+        InstantiateMsg {},
+    )
+    .unwrap();
+
+    // Channel open
+    let channel = mock_channel(IBC_VERSION);
+    let resp = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        IbcChannelOpenMsg::new_init(channel.clone()),
+    )
+    .unwrap();
+    assert_eq!(
+        resp,
+        Some(Ibc3ChannelOpenResponse {
+            version: IBC_VERSION.to_owned()
+        })
+    );
+
+    let invalid_channel = mock_channel("other-version");
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        IbcChannelOpenMsg::new_init(invalid_channel),
+    )
+    .unwrap_err();
+    assert_eq!(err, StdError::generic_err("Invalid channel version"));
+
+    // Channel connect
+    let resp = ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        IbcChannelConnectMsg::new_confirm(channel.clone()),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, "channel_connect");
+    assert_eq!(resp.attributes[1].value, "channel-1");
+    assert_eq!(query_counter(deps.as_ref()), 1);
+
+    // Packet receive
+    let data = Binary::from(b"packet-data");
+    let resp = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        IbcPacketReceiveMsg::new(mock_packet(data.clone()), relayer.clone()),
+    )
+    .unwrap();
+    assert_eq!(resp.acknowledgement, Some(data.clone()));
+    assert_eq!(query_counter(deps.as_ref()), 2);
+
+    // Packet ack
+    let resp = ibc_packet_ack(
+        deps.as_mut(),
+        mock_env(),
+        IbcPacketAckMsg::new(
+            IbcAcknowledgement::new(data.clone()),
+            mock_packet(data.clone()),
+            relayer.clone(),
+        ),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, data.to_string());
+    assert_eq!(query_counter(deps.as_ref()), 3);
+
+    // Packet timeout
+    let resp = ibc_packet_timeout(
+        deps.as_mut(),
+        mock_env(),
+        IbcPacketTimeoutMsg::new(mock_packet(data), relayer),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, "packet_timeout");
+    assert_eq!(query_counter(deps.as_ref()), 13);
+
+    // Channel close is overridden
+    let resp = ibc_channel_close(
+        deps.as_mut(),
+        mock_env(),
+        IbcChannelCloseMsg::new_init(channel),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, "overridden_close");
+}
@@ -0,0 +1,203 @@
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, IbcAcknowledgement, IbcBasicResponse, IbcEndpoint, IbcMsg,
+    IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcReceiveResponse, IbcTimeout, Timestamp,
+};
+use cw_storage_plus::Map;
+use sylvia::ctx::{IbcPacketAckCtx, IbcPacketCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+use sylvia::types::{PacketAck, PacketResponse};
+use sylvia::{contract, entry_points};
+
+pub struct TransferContract {
+    pub(crate) balances: Map<String, u128>,
+}
+
+#[entry_points]
+#[contract]
+impl TransferContract {
+    pub const fn new() -> Self {
+        Self {
+            balances: Map::new("balances"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn balance(&self, ctx: QueryCtx, recipient: String) -> StdResult<u128> {
+        self.balances
+            .may_load(ctx.deps.storage, recipient)
+            .map(Option::unwrap_or_default)
+    }
+
+    #[sv::msg(ibc_packet)]
+    fn transfer(
+        &self,
+        ctx: IbcPacketCtx,
+        recipient: String,
+        amount: u128,
+    ) -> StdResult<PacketResponse<u128>> {
+        if amount == 0 {
+            return Err(StdError::generic_err("Zero amount"));
+        }
+
+        let balance =
+            self.balances
+                .update(ctx.deps.storage, recipient, |balance| -> StdResult<_> {
+                    Ok(balance.unwrap_or_default() + amount)
+                })?;
+
+        let response = IbcReceiveResponse::without_ack()
+            .add_attribute("channel", ctx.packet.dest.channel_id)
+            .add_attribute("relayer", ctx.relayer);
+        Ok(PacketResponse::new(balance, response))
+    }
+
+    #[sv::msg(ibc_packet)]
+    fn ping(&self, _ctx: IbcPacketCtx) -> StdResult<String> {
+        Ok("pong".to_owned())
+    }
+
+    #[sv::msg(ibc_packet_ack)]
+    fn packet_ack(
+        &self,
+        _ctx: IbcPacketAckCtx,
+        msg: IbcPacketAckMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        let packet: sv::IbcPacketMsg = from_json(&msg.original_packet.data)?;
+        let resp = IbcBasicResponse::new();
+        match packet {
+            sv::IbcPacketMsg::Transfer { .. } => {
+                let ack = PacketAck::<u128>::from_ack(&msg.acknowledgement)?;
+                Ok(resp.add_attribute("transfer_ack", format!("{:?}", ack.into_result())))
+            }
+            sv::IbcPacketMsg::Ping {} => Ok(resp.add_attribute("ping_ack", "received")),
+        }
+    }
+}
+
+fn mock_packet(data: Binary) -> IbcPacket {
+    IbcPacket::new(
+        data,
+        IbcEndpoint {
+            port_id: "their_port".to_owned(),
+            channel_id: "channel-7".to_owned(),
+        },
+        IbcEndpoint {
+            port_id: "my_port".to_owned(),
+            channel_id: "channel-1".to_owned(),
+        },
+        1,
+        IbcTimeout::with_timestamp(Timestamp::from_seconds(100)),
+    )
+}
+
+#[test]
+fn send_packet() {
+    use crate::sv::{IbcPacketMsg, IbcPacketSender};
+    use sylvia::types::IbcPacketBuilder;
+
+    let timeout = IbcTimeout::with_timestamp(Timestamp::from_seconds(100));
+    let msg = IbcPacketBuilder::<TransferContract>::new("channel-1", timeout.clone())
+        .transfer("alice".to_owned(), 100)
+        .unwrap();
+
+    assert_eq!(
+        msg,
+        IbcMsg::SendPacket {
+            channel_id: "channel-1".to_owned(),
+            data: to_json_binary(&IbcPacketMsg::transfer("alice".to_owned(), 100)).unwrap(),
+            timeout,
+        }
+    );
+
+    let IbcMsg::SendPacket { data, .. } = msg else {
+        panic!("Expected SendPacket message");
+    };
+    assert_eq!(
+        data,
+        Binary::from(br#"{"transfer":{"recipient":"alice","amount":100}}"#)
+    );
+}
+
+#[test]
+fn receive_packet() {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    use crate::entry_points::{ibc_packet_ack, ibc_packet_receive, query};
+    use crate::sv::{ContractQueryMsg, IbcPacketMsg, QueryMsg};
+
+    let mut deps = mock_dependencies();
+    let relayer = MockApi::default().addr_make("relayer");
+    let receive = |packet: &IbcPacketMsg| {
+        IbcPacketReceiveMsg::new(
+            mock_packet(to_json_binary(packet).unwrap()),
+            relayer.clone(),
+        )
+    };
+
+    let packet = IbcPacketMsg::transfer("alice".to_owned(), 100);
+    ibc_packet_receive(deps.as_mut(), mock_env(), receive(&packet)).unwrap();
+    let resp = ibc_packet_receive(deps.as_mut(), mock_env(), receive(&packet)).unwrap();
+
+    let ack = resp.acknowledgement.unwrap();
+    assert_eq!(
+        PacketAck::<u128>::from_ack(&IbcAcknowledgement::new(ack.clone())).unwrap(),
+        PacketAck::success(200)
+    );
+    assert_eq!(resp.attributes[0].value, "channel-1");
+    assert_eq!(resp.attributes[1].value, relayer.as_str());
+
+    let msg = ContractQueryMsg::TransferContract(QueryMsg::balance("alice".to_owned()));
+    let balance: u128 = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+    assert_eq!(balance, 200);
+
+    let resp =
+        ibc_packet_receive(deps.as_mut(), mock_env(), receive(&IbcPacketMsg::ping())).unwrap();
+    assert_eq!(
+        PacketAck::<String>::from_ack(&IbcAcknowledgement::new(resp.acknowledgement.unwrap()))
+            .unwrap(),
+        PacketAck::success("pong".to_owned())
+    );
+
+    // Handler errors are returned, so the chain reverts the state and writes the error ack
+    let err = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        receive(&IbcPacketMsg::transfer("alice".to_owned(), 0)),
+    )
+    .unwrap_err();
+    assert_eq!(err, StdError::generic_err("Zero amount"));
+
+    // Undecodable packet is acknowledged with an error
+    let resp = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        IbcPacketReceiveMsg::new(mock_packet(Binary::from(b"invalid")), relayer.clone()),
+    )
+    .unwrap();
+    let ack =
+        PacketAck::<()>::from_ack(&IbcAcknowledgement::new(resp.acknowledgement.unwrap())).unwrap();
+    assert!(!ack.is_success());
+
+    // Acknowledgement of the sent packet
+    let resp = ibc_packet_ack(
+        deps.as_mut(),
+        mock_env(),
+        IbcPacketAckMsg::new(
+            IbcAcknowledgement::new(
+                PacketAck::<u128>::error("Out of funds")
+                    .to_binary()
+                    .unwrap(),
+            ),
+            mock_packet(to_json_binary(&packet).unwrap()),
+            relayer,
+        ),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, r#"Err("Out of funds")"#);
+}
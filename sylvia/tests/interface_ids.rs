@@ -0,0 +1,293 @@
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Empty, Response, StdError, StdResult};
+use sylvia::types::{BoundQuerier, Interface, InterfaceId, SupportedInterfacesQuery};
+
+pub mod counter {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn increase_by(&self, ctx: ExecCtx, amount: u32) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> Result<u64, Self::Error>;
+    }
+}
+
+pub mod counter_v2 {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn increase_by(&self, ctx: ExecCtx, amount: u64) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> Result<u64, Self::Error>;
+    }
+}
+
+pub mod counter_alias {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::interface;
+
+    /// Same messages as the [crate::counter::Counter] spelled differently.
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<core::primitive::u64>;
+
+        #[sv::msg(exec)]
+        fn increase_by(
+            &self,
+            ctx: ExecCtx,
+            amount: std::primitive::u32,
+        ) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod transfer {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Transfer {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn transfer(
+            &self,
+            ctx: ExecCtx,
+            recipient: String,
+            amount: Vec<u128>,
+        ) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod transfer_reordered {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Transfer {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn transfer(
+            &self,
+            ctx: ExecCtx,
+            amount: std::vec::Vec<u128>,
+            recipient: std::string::String,
+        ) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod admin {
+    use sylvia::ctx::QueryCtx;
+    use sylvia::cw_std::{Empty, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    pub trait Admin {
+        type Error: From<StdError>;
+        type ExecC: sylvia::types::CustomMsg;
+        type QueryC: sylvia::types::CustomQuery;
+
+        #[sv::msg(query)]
+        fn admin(&self, ctx: QueryCtx<Self::QueryC>) -> Result<Option<String>, Self::Error>;
+    }
+
+    impl Admin for crate::CounterContract {
+        type Error = StdError;
+        type ExecC = Empty;
+        type QueryC = Empty;
+
+        fn admin(&self, _ctx: QueryCtx) -> Result<Option<String>, Self::Error> {
+            Ok(None)
+        }
+    }
+}
+
+pub struct CounterContract;
+
+pub mod plain {
+    use sylvia::ctx::InstantiateCtx;
+    use sylvia::cw_std::{Response, StdResult};
+
+    /// Contract not exposing the `supported_interfaces` query.
+    pub struct PlainContract;
+
+    #[sylvia::contract]
+    impl PlainContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+#[sylvia::contract]
+#[sv::features(supported_interfaces)]
+#[sv::messages(counter as Counter)]
+#[sv::messages(admin as Admin)]
+impl CounterContract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl counter::Counter for CounterContract {
+    type Error = StdError;
+
+    fn increase_by(&self, _ctx: ExecCtx, _amount: u32) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn count(&self, _ctx: QueryCtx) -> StdResult<u64> {
+        Ok(0)
+    }
+}
+
+#[test]
+fn interface_ids() {
+    let id = counter::sv::INTERFACE_ID;
+    assert_eq!(id.name(), "Counter");
+    assert_eq!(id.to_string(), format!("Counter:{:016x}", id.hash()));
+    assert_eq!(<dyn counter::Counter<Error = StdError>>::ID, id);
+    assert_eq!(
+        <dyn admin::Admin<Error = StdError, ExecC = Empty, QueryC = Empty>>::ID,
+        admin::sv::INTERFACE_ID
+    );
+
+    // Interfaces with the same name but different messages have different ids
+    assert_ne!(counter_v2::sv::INTERFACE_ID, id);
+    assert_ne!(
+        InterfaceId::new("Counter", "query count() -> uint64"),
+        InterfaceId::new("Counter", "query count() -> uint32")
+    );
+}
+
+#[test]
+fn interface_ids_depend_on_wire_format() {
+    // Order of the methods and spelling of the types don't matter
+    assert_eq!(counter_alias::sv::INTERFACE_ID, counter::sv::INTERFACE_ID);
+    // Neither does the order of the parameters
+    assert_eq!(
+        transfer_reordered::sv::INTERFACE_ID,
+        transfer::sv::INTERFACE_ID
+    );
+    assert_eq!(
+        transfer::sv::INTERFACE_ID,
+        InterfaceId::new(
+            "Transfer",
+            "execute transfer(amount: Array_of_uint128, recipient: String)"
+        )
+    );
+    assert_eq!(
+        counter::sv::INTERFACE_ID,
+        InterfaceId::new(
+            "Counter",
+            "execute increase_by(amount: uint32); query count() -> uint64"
+        )
+    );
+}
+
+#[test]
+fn contract_supported_interfaces() {
+    assert_eq!(
+        sv::INTERFACE_IDS,
+        [counter::sv::INTERFACE_ID, admin::sv::INTERFACE_ID]
+    );
+    assert_eq!(
+        sv::supported_interfaces(),
+        [
+            counter::sv::INTERFACE_ID.to_string(),
+            admin::sv::INTERFACE_ID.to_string()
+        ]
+    );
+
+    let msg: sv::ContractQueryMsg =
+        sylvia::cw_std::from_json(r#"{ "supported_interfaces": {} }"#).unwrap();
+    assert_eq!(
+        msg,
+        sv::ContractQueryMsg::SupportedInterfaces(SupportedInterfacesQuery::SupportedInterfaces {})
+    );
+}
+
+#[test]
+fn query_supported_interfaces() {
+    use cw_multi_test::IntoBech32;
+    use sv::mt::CodeId;
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let owner = "owner".into_bech32();
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    let querier = app.querier();
+    let querier = BoundQuerier::<_, CounterContract>::borrowed(&contract.contract_addr, &querier);
+
+    assert_eq!(
+        querier.supported_interfaces().unwrap(),
+        sv::supported_interfaces()
+    );
+    assert!(querier
+        .supports::<dyn counter::Counter<Error = StdError>>()
+        .unwrap());
+    assert!(querier
+        .supports::<dyn admin::Admin<Error = StdError, ExecC = Empty, QueryC = Empty>>()
+        .unwrap());
+    assert!(!querier
+        .supports::<dyn counter_v2::Counter<Error = StdError>>()
+        .unwrap());
+}
+
+#[test]
+fn supports_without_supported_interfaces_query() {
+    use cw_multi_test::IntoBech32;
+    use plain::sv::mt::CodeId;
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let owner = "owner".into_bech32();
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    let querier = app.querier();
+    let querier =
+        BoundQuerier::<_, plain::PlainContract>::borrowed(&contract.contract_addr, &querier);
+
+    querier.supported_interfaces().unwrap_err();
+    assert!(!querier
+        .supports::<dyn counter::Counter<Error = StdError>>()
+        .unwrap());
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "mt")]
+
+use cw_storage_plus::Item;
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Addr, Response, StdError, StdResult, Uint128};
+use sylvia::types::Remote;
+
+use crate::oracle::sv::{Executor, Querier};
+use crate::oracle::Oracle;
+
+pub mod oracle {
+    use sylvia::ctx::{ExecCtx, QueryCtx, SudoCtx};
+    use sylvia::cw_std::{Response, StdError, StdResult, Uint128};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Oracle {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn report(
+            &self,
+            ctx: ExecCtx,
+            denom: String,
+            price: Uint128,
+        ) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn price(&self, ctx: QueryCtx, denom: String) -> StdResult<Uint128>;
+
+        #[sv::msg(sudo)]
+        fn reset(&self, ctx: SudoCtx) -> StdResult<Response>;
+    }
+}
+
+pub struct Market {
+    oracle: Item<Remote<'static, dyn Oracle<Error = StdError>>>,
+}
+
+#[contract]
+impl Market {
+    pub const fn new() -> Self {
+        Self {
+            oracle: Item::new("oracle"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, oracle: Addr) -> StdResult<Response> {
+        self.oracle.save(ctx.deps.storage, &Remote::new(oracle))?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn sell(&self, ctx: ExecCtx, denom: String, price: Uint128) -> StdResult<Response> {
+        let msg = self
+            .oracle
+            .load(ctx.deps.storage)?
+            .executor()
+            .report(denom, price)?
+            .build();
+        Ok(Response::new().add_message(msg))
+    }
+
+    #[sv::msg(query)]
+    fn value(&self, ctx: QueryCtx, denom: String, amount: Uint128) -> StdResult<Uint128> {
+        let price = self
+            .oracle
+            .load(ctx.deps.storage)?
+            .querier(&ctx.deps.querier)
+            .price(denom)?;
+        Ok(price * amount)
+    }
+}
+
+mod tests {
+    use cw_multi_test::IntoBech32;
+    use sylvia::cw_std::{Response, StdError, Uint128};
+    use sylvia::multitest::App;
+
+    use crate::oracle::sv::mt::{CodeId as OracleCodeId, OracleMock, OracleProxy};
+    use crate::sv::mt::{CodeId, MarketProxy};
+
+    #[test]
+    fn stubbed_remote() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+
+        let mock = OracleMock::new();
+        mock.on_price(|denom| match denom.as_str() {
+            "atom" => Ok(Uint128::new(10)),
+            _ => Err(StdError::generic_err("Unknown denom")),
+        });
+        let oracle = OracleCodeId::store_code(&app, &mock)
+            .instantiate()
+            .call(&owner)
+            .unwrap();
+
+        let market = CodeId::store_code(&app)
+            .instantiate(oracle.contract_addr.clone())
+            .call(&owner)
+            .unwrap();
+
+        assert_eq!(
+            market.value("atom".to_owned(), Uint128::new(3)).unwrap(),
+            Uint128::new(30)
+        );
+        let err = market
+            .value("osmo".to_owned(), Uint128::new(3))
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown denom"));
+        assert_eq!(
+            mock.price_calls(),
+            [("atom".to_owned(),), ("osmo".to_owned(),)]
+        );
+
+        // Messages without the handler succeed with the empty response
+        market
+            .sell("atom".to_owned(), Uint128::new(12))
+            .call(&owner)
+            .unwrap();
+        assert_eq!(mock.report_calls(), [("atom".to_owned(), Uint128::new(12))]);
+
+        mock.on_report(|_, price| match price.is_zero() {
+            true => Err(StdError::generic_err("Zero price")),
+            false => Ok(Response::new().add_attribute("action", "report")),
+        });
+        let err = market
+            .sell("atom".to_owned(), Uint128::zero())
+            .call(&owner)
+            .unwrap_err();
+        assert!(err.to_string().contains("Zero price"));
+        assert_eq!(mock.report_calls().len(), 2);
+    }
+
+    #[test]
+    fn mock_proxy() {
+        let app = App::default();
+        let owner = "owner".into_bech32();
+
+        let mock = OracleMock::new();
+        let oracle = OracleCodeId::store_code(&app, &mock)
+            .instantiate()
+            .call(&owner)
+            .unwrap();
+
+        // Queries without the handler fail
+        let err = oracle.price("atom".to_owned()).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Response to the `price` query is not set in the mock"));
+
+        mock.on_price(|_| Ok(Uint128::new(7)));
+        assert_eq!(oracle.price("atom".to_owned()).unwrap(), Uint128::new(7));
+
+        let resp = oracle
+            .report("atom".to_owned(), Uint128::new(8))
+            .call(&owner)
+            .unwrap();
+        assert!(resp.data.is_none());
+
+        oracle.reset().unwrap();
+        oracle.reset().unwrap();
+        assert_eq!(mock.reset_calls(), [(), ()]);
+    }
+}
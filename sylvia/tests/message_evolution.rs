@@ -0,0 +1,153 @@
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{from_json, Response, StdError, StdResult, WasmQuery};
+
+pub mod counter {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        #[sv::alias("add")]
+        fn increase_by(&self, ctx: ExecCtx, amount: u32) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        #[sv::deprecated(since = "1.1.0", note = "Use `count` of the contract instead")]
+        fn total(&self, ctx: QueryCtx) -> Result<u32, Self::Error>;
+    }
+}
+
+pub struct Contract {
+    count: Item<u32>,
+}
+
+#[sylvia::contract]
+#[sv::messages(counter as Counter)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            count: Item::new("count"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        self.count.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::alias("incr", "inc")]
+    fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+        self.count
+            .update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    #[sv::alias("get_count")]
+    fn count(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.count.load(ctx.deps.storage)
+    }
+
+    #[sv::msg(query)]
+    #[sv::deprecated(note = "Use `count` instead")]
+    #[sv::alias("current")]
+    fn value(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.count.load(ctx.deps.storage)
+    }
+}
+
+impl counter::Counter for Contract {
+    type Error = StdError;
+
+    fn increase_by(&self, ctx: ExecCtx, amount: u32) -> StdResult<Response> {
+        self.count
+            .update(ctx.deps.storage, |count| -> StdResult<_> {
+                Ok(count + amount)
+            })?;
+        Ok(Response::new())
+    }
+
+    fn total(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.count.load(ctx.deps.storage)
+    }
+}
+
+#[test]
+fn aliases_are_deserialized() {
+    let msg: sv::ContractExecMsg = from_json(r#"{ "inc": {} }"#).unwrap();
+    assert_eq!(msg, sv::ContractExecMsg::Contract(sv::ExecMsg::increment()));
+
+    let msg: sv::ContractExecMsg = from_json(r#"{ "incr": {} }"#).unwrap();
+    assert_eq!(msg, sv::ContractExecMsg::Contract(sv::ExecMsg::increment()));
+
+    let msg: sv::ContractExecMsg = from_json(r#"{ "add": { "amount": 2 } }"#).unwrap();
+    assert_eq!(
+        msg,
+        sv::ContractExecMsg::Counter(counter::sv::ExecMsg::increase_by(2))
+    );
+
+    let msg: sv::ContractQueryMsg = from_json(r#"{ "get_count": {} }"#).unwrap();
+    assert_eq!(msg, sv::ContractQueryMsg::Contract(sv::QueryMsg::count()));
+
+    // Messages are still serialized with the current names
+    assert_eq!(
+        sylvia::cw_std::to_json_string(&sv::ExecMsg::increment()).unwrap(),
+        r#"{"increment":{}}"#
+    );
+
+    assert_eq!(sv::execute_messages(), ["inc", "incr", "increment"]);
+    assert_eq!(
+        sv::query_messages(),
+        ["count", "current", "get_count", "value"]
+    );
+}
+
+#[test]
+fn deprecation_in_schema() {
+    let api = sylvia::schema::contract_api::<Contract>("contract", "1.0.0")
+        .render()
+        .to_string()
+        .unwrap();
+
+    // Deprecated `value` query of the contract and `total` query of the interface
+    assert_eq!(api.matches("\"deprecated\": true").count(), 2);
+}
+
+#[test]
+#[allow(deprecated)]
+fn mt_aliases_and_deprecated_messages() {
+    use counter::sv::mt::CounterProxy;
+    use cw_multi_test::IntoBech32;
+    use sv::mt::{CodeId, ContractProxy};
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let owner = "owner".into_bech32();
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    contract.increment().call(&owner).unwrap();
+    contract.increase_by(2).call(&owner).unwrap();
+    assert_eq!(contract.value().unwrap(), 3);
+    assert_eq!(contract.total().unwrap(), 3);
+
+    // Query sent using the alias
+    let count: u32 = app
+        .querier()
+        .query(
+            &WasmQuery::Smart {
+                contract_addr: contract.contract_addr.to_string(),
+                msg: br#"{ "get_count": {} }"#.into(),
+            }
+            .into(),
+        )
+        .unwrap();
+    assert_eq!(count, 3);
+}
@@ -1,13 +1,53 @@
 #![cfg(feature = "mt")]
 
-use cw_multi_test::IntoBech32;
+use cw_multi_test::{Executor, IntoBech32};
 use std::marker::PhantomData;
 use sylvia::ctx::InstantiateCtx;
-use sylvia::cw_std::{CodeInfoResponse, Empty, Response, StdResult};
+use sylvia::cw_std::{coins, CodeInfoResponse, Empty, Response, StdResult};
 use sylvia::entry_points;
 use sylvia::multitest::App;
 use sylvia_derive::contract;
 
+use crate::counter::sv::mt::{CodeId as CounterCodeId, CounterProxy};
+
+pub mod counter {
+    use cw_storage_plus::Item;
+    use sylvia::contract;
+    use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError, StdResult};
+
+    pub struct Counter {
+        count: Item<u64>,
+    }
+
+    #[contract]
+    impl Counter {
+        pub const fn new() -> Self {
+            Self {
+                count: Item::new("count"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &0)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+            self.count
+                .update(ctx.deps.storage, |count| Ok::<_, StdError>(count + 1))?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+    }
+}
+
 pub struct SomeContract<ParamT> {
     _phantom: PhantomData<ParamT>,
 }
@@ -55,3 +95,102 @@ fn code_info() {
     let _: CodeInfoResponse = code_id.code_info().unwrap();
     let _: CodeInfoResponse = app.code_info(code_id.code_id()).unwrap();
 }
+
+#[test]
+fn snapshot_and_restore() {
+    let owner = "owner".into_bech32();
+    let recipient = "recipient".into_bech32();
+
+    let app = App::<cw_multi_test::BasicApp>::default();
+    app.app_mut().init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &owner, coins(100, "atom"))
+            .unwrap()
+    });
+    let code_id = CounterCodeId::store_code(&app);
+    let counter = code_id.instantiate().call(&owner).unwrap();
+
+    let snapshot = app.snapshot();
+    assert_eq!(snapshot.block_info(), &app.block_info());
+
+    counter.increment().call(&owner).unwrap();
+    app.app_mut()
+        .send_tokens(owner.clone(), recipient.clone(), &coins(40, "atom"))
+        .unwrap();
+    app.update_block(|block| block.height += 10);
+    let other = code_id.instantiate().call(&owner).unwrap();
+
+    // The same snapshot can be restored multiple times
+    for _ in 0..2 {
+        app.restore(&snapshot);
+
+        assert_eq!(counter.count().unwrap(), 0);
+        let balance = app.querier().query_balance(&owner, "atom").unwrap();
+        assert_eq!(balance.amount.u128(), 100);
+        let balance = app.querier().query_balance(&recipient, "atom").unwrap();
+        assert_eq!(balance.amount.u128(), 0);
+        assert_eq!(app.block_info(), *snapshot.block_info());
+        assert!(other.count().is_err());
+
+        // Contracts instantiated after restoring get the same addresses
+        let instantiated = code_id.instantiate().call(&owner).unwrap();
+        assert_eq!(instantiated.contract_addr, other.contract_addr);
+    }
+}
+
+#[test]
+fn restore_unloads_codes_stored_after_snapshot() {
+    let owner = "owner".into_bech32();
+
+    let app = App::<cw_multi_test::BasicApp>::default();
+    let code_id = CounterCodeId::store_code(&app);
+    let before = app.snapshot();
+
+    let stored_after = CounterCodeId::store_code(&app);
+    let counter = stored_after.instantiate().call(&owner).unwrap();
+    let after = app.snapshot();
+
+    app.restore(&before);
+    let err = stored_after.instantiate().call(&owner).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Code stored after the restored snapshot is not available"));
+    code_id.instantiate().call(&owner).unwrap();
+
+    // Codes are loaded back with the later snapshot
+    app.restore(&after);
+    assert_eq!(counter.count().unwrap(), 0);
+    stored_after.instantiate().call(&owner).unwrap();
+}
+
+#[test]
+fn branch() {
+    let owner = "owner".into_bech32();
+
+    let app = App::default();
+    let counter = CounterCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+    counter.increment().call(&owner).unwrap();
+
+    let count = app.branch(|_| {
+        counter.increment().call(&owner).unwrap();
+        counter.increment().call(&owner).unwrap();
+        counter.count().unwrap()
+    });
+    assert_eq!(count, 3);
+    assert_eq!(counter.count().unwrap(), 1);
+
+    // State is restored even if the branch panics
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        app.branch(|app| {
+            counter.increment().call(&owner).unwrap();
+            app.update_block(|block| block.height += 1);
+            panic!("Branch failed");
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(counter.count().unwrap(), 1);
+}
@@ -0,0 +1,186 @@
+use cosmwasm_std::testing::{MockApi, MockStorage};
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Empty, Response, StdError, StdResult};
+use sylvia::ownable::{self, Ownable, Ownership};
+
+pub struct OwnedContract {
+    counter: Item<u32>,
+}
+
+#[sylvia::contract]
+#[sv::messages(sylvia::ownable as Ownable)]
+impl OwnedContract {
+    pub const fn new() -> Self {
+        Self {
+            counter: Item::new("counter"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        ownable::initialize_owner(
+            ctx.deps.storage,
+            ctx.deps.api,
+            Some(ctx.info.sender.as_str()),
+        )?;
+        self.counter.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+        ownable::ensure_owner(&ctx)?;
+        self.counter
+            .update(ctx.deps.storage, |c| -> StdResult<_> { Ok(c + 1) })?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::guard(ownable::only_owner)]
+    fn reset(&self, ctx: ExecCtx) -> StdResult<Response> {
+        self.counter.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn counter(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.counter.load(ctx.deps.storage)
+    }
+}
+
+impl Ownable for OwnedContract {
+    type Error = StdError;
+    type ExecC = Empty;
+    type QueryC = Empty;
+}
+
+#[test]
+fn two_step_transfer() {
+    use sv::mt::{CodeId, OwnedContractProxy};
+    use sylvia::ownable::sv::mt::OwnableProxy;
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let owner = MockApi::default().addr_make("owner");
+    let new_owner = MockApi::default().addr_make("new_owner");
+    let stranger = MockApi::default().addr_make("stranger");
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+    assert_eq!(
+        contract.ownership().unwrap(),
+        Ownership {
+            owner: Some(owner.clone()),
+            pending_owner: None,
+        }
+    );
+
+    let err = contract
+        .transfer_ownership(new_owner.to_string())
+        .call(&stranger)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Caller is not the contract owner")
+    );
+
+    let err = contract.accept_ownership().call(&new_owner).unwrap_err();
+    assert_eq!(err, StdError::generic_err("No pending ownership transfer"));
+
+    contract
+        .transfer_ownership(new_owner.to_string())
+        .call(&owner)
+        .unwrap();
+    assert_eq!(
+        contract.ownership().unwrap(),
+        Ownership {
+            owner: Some(owner.clone()),
+            pending_owner: Some(new_owner.clone()),
+        }
+    );
+
+    // Ownership is not transferred until accepted
+    contract.increment().call(&owner).unwrap();
+    let err = contract.increment().call(&new_owner).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Caller is not the contract owner")
+    );
+
+    let err = contract.accept_ownership().call(&stranger).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Caller is not the pending owner")
+    );
+
+    contract.accept_ownership().call(&new_owner).unwrap();
+    assert_eq!(
+        contract.ownership().unwrap(),
+        Ownership {
+            owner: Some(new_owner.clone()),
+            pending_owner: None,
+        }
+    );
+
+    let err = contract.reset().call(&owner).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Caller is not the contract owner")
+    );
+    contract.increment().call(&new_owner).unwrap();
+    assert_eq!(contract.counter().unwrap(), 2);
+    contract.reset().call(&new_owner).unwrap();
+    assert_eq!(contract.counter().unwrap(), 0);
+}
+
+#[test]
+fn renounce_ownership() {
+    use sv::mt::{CodeId, OwnedContractProxy};
+    use sylvia::ownable::sv::mt::OwnableProxy;
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let owner = MockApi::default().addr_make("owner");
+    let new_owner = MockApi::default().addr_make("new_owner");
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+    contract
+        .transfer_ownership(new_owner.to_string())
+        .call(&owner)
+        .unwrap();
+
+    let err = contract.renounce_ownership().call(&new_owner).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Caller is not the contract owner")
+    );
+
+    contract.renounce_ownership().call(&owner).unwrap();
+    assert_eq!(contract.ownership().unwrap(), Ownership::default());
+
+    // Pending transfer is cancelled with the renounce
+    let err = contract.accept_ownership().call(&new_owner).unwrap_err();
+    assert_eq!(err, StdError::generic_err("No pending ownership transfer"));
+
+    let err = contract.increment().call(&owner).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Contract ownership has been renounced")
+    );
+}
+
+#[test]
+fn ownership_does_not_collide_with_contract_state() {
+    let mut storage = MockStorage::new();
+    let ownership = Item::<String>::new("ownership");
+    ownership.save(&mut storage, &"state".to_owned()).unwrap();
+    assert!(!ownable::has_owner(&storage));
+
+    let owner = MockApi::default().addr_make("owner");
+    ownable::initialize_owner(&mut storage, &MockApi::default(), Some(owner.as_str())).unwrap();
+    assert!(ownable::has_owner(&storage));
+    assert_eq!(ownable::get_ownership(&storage).unwrap().owner, Some(owner));
+    assert_eq!(ownership.load(&storage).unwrap(), "state");
+}
@@ -0,0 +1,233 @@
+use cw_storage_plus::{Bound, Map};
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_schema::cw_serde;
+use sylvia::cw_std::{Order, Response, StdError, StdResult};
+use sylvia::types::{Page, Paginated};
+
+#[cw_serde]
+pub struct Member {
+    pub name: String,
+    pub weight: u64,
+}
+
+#[cw_serde]
+pub struct TagsResponse {
+    pub tags: Vec<(u32, String)>,
+}
+
+impl Paginated for TagsResponse {
+    type Item = (u32, String);
+    type Cursor = u32;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<Self::Cursor>) {
+        let next = self.tags.last().map(|(id, _)| *id);
+        (self.tags, next)
+    }
+}
+
+pub mod tags {
+    use sylvia::ctx::QueryCtx;
+    use sylvia::cw_std::StdError;
+    use sylvia::interface;
+
+    use crate::TagsResponse;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Tags {
+        type Error: From<StdError>;
+
+        #[sv::msg(query, paginated(max = 4))]
+        fn tags(
+            &self,
+            ctx: QueryCtx,
+            prefix: String,
+            start_after: Option<u32>,
+            limit: u32,
+        ) -> Result<TagsResponse, Self::Error>;
+    }
+}
+
+pub struct Contract {
+    members: Map<String, u64>,
+    tags: Map<u32, String>,
+}
+
+#[sylvia::contract]
+#[sv::messages(tags)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            members: Map::new("members"),
+            tags: Map::new("tags"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn add_member(&self, ctx: ExecCtx, name: String, weight: u64) -> StdResult<Response> {
+        self.members.save(ctx.deps.storage, name, &weight)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn add_tag(&self, ctx: ExecCtx, id: u32, tag: String) -> StdResult<Response> {
+        self.tags.save(ctx.deps.storage, id, &tag)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query, paginated(default = 2, max = 3))]
+    fn list_members(
+        &self,
+        ctx: QueryCtx,
+        start_after: Option<String>,
+        limit: u32,
+    ) -> StdResult<Page<Member, String>> {
+        let members = self
+            .members
+            .range(
+                ctx.deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit as usize)
+            .map(|member| member.map(|(name, weight)| Member { name, weight }))
+            .collect::<StdResult<_>>()?;
+        Ok(Page::from_items(members, limit, |member| {
+            member.name.clone()
+        }))
+    }
+}
+
+impl tags::Tags for Contract {
+    type Error = StdError;
+
+    fn tags(
+        &self,
+        ctx: QueryCtx,
+        prefix: String,
+        start_after: Option<u32>,
+        limit: u32,
+    ) -> StdResult<TagsResponse> {
+        let tags = self
+            .tags
+            .range(
+                ctx.deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter(|tag| {
+                tag.as_ref()
+                    .map_or(true, |(_, tag)| tag.starts_with(&prefix))
+            })
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+        Ok(TagsResponse { tags })
+    }
+}
+
+#[test]
+fn limit_is_optional_in_message() {
+    let api = sylvia::schema::contract_api::<Contract>("contract", "1.0.0")
+        .render()
+        .to_string()
+        .unwrap();
+    assert!(api.contains("\"title\": \"Page_for_Member_and_String\""));
+
+    let msg: sv::QueryMsg = sylvia::cw_std::from_json(r#"{"list_members":{}}"#).unwrap();
+    assert_eq!(msg, sv::QueryMsg::list_members(None, None));
+}
+
+#[cfg(all(test, feature = "mt"))]
+mod tests {
+    use cw_multi_test::IntoBech32;
+    use sylvia::cw_std::{StdError, StdResult};
+    use sylvia::multitest::App;
+    use sylvia::types::BoundQuerier;
+
+    use crate::sv::mt::{CodeId, ContractProxy};
+    use crate::sv::Querier;
+    use crate::tags::sv::mt::TagsProxy;
+    use crate::Contract;
+
+    #[test]
+    fn query_pages() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_bech32();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+        for (weight, name) in ["alice", "bob", "carol", "dave", "eve"].iter().enumerate() {
+            contract
+                .add_member(name.to_string(), weight as u64)
+                .call(&owner)
+                .unwrap();
+        }
+
+        // Default limit is used if none is given
+        let page = contract.list_members(None, None).unwrap();
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.next_start_after, Some("bob".to_owned()));
+
+        // Limit is clamped to the max
+        let page = contract
+            .list_members(Some("bob".to_owned()), Some(10))
+            .unwrap();
+        let names: Vec<_> = page.items.iter().map(|member| &member.name).collect();
+        assert_eq!(names, ["carol", "dave", "eve"]);
+        assert_eq!(page.next_start_after, Some("eve".to_owned()));
+
+        let names = contract
+            .list_members_iter()
+            .map(|member| member.map(|member| member.name))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(names, ["alice", "bob", "carol", "dave", "eve"]);
+
+        let querier = app.querier();
+        let querier = BoundQuerier::<_, Contract>::borrowed(&contract.contract_addr, &querier);
+        let weights: u64 = querier
+            .list_members_iter()
+            .map(|member| member.map(|member| member.weight))
+            .sum::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(weights, 10);
+    }
+
+    #[test]
+    fn query_interface_pages() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_bech32();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+        for id in 1..=10 {
+            let tag = match id % 2 {
+                0 => format!("even-{id}"),
+                _ => format!("odd-{id}"),
+            };
+            contract.add_tag(id, tag).call(&owner).unwrap();
+        }
+
+        let page = contract.tags("odd".to_owned(), None, Some(10)).unwrap();
+        assert_eq!(page.tags.len(), 4);
+
+        let ids = contract
+            .tags_iter("even".to_owned())
+            .map(|tag| tag.map(|(id, _)| id))
+            .collect::<Result<Vec<_>, StdError>>()
+            .unwrap();
+        assert_eq!(ids, [2, 4, 6, 8, 10]);
+
+        let querier = app.querier();
+        let querier = BoundQuerier::<_, Contract>::borrowed(&contract.contract_addr, &querier);
+        let count = crate::tags::sv::Querier::tags_iter(&querier, "odd".to_owned()).count();
+        assert_eq!(count, 5);
+    }
+}
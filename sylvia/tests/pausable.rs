@@ -0,0 +1,192 @@
+use cosmwasm_std::testing::{MockApi, MockStorage};
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Empty, Response, StdError, StdResult};
+use sylvia::ownable::{self, Ownable};
+// The `Pausable` interface is recognized by its id, so it can be listed under an alias.
+use sylvia::pausable as pause;
+use sylvia::pausable::{AllowWhenPaused, Pausable};
+
+pub struct PausableContract {
+    counter: Item<u32>,
+}
+
+#[sylvia::contract]
+#[sv::features(pausable)]
+#[sv::messages(sylvia::ownable as Ownable)]
+#[sv::messages(pause as Pausable)]
+impl PausableContract {
+    pub const fn new() -> Self {
+        Self {
+            counter: Item::new("counter"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        ownable::initialize_owner(
+            ctx.deps.storage,
+            ctx.deps.api,
+            Some(ctx.info.sender.as_str()),
+        )?;
+        self.counter.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+        self.counter
+            .update(ctx.deps.storage, |c| -> StdResult<_> { Ok(c + 1) })?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::allow_when_paused]
+    fn reset(&self, ctx: ExecCtx) -> StdResult<Response> {
+        self.counter.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn counter(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.counter.load(ctx.deps.storage)
+    }
+}
+
+impl Ownable for PausableContract {
+    type Error = StdError;
+    type ExecC = Empty;
+    type QueryC = Empty;
+}
+
+impl Pausable for PausableContract {
+    type Error = StdError;
+    type ExecC = Empty;
+    type QueryC = Empty;
+}
+
+#[test]
+fn allowed_when_paused_messages() {
+    assert!(!sv::ExecMsg::increment().allowed_when_paused());
+    assert!(sv::ExecMsg::reset().allowed_when_paused());
+    assert!(sylvia::pausable::sv::ExecMsg::pause().allowed_when_paused());
+    assert!(sylvia::pausable::sv::ExecMsg::unpause().allowed_when_paused());
+
+    let msg: sv::ContractExecMsg = sylvia::pausable::sv::ExecMsg::pause().into();
+    assert!(msg.allowed_when_paused());
+    let msg: sv::ContractExecMsg = sv::ExecMsg::increment().into();
+    assert!(!msg.allowed_when_paused());
+    // Interface without `sv::allow_when_paused` methods
+    let msg: sv::ContractExecMsg = sylvia::ownable::sv::ExecMsg::accept_ownership().into();
+    assert!(!msg.allowed_when_paused());
+}
+
+#[test]
+fn paused_state_does_not_collide_with_contract_state() {
+    let mut storage = MockStorage::new();
+    let paused = Item::<bool>::new("paused");
+    paused.save(&mut storage, &false).unwrap();
+
+    sylvia::pausable::set_paused(&mut storage, true).unwrap();
+    assert!(sylvia::pausable::is_paused(&storage).unwrap());
+    assert!(!paused.load(&storage).unwrap());
+}
+
+#[test]
+fn paused_contract_rejects_exec() {
+    use sv::mt::{CodeId, PausableContractProxy};
+    use sylvia::ownable::sv::mt::OwnableProxy;
+    use sylvia::pausable::sv::mt::PausableProxy;
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let owner = MockApi::default().addr_make("owner");
+    let stranger = MockApi::default().addr_make("stranger");
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+    assert!(!contract.paused().unwrap());
+
+    let err = contract.pause().call(&stranger).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Caller is not the contract owner")
+    );
+
+    contract.increment().call(&owner).unwrap();
+    contract.pause().call(&owner).unwrap();
+    assert!(contract.paused().unwrap());
+
+    let err = contract.increment().call(&owner).unwrap_err();
+    assert_eq!(err, StdError::generic_err("Contract is paused"));
+    assert_eq!(contract.counter().unwrap(), 1);
+
+    // Interface messages are also rejected
+    let err = contract
+        .transfer_ownership(stranger.to_string())
+        .call(&owner)
+        .unwrap_err();
+    assert_eq!(err, StdError::generic_err("Contract is paused"));
+
+    // Message marked with `sv::allow_when_paused`
+    contract.reset().call(&owner).unwrap();
+    assert_eq!(contract.counter().unwrap(), 0);
+
+    let err = contract.unpause().call(&stranger).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Caller is not the contract owner")
+    );
+
+    contract.unpause().call(&owner).unwrap();
+    assert!(!contract.paused().unwrap());
+    contract.increment().call(&owner).unwrap();
+    assert_eq!(contract.counter().unwrap(), 1);
+}
+
+pub mod unowned {
+    use sylvia::ctx::InstantiateCtx;
+    use sylvia::cw_std::{Empty, Response, StdError, StdResult};
+    use sylvia::pausable::Pausable;
+
+    pub struct UnownedContract;
+
+    #[sylvia::contract]
+    #[sv::features(pausable)]
+    #[sv::messages(sylvia::pausable as Pausable)]
+    impl UnownedContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+
+    impl Pausable for UnownedContract {
+        type Error = StdError;
+        type ExecC = Empty;
+        type QueryC = Empty;
+    }
+}
+
+#[test]
+fn default_pauser_requires_ownership() {
+    use sylvia::pausable::sv::mt::PausableProxy;
+    use unowned::sv::mt::CodeId;
+
+    let app = sylvia::multitest::App::default();
+    let code_id = CodeId::store_code(&app);
+    let owner = MockApi::default().addr_make("owner");
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+    let err = contract.pause().call(&owner).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(
+            "Pausable requires the owner set with `sylvia::ownable::initialize_owner` or an overridden `ensure_pauser`"
+        )
+    );
+}
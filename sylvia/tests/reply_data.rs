@@ -5,9 +5,9 @@ use noop_contract::sv::{Executor, NoopContractInstantiateBuilder};
 use sv::SubMsgMethods;
 use sylvia::builder::instantiate::InstantiateBuilder;
 use sylvia::ctx::{ExecCtx, InstantiateCtx, ReplyCtx};
-use sylvia::cw_std::{Addr, Binary, Response, StdError, SubMsg};
+use sylvia::cw_std::{Addr, Binary, Event, Response, StdError, SubMsg};
 use sylvia::cw_utils::{MsgInstantiateContractResponse, ParseReplyError};
-use sylvia::types::Remote;
+use sylvia::types::{InstantiateReplyData, Remote};
 use sylvia::{contract, entry_points};
 use thiserror::Error;
 
@@ -215,6 +215,29 @@ impl Contract {
         Ok(Response::new())
     }
 
+    #[sv::msg(reply, reply_on=success)]
+    fn typed_instantiate(
+        &self,
+        _ctx: ReplyCtx,
+        #[sv::data(instantiate = ComplexData)] data: InstantiateReplyData<ComplexData>,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> Result<Response, ContractError> {
+        Ok(Response::new()
+            .add_attribute("contract_address", data.contract_address)
+            .add_attribute("message", data.data.message))
+    }
+
+    #[sv::msg(reply, reply_on=success)]
+    fn typed_exec(
+        &self,
+        _ctx: ReplyCtx,
+        #[sv::data(exec = ComplexData, opt)] data: Option<ComplexData>,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> Result<Response, ContractError> {
+        let number = data.map(|data| data.number).unwrap_or_default();
+        Ok(Response::new().add_attribute("number", number.to_string()))
+    }
+
     #[sv::msg(reply, reply_on=success)]
     fn no_data(
         &self,
@@ -227,7 +250,10 @@ impl Contract {
 
 use crate::noop_contract::sv::mt::CodeId as NoopCodeId;
 use crate::sv::mt::{CodeId, ContractProxy};
-use crate::sv::{DATA_OPT_REPLY_ID, DATA_RAW_OPT_REPLY_ID, DATA_RAW_REPLY_ID, DATA_REPLY_ID};
+use crate::sv::{
+    DATA_OPT_REPLY_ID, DATA_RAW_OPT_REPLY_ID, DATA_RAW_REPLY_ID, DATA_REPLY_ID,
+    TYPED_EXEC_REPLY_ID, TYPED_INSTANTIATE_REPLY_ID,
+};
 
 use sylvia::cw_multi_test::IntoBech32;
 use sylvia::multitest::App;
@@ -397,3 +423,119 @@ fn data() {
         .call(&owner)
         .unwrap();
 }
+
+#[test]
+fn typed_exec_data() {
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let noop_code_id = NoopCodeId::store_code(&app);
+
+    let owner = "owner".into_bech32();
+    let data = ComplexData {
+        message: "Hello".to_string(),
+        number: 42,
+    };
+
+    // Trigger remote instantiation reply
+    let contract = code_id
+        .instantiate(noop_code_id.code_id())
+        .with_label("Contract")
+        .call(&owner)
+        .unwrap();
+
+    contract
+        .send_message_expecting_data(None, TYPED_EXEC_REPLY_ID)
+        .call(&owner)
+        .unwrap();
+
+    let resp = contract
+        .send_message_expecting_data(Some(to_json_binary(&data).unwrap()), TYPED_EXEC_REPLY_ID)
+        .call(&owner)
+        .unwrap();
+    assert!(resp.has_event(&Event::new("wasm").add_attribute("number", "42")));
+}
+
+/// Encodes the `MsgInstantiateContractResponse` protobuf message.
+fn instantiate_response(contract_address: &str, data: &[u8]) -> Binary {
+    let mut encoded = vec![0x0a, contract_address.len() as u8];
+    encoded.extend_from_slice(contract_address.as_bytes());
+    encoded.extend_from_slice(&[0x12, data.len() as u8]);
+    encoded.extend_from_slice(data);
+    Binary::new(encoded)
+}
+
+#[test]
+fn typed_instantiate_data_from_msg_responses() {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{MsgResponse, Reply, SubMsgResponse, SubMsgResult};
+
+    let mut deps = mock_dependencies();
+    let data = ComplexData {
+        message: "Hello".to_string(),
+        number: 42,
+    };
+    let reply = |data: Option<Binary>, msg_responses: Vec<MsgResponse>| {
+        #[allow(deprecated)]
+        let result = SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data,
+            msg_responses,
+        });
+        Reply {
+            id: TYPED_INSTANTIATE_REPLY_ID,
+            payload: Binary::default(),
+            gas_used: 0,
+            result,
+        }
+    };
+    let response = instantiate_response("contract1", to_json_binary(&data).unwrap().as_slice());
+
+    // Data is read from the `msg_responses` if missing in the `data` field
+    let msg_responses = vec![MsgResponse {
+        type_url: "/cosmwasm.wasm.v1.MsgInstantiateContractResponse".to_owned(),
+        value: response.clone(),
+    }];
+    let resp = sv::dispatch_reply(
+        deps.as_mut(),
+        mock_env(),
+        reply(None, msg_responses),
+        Contract::new(),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, "contract1");
+    assert_eq!(resp.attributes[1].value, "Hello");
+
+    let resp = sv::dispatch_reply(
+        deps.as_mut(),
+        mock_env(),
+        reply(Some(response), vec![]),
+        Contract::new(),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, "contract1");
+
+    let err = sv::dispatch_reply(
+        deps.as_mut(),
+        mock_env(),
+        reply(None, vec![]),
+        Contract::new(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Missing reply data field.").into()
+    );
+
+    // Instantiated contract didn't set the data
+    let err = sv::dispatch_reply(
+        deps.as_mut(),
+        mock_env(),
+        reply(Some(instantiate_response("contract1", &[])), vec![]),
+        Contract::new(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Missing reply data field.").into()
+    );
+}
@@ -362,5 +362,15 @@ mod tests {
             err,
             StdError::generic_err(format!("Unknown reply id: {}.", unknown_reply_id)).into()
         );
+
+        // Should return error if payload doesn't match the handler parameters
+        let err = contract
+            .call_remote_unknown_id(false, ALWAYS_REPLY_ID)
+            .call(&owner)
+            .unwrap_err();
+        assert!(err.to_string().starts_with(&format!(
+            "Generic error: Invalid reply payload for reply id: {}. Expected payload of type `(u32, String)`.",
+            ALWAYS_REPLY_ID
+        )));
     }
 }
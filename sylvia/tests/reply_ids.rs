@@ -0,0 +1,100 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{to_json_binary, Binary, Reply, SubMsgResult, WasmMsg};
+use sylvia::ctx::{InstantiateCtx, ReplyCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::utils::reply_id_hash;
+use sylvia::{contract, entry_points};
+
+pub struct Contract;
+
+#[entry_points]
+#[contract]
+#[sv::features(replies)]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(reply, reply_on = success, id = 42)]
+    fn pinned(&self, _ctx: ReplyCtx, value: u32) -> StdResult<Response> {
+        Ok(Response::new().add_attribute("pinned_success", value.to_string()))
+    }
+
+    #[sv::msg(reply, handlers=[pinned], reply_on = error, id = 42)]
+    fn pinned_failure(&self, _ctx: ReplyCtx, error: String, _value: u32) -> StdResult<Response> {
+        Ok(Response::new().add_attribute("pinned_error", error))
+    }
+
+    #[sv::msg(reply)]
+    fn unpinned(
+        &self,
+        _ctx: ReplyCtx,
+        _result: SubMsgResult,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> StdResult<Response> {
+        Ok(Response::new().add_attribute("unpinned", "called"))
+    }
+
+    #[sv::msg(reply, handlers=[hashed_one, hashed_two], id = hash)]
+    fn hashed(
+        &self,
+        _ctx: ReplyCtx,
+        _result: SubMsgResult,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> StdResult<Response> {
+        Ok(Response::new().add_attribute("hashed", "called"))
+    }
+}
+
+#[test]
+fn pinned_reply_ids() {
+    assert_eq!(sv::PINNED_REPLY_ID, 42);
+    assert_eq!(sv::UNPINNED_REPLY_ID, 1);
+    assert_eq!(sv::HASHED_ONE_REPLY_ID, reply_id_hash("hashed_one"));
+    assert_eq!(sv::HASHED_TWO_REPLY_ID, reply_id_hash("hashed_two"));
+
+    assert_eq!(
+        sv::reply_ids(),
+        [
+            ("pinned", 42),
+            ("unpinned", 1),
+            ("hashed_one", reply_id_hash("hashed_one")),
+            ("hashed_two", reply_id_hash("hashed_two")),
+        ]
+    );
+}
+
+#[test]
+fn dispatch_pinned_reply_ids() {
+    use sv::SubMsgMethods;
+
+    let msg = WasmMsg::ClearAdmin {
+        contract_addr: "contract".to_owned(),
+    };
+    let sub_msg = SubMsgMethods::<cosmwasm_std::Empty>::pinned(msg, 7).unwrap();
+    assert_eq!(sub_msg.id, 42);
+
+    let mut deps = mock_dependencies();
+    let reply = Reply {
+        id: 42,
+        payload: to_json_binary(&7u32).unwrap(),
+        gas_used: 0,
+        result: SubMsgResult::Err("failure".to_owned()),
+    };
+    let resp = sv::dispatch_reply(deps.as_mut(), mock_env(), reply, Contract::new()).unwrap();
+    assert_eq!(resp.attributes[0].key, "pinned_error");
+
+    let reply = Reply {
+        id: reply_id_hash("hashed_two"),
+        payload: Binary::default(),
+        gas_used: 0,
+        result: SubMsgResult::Err("failure".to_owned()),
+    };
+    let resp = sv::dispatch_reply(deps.as_mut(), mock_env(), reply, Contract::new()).unwrap();
+    assert_eq!(resp.attributes[0].key, "hashed");
+}
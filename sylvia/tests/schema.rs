@@ -0,0 +1,160 @@
+use sylvia::ctx::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx, SudoCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+use sylvia::schema::{contract_api, write_contract_api_into};
+
+pub mod counter {
+    use sylvia::ctx::{QueryCtx, SudoCtx};
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> Result<u32, Self::Error>;
+
+        #[sv::msg(sudo)]
+        fn reset_count(&self, ctx: SudoCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod full {
+    use super::*;
+
+    pub struct FullContract;
+
+    #[sylvia::contract]
+    #[sv::features(supported_interfaces)]
+    #[sv::messages(crate::counter as Counter)]
+    impl FullContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx, _admin: String) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn admin(&self, _ctx: QueryCtx) -> StdResult<String> {
+            Ok(String::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, _ctx: MigrateCtx, _new_admin: String) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+
+    impl crate::counter::Counter for FullContract {
+        type Error = StdError;
+
+        fn count(&self, _ctx: QueryCtx) -> StdResult<u32> {
+            Ok(0)
+        }
+
+        fn reset_count(&self, _ctx: SudoCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+pub mod minimal {
+    use super::*;
+
+    pub struct MinimalContract;
+
+    #[sylvia::contract]
+    impl MinimalContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn value(&self, _ctx: QueryCtx) -> StdResult<u64> {
+            Ok(0)
+        }
+    }
+}
+
+fn schema_files(api: sylvia::cw_schema::Api) -> Vec<String> {
+    api.render()
+        .to_schema_files()
+        .unwrap()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+#[test]
+fn full_contract_api() {
+    let api = contract_api::<full::FullContract>("full-contract", "1.2.3");
+    assert_eq!(api.contract_name, "full-contract");
+    assert_eq!(api.contract_version, "1.2.3");
+
+    assert_eq!(
+        schema_files(api),
+        [
+            "instantiate.json",
+            "execute.json",
+            "query.json",
+            "migrate.json",
+            "sudo.json",
+            "response_to_admin.json",
+            "response_to_count.json",
+            "response_to_supported_interfaces.json",
+        ]
+    );
+
+    let api = contract_api::<full::FullContract>("full-contract", "1.2.3")
+        .render()
+        .to_string()
+        .unwrap();
+    assert!(api.contains("\"title\": \"MigrateMsg\""));
+    assert!(api.contains("\"title\": \"SudoMsg\""));
+    assert!(api.contains("\"reset_count\""));
+    assert!(api.contains("\"increment\""));
+}
+
+#[test]
+fn minimal_contract_api() {
+    let api = contract_api::<minimal::MinimalContract>("minimal-contract", "0.1.0");
+    assert!(api.migrate.is_none());
+    assert!(api.sudo.is_none());
+
+    assert_eq!(
+        schema_files(api),
+        [
+            "instantiate.json",
+            "execute.json",
+            "query.json",
+            "response_to_value.json",
+        ]
+    );
+}
+
+#[test]
+fn write_schema_files() {
+    let out_dir = std::env::temp_dir().join(format!("sylvia-schema-{}", std::process::id()));
+    write_contract_api_into::<full::FullContract>(&out_dir, "full-contract", "1.2.3");
+
+    assert!(out_dir.join("full-contract.json").is_file());
+    assert!(out_dir.join("raw").join("sudo.json").is_file());
+    assert!(out_dir.join("raw").join("migrate.json").is_file());
+    assert!(out_dir.join("raw").join("response_to_count.json").is_file());
+
+    std::fs::remove_dir_all(out_dir).unwrap();
+}
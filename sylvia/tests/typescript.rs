@@ -0,0 +1,172 @@
+#![cfg(feature = "typescript")]
+use cosmwasm_schema::cw_serde;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Addr, Response, StdError, StdResult, Uint128};
+use sylvia::typescript::{contract_module, TsClient, TsGenerator, TsMethod, TypeScriptApi};
+
+pub mod counter {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn increase_by(&self, ctx: ExecCtx, amount: u32) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> Result<u64, Self::Error>;
+    }
+}
+
+/// Balance of the account.
+#[cw_serde]
+pub struct BalanceResponse {
+    /// Owner of the balance.
+    pub owner: Addr,
+    pub amount: Uint128,
+    pub frozen: Option<bool>,
+}
+
+pub struct TokenContract;
+
+#[sylvia::contract]
+#[sv::messages(counter as Counter)]
+impl TokenContract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx, admin: String) -> StdResult<Response> {
+        let _ = admin;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn transfer(
+        &self,
+        _ctx: ExecCtx,
+        recipient: String,
+        amount: Uint128,
+        memos: Vec<String>,
+    ) -> StdResult<Response> {
+        let _ = (recipient, amount, memos);
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn freeze(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn balance(&self, _ctx: QueryCtx, owner: String) -> StdResult<BalanceResponse> {
+        Ok(BalanceResponse {
+            owner: Addr::unchecked(owner),
+            amount: Uint128::zero(),
+            frozen: None,
+        })
+    }
+}
+
+impl counter::Counter for TokenContract {
+    type Error = StdError;
+
+    fn increase_by(&self, _ctx: ExecCtx, _amount: u32) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn count(&self, _ctx: QueryCtx) -> StdResult<u64> {
+        Ok(0)
+    }
+}
+
+#[test]
+fn clients_mirror_messages() {
+    let mut ts_gen = TsGenerator::default();
+    let clients = TokenContract::ts_clients(&mut ts_gen);
+
+    assert_eq!(
+        clients,
+        [
+            TsClient::new(
+                "TokenContract",
+                vec![TsMethod::new(
+                    "balance",
+                    vec![("owner", "string".to_owned())],
+                    Some("BalanceResponse".to_owned())
+                )],
+                vec![
+                    TsMethod::new(
+                        "transfer",
+                        vec![
+                            ("recipient", "string".to_owned()),
+                            ("amount", "Uint128".to_owned()),
+                            ("memos", "string[]".to_owned()),
+                        ],
+                        None
+                    ),
+                    TsMethod::new("freeze", vec![], None),
+                ]
+            ),
+            TsClient::new(
+                "Counter",
+                vec![TsMethod::new("count", vec![], Some("number".to_owned()))],
+                vec![TsMethod::new(
+                    "increase_by",
+                    vec![("amount", "number".to_owned())],
+                    None
+                )],
+            ),
+        ]
+    );
+}
+
+#[test]
+fn contract_module_content() {
+    let module = contract_module::<TokenContract>();
+
+    assert!(module.contains("from \"@cosmjs/cosmwasm-stargate\""));
+    assert!(module.contains(
+        "/**\n * Balance of the account.\n */\nexport interface BalanceResponse {\n  amount: Uint128;\n  frozen?: boolean | null;\n  /**\n   * Owner of the balance.\n   */\n  owner: Addr;\n}"
+    ));
+    assert!(module.contains("export type Uint128 = string;"));
+    assert!(module.contains("export interface InstantiateMsg {\n  admin: string;\n}"));
+    assert!(module.contains(
+        "export type TokenContractExecMsg = { transfer: { recipient: string; amount: Uint128; memos: string[] } } | { freeze: {} };"
+    ));
+    assert!(module.contains("export type CounterQueryMsg = { count: {} };"));
+
+    assert!(module.contains(
+        "  async balance(owner: string): Promise<BalanceResponse> {\n    return this.client.queryContractSmart(this.contractAddress, { balance: { owner: owner } });\n  }"
+    ));
+    assert!(module.contains("export class TokenContractClient extends TokenContractQueryClient {"));
+    assert!(module.contains(
+        "  async increaseBy(amount: number, options: { funds?: readonly Coin[]; fee?: StdFee | \"auto\" | number; memo?: string } = {}): Promise<ExecuteResult> {\n    return this.signingClient.execute(this.sender, this.contractAddress, { increase_by: { amount: amount } }, options.fee ?? \"auto\", options.memo, options.funds);\n  }"
+    ));
+}
+
+#[test]
+fn exec_options_do_not_collide_with_fields() {
+    let client = TsClient::new(
+        "Vault",
+        vec![],
+        vec![TsMethod::new(
+            "deposit",
+            vec![
+                ("funds", "Coin[]".to_owned()),
+                ("memo", "string".to_owned()),
+                ("options", "string[]".to_owned()),
+            ],
+            None,
+        )],
+    );
+
+    assert!(client.emit().contains(
+        "  async deposit(funds: Coin[], memo: string, options: string[], options_: { funds?: readonly Coin[]; fee?: StdFee | \"auto\" | number; memo?: string } = {}): Promise<ExecuteResult> {\n    return this.signingClient.execute(this.sender, this.contractAddress, { deposit: { funds: funds, memo: memo, options: options } }, options_.fee ?? \"auto\", options_.memo, options_.funds);\n  }"
+    ));
+}
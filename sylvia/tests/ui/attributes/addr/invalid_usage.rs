@@ -0,0 +1,30 @@
+#![allow(unused_imports)]
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Addr, Response, StdResult};
+
+pub struct Contract;
+
+#[sylvia::contract]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx, #[sv::addr] _admin: String) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::addr]
+    fn exec(&self, _ctx: ExecCtx, #[sv::addr(checked)] _addr: Addr) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn query(&self, _ctx: QueryCtx, #[sv::addr] _addrs: Option<Vec<u32>>) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
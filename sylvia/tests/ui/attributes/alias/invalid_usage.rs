@@ -0,0 +1,37 @@
+#![allow(unused_imports)]
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+pub struct Contract;
+
+#[sylvia::contract]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    #[sv::alias("init")]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::alias("other_exec")]
+    fn exec(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn other_exec(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    #[sv::alias]
+    fn query(&self, _ctx: QueryCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
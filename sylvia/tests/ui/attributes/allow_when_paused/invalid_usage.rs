@@ -0,0 +1,34 @@
+#![allow(unused_imports)]
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+pub struct Contract;
+
+#[sylvia::contract]
+#[sv::features(pausable)]
+#[sv::messages(sylvia::pausable as Pausable)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    #[sv::allow_when_paused]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    #[sv::allow_when_paused]
+    fn query(&self, _ctx: QueryCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::allow_when_paused(always)]
+    fn exec(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+#![deny(deprecated)]
+use sylvia::ctx::{InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Addr, QuerierWrapper, Response, StdResult};
+use sylvia::types::BoundQuerier;
+
+pub struct Contract;
+
+#[sylvia::contract]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    #[sv::deprecated(note = "Use `count` instead")]
+    fn value(&self, _ctx: QueryCtx) -> StdResult<u32> {
+        Ok(0)
+    }
+}
+
+fn query_value(addr: &Addr, querier: &QuerierWrapper) -> StdResult<u32> {
+    use sv::Querier;
+
+    BoundQuerier::<_, Contract>::borrowed(addr, querier).value()
+}
+
+fn main() {
+    let _ = sv::QueryMsg::value();
+}
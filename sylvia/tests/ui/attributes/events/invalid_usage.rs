@@ -0,0 +1,35 @@
+#![allow(unused_imports)]
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+#[sylvia::event]
+pub struct Frozen;
+
+pub struct Contract;
+
+#[sylvia::contract]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    #[sv::events]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::events()]
+    fn exec(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    #[sv::events(Frozen)]
+    fn query(&self, _ctx: QueryCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::types::Page;
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec, paginated)]
+    fn exec(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query, paginated(default = 50, max = 10))]
+    fn exceeding_default(
+        &self,
+        _ctx: QueryCtx,
+        _start_after: Option<u32>,
+        _limit: u32,
+    ) -> StdResult<Page<u32, u32>> {
+        Ok(Page::from_items(vec![], 0, |item| *item))
+    }
+
+    #[sv::msg(query, paginated(size = 10))]
+    fn unknown_limit(
+        &self,
+        _ctx: QueryCtx,
+        _start_after: Option<u32>,
+        _limit: u32,
+    ) -> StdResult<Page<u32, u32>> {
+        Ok(Page::from_items(vec![], 0, |item| *item))
+    }
+
+    #[sv::msg(query, paginated)]
+    fn missing_limit(&self, _ctx: QueryCtx, start_after: Option<u32>) -> StdResult<Page<u32, u32>> {
+        Ok(Page::from_items(vec![], 0, |item| *item))
+    }
+
+    #[sv::msg(query, paginated)]
+    fn invalid_params(
+        &self,
+        _ctx: QueryCtx,
+        start_after: u32,
+        limit: u64,
+    ) -> StdResult<Page<u32, u32>> {
+        Ok(Page::from_items(vec![], 0, |item| *item))
+    }
+}
+
+fn main() {}
@@ -0,0 +1,76 @@
+#![allow(unused_imports)]
+use cw_storage_plus::Item;
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, ReplyCtx};
+use sylvia::cw_std::{Addr, Binary, Response, StdResult, Uint128};
+use sylvia::types::Remote;
+
+pub mod token {
+    use sylvia::contract;
+    use sylvia::ctx::{ExecCtx, InstantiateCtx};
+    use sylvia::cw_std::{Response, StdResult, Uint128};
+
+    pub struct Token;
+
+    #[contract]
+    impl Token {
+        pub fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec, data = Uint128)]
+        fn mint(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+use token::sv::Executor;
+use sv::SubMsgMethods;
+
+pub struct Contract {
+    token: Item<Remote<'static, token::Token>>,
+}
+
+#[contract]
+#[sv::features(replies)]
+impl Contract {
+    pub fn new() -> Self {
+        Self {
+            token: Item::new("token"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn mint(&self, ctx: ExecCtx) -> StdResult<Response> {
+        let submsg = self
+            .token
+            .load(ctx.deps.storage)?
+            .executor()
+            .mint()?
+            .minted(Binary::default())?;
+        Ok(Response::new().add_submessage(submsg))
+    }
+
+    #[sv::msg(reply, reply_on=success)]
+    fn minted(
+        &self,
+        _ctx: ReplyCtx,
+        #[sv::data] _data: String,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
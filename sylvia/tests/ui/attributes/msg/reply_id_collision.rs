@@ -0,0 +1,51 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{InstantiateCtx, ReplyCtx};
+use sylvia::cw_std::{Binary, Response, StdResult, SubMsgResult};
+
+pub struct Contract;
+
+#[contract]
+#[sv::features(replies)]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(reply)]
+    fn first(
+        &self,
+        _ctx: ReplyCtx,
+        _result: SubMsgResult,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(reply, id = 0)]
+    fn second(
+        &self,
+        _ctx: ReplyCtx,
+        _result: SubMsgResult,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(reply, handlers=[third, fourth], id = 7)]
+    fn multiple(
+        &self,
+        _ctx: ReplyCtx,
+        _result: SubMsgResult,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
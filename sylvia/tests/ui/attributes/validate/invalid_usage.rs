@@ -0,0 +1,47 @@
+#![allow(unused_imports)]
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+pub struct Contract;
+
+#[sylvia::contract]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(
+        &self,
+        _ctx: InstantiateCtx,
+        #[sv::validate] _name: String,
+        #[sv::validate(min = 1, min = 2)] _decimals: u8,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::validate(min = 1)]
+    fn exec(
+        &self,
+        _ctx: ExecCtx,
+        #[sv::validate(chars(digits))] _symbol: String,
+        #[sv::validate(length(min = 1), non_empty)] _memo: String,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn query(
+        &self,
+        _ctx: QueryCtx,
+        #[sv::validate(between(1, 2))] _index: u32,
+        #[sv::validate(max = 2)]
+        #[sv::validate(min = 1)]
+        _limit: u32,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+#![allow(unused_imports)]
+use sylvia::ctx::InstantiateCtx;
+use sylvia::cw_std::{Response, StdError, StdResult};
+
+#[sylvia::error_codes]
+#[derive(thiserror::Error, Debug)]
+pub enum DuplicatedCode {
+    #[error("{0}")]
+    #[sv::code(1)]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    #[sv::code(1)]
+    Unauthorized,
+}
+
+#[sylvia::error_codes]
+#[derive(thiserror::Error, Debug)]
+pub enum InvalidCode {
+    #[error("Unauthorized")]
+    #[sv::code(-1)]
+    Unauthorized,
+
+    #[error("Frozen")]
+    #[sv::code(1)]
+    #[sv::code(2)]
+    Frozen,
+}
+
+#[sylvia::error_codes(codes)]
+#[derive(thiserror::Error, Debug)]
+pub enum UnexpectedArgs {
+    #[error("Unauthorized")]
+    Unauthorized,
+}
+
+pub struct Contract;
+
+#[sylvia::contract]
+#[sv::error(StdError, code)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    #[sv::code(1)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![allow(unused_imports)]
+use sylvia::cw_std::Uint128;
+
+#[sylvia::event(name = "wasm-transfer")]
+pub struct Transfer {
+    pub amount: Uint128,
+}
+
+#[sylvia::event(name = "x")]
+pub struct Short {
+    pub amount: Uint128,
+}
+
+#[sylvia::event]
+pub struct Reserved {
+    pub _contract_address: String,
+}
+
+#[sylvia::event]
+pub struct Tuple(pub Uint128);
+
+#[sylvia::event(ty = "burn")]
+pub struct Burn {
+    pub amount: Uint128,
+}
+
+fn main() {}
@@ -0,0 +1,189 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
+use cosmwasm_std::{Response, StdError, StdResult};
+use sylvia::ctx::{InstantiateCtx, MigrateCtx, QueryCtx};
+use sylvia::cw2::{get_contract_version, set_contract_version, ContractVersion};
+use sylvia::multitest::App;
+
+const CONTRACT_NAME: &str = "crates.io:sv-version";
+
+pub mod v1 {
+    use super::*;
+    use sylvia::contract;
+
+    pub struct Contract;
+
+    #[contract]
+    #[sv::version(name = crate::CONTRACT_NAME, version = "1.0.0")]
+    impl Contract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn noop(&self, _ctx: QueryCtx) -> StdResult<bool> {
+            Ok(true)
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+    use sylvia::contract;
+
+    pub struct Contract;
+
+    #[contract]
+    #[sv::version(name = crate::CONTRACT_NAME, version = "2.0.0")]
+    impl Contract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, ctx: MigrateCtx) -> StdResult<Response> {
+            // Version is already updated by the generated dispatch.
+            let version = get_contract_version(ctx.deps.storage)?;
+            Ok(Response::new().add_attribute("version", version.version))
+        }
+    }
+}
+
+pub mod foreign {
+    use super::*;
+    use sylvia::contract;
+
+    pub struct Contract;
+
+    #[contract]
+    #[sv::version(name = "crates.io:sv-foreign", version = env!("CARGO_PKG_VERSION"))]
+    impl Contract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn generated_consts() {
+    assert_eq!(v1::sv::CONTRACT_NAME, CONTRACT_NAME);
+    assert_eq!(v1::sv::CONTRACT_VERSION, "1.0.0");
+    assert_eq!(foreign::sv::CONTRACT_VERSION, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn instantiate_stores_version() {
+    let mut deps = mock_dependencies();
+    let sender = MockApi::default().addr_make("sender");
+
+    v1::sv::InstantiateMsg {}
+        .dispatch(
+            &v1::Contract::new(),
+            (deps.as_mut(), mock_env(), message_info(&sender, &[])),
+        )
+        .unwrap();
+
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap(),
+        ContractVersion {
+            contract: CONTRACT_NAME.to_owned(),
+            version: "1.0.0".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn migrate_requires_stored_version() {
+    let mut deps = mock_dependencies();
+
+    let err = v2::sv::MigrateMsg {}
+        .dispatch(&v2::Contract::new(), (deps.as_mut(), mock_env()))
+        .unwrap_err();
+    assert!(matches!(err, StdError::NotFound { .. }));
+
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "not-semver").unwrap();
+    let err = v2::sv::MigrateMsg {}
+        .dispatch(&v2::Contract::new(), (deps.as_mut(), mock_env()))
+        .unwrap_err();
+    assert!(err.to_string().contains("Semver: "));
+}
+
+#[test]
+fn guarded_migrations() {
+    use v1::sv::mt::ContractProxy;
+
+    let app = App::default();
+    let admin = MockApi::default().addr_make("admin");
+
+    let v1_code_id = v1::sv::mt::CodeId::store_code(&app);
+    let v2_code_id = v2::sv::mt::CodeId::store_code(&app);
+    let foreign_code_id = foreign::sv::mt::CodeId::store_code(&app);
+
+    let contract = v1_code_id
+        .instantiate()
+        .with_label("Version")
+        .with_admin(admin.as_str())
+        .call(&admin)
+        .unwrap();
+    contract.assert_contract_version(CONTRACT_NAME, "1.0.0");
+
+    // Upgrade
+    let resp = contract
+        .migrate()
+        .call(&admin, v2_code_id.code_id())
+        .unwrap();
+    assert!(resp.has_event(&cosmwasm_std::Event::new("wasm").add_attribute("version", "2.0.0")));
+    contract.assert_contract_version(CONTRACT_NAME, "2.0.0");
+
+    // Migration to the same version is allowed
+    contract
+        .migrate()
+        .call(&admin, v2_code_id.code_id())
+        .unwrap();
+    contract.assert_contract_version(CONTRACT_NAME, "2.0.0");
+
+    // Downgrade
+    let err = contract
+        .migrate()
+        .call(&admin, v1_code_id.code_id())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Cannot migrate from newer version (2.0.0) to older (1.0.0)")
+    );
+
+    // Different contract
+    let err = contract
+        .migrate()
+        .call(&admin, foreign_code_id.code_id())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Cannot migrate from crates.io:sv-version to crates.io:sv-foreign")
+    );
+    contract.assert_contract_version(CONTRACT_NAME, "2.0.0");
+}
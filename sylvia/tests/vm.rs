@@ -0,0 +1,126 @@
+#![cfg(feature = "vm")]
+
+#[path = "wasm/counter/src/contract.rs"]
+mod contract;
+
+mod tests {
+    use cw_multi_test::{BasicApp, IntoBech32};
+    use sylvia::multitest::App;
+
+    use crate::contract::sv::mt::{CodeId, CounterProxy};
+    use crate::contract::Counter;
+
+    /// Contract built by the `tests/wasm/build.sh`.
+    fn wasm() -> Vec<u8> {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/wasm/counter/artifacts/sylvia_counter.wasm"
+        );
+        std::fs::read(path).unwrap_or_else(|err| {
+            panic!("Failed to read {path}, build it with `tests/wasm/build.sh`: {err}")
+        })
+    }
+
+    fn counter_flow(code_id: &CodeId<'_, Counter, BasicApp>) {
+        let owner = "owner".into_bech32();
+        let other = "other".into_bech32();
+
+        let contract = code_id.instantiate(5).call(&owner).unwrap();
+        assert_eq!(contract.count().unwrap(), 5);
+
+        contract.increment().call(&owner).unwrap();
+        assert_eq!(contract.count().unwrap(), 6);
+
+        let err = contract.increment().call(&other).unwrap_err();
+        assert!(err.to_string().ends_with("Unauthorized"), "{err}");
+
+        contract
+            .add_admin("invalid".to_owned())
+            .call(&owner)
+            .unwrap_err();
+        contract.add_admin(other.to_string()).call(&owner).unwrap();
+        contract.increment().call(&other).unwrap();
+        assert_eq!(contract.count().unwrap(), 7);
+
+        let mut admins = vec![owner, other];
+        admins.sort();
+        assert_eq!(contract.admins().unwrap(), admins);
+
+        contract.set_count(u64::MAX).unwrap();
+        let err = contract.increment().call(&admins[0]).unwrap_err();
+        assert!(err.to_string().ends_with("Overflow"), "{err}");
+    }
+
+    #[test]
+    fn native() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+
+        counter_flow(&code_id);
+        assert!(code_id.gas_log().records().is_empty());
+    }
+
+    #[test]
+    #[ignore]
+    fn wasm_flow() {
+        let app = App::default();
+        let code_id = CodeId::store_wasm(&app, &wasm());
+
+        counter_flow(&code_id);
+
+        let records = code_id.gas_log().records();
+        let entry_points: Vec<_> = records.iter().map(|record| record.entry_point).collect();
+        assert_eq!(
+            entry_points,
+            [
+                "instantiate",
+                "query",
+                "execute",
+                "query",
+                "execute",
+                "execute",
+                "execute",
+                "execute",
+                "query",
+                "query",
+                "sudo",
+                "execute",
+            ]
+        );
+        assert!(records.iter().all(|record| record.gas_used > 0));
+
+        // Address validation is charged by the api
+        assert!(records[5].gas_used > records[2].gas_used);
+        assert_eq!(
+            code_id.gas_log().total(),
+            records.iter().map(|record| record.gas_used).sum::<u64>()
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn wasm_code_checksum() {
+        let app = App::default();
+        let code_id = CodeId::store_wasm(&app, &wasm());
+        let owner = "owner".into_bech32();
+
+        let contract = code_id.instantiate(0).call(&owner).unwrap();
+        let info = app
+            .app()
+            .wrap()
+            .query_wasm_contract_info(contract.contract_addr.as_str())
+            .unwrap();
+        assert_eq!(info.code_id, code_id.code_id());
+        assert_eq!(
+            code_id.gas_log().last().unwrap().contract,
+            contract.contract_addr
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to store the wasm code")]
+    fn invalid_wasm() {
+        let app = App::default();
+        CodeId::store_wasm(&app, b"not a wasm");
+    }
+}
@@ -0,0 +1,23 @@
+#!/bin/bash
+# Build the contracts executed through the `cosmwasm-vm` by the `vm` tests
+# with the standard optimizer image.
+# The compiled contracts are stored in the `artifacts` directory of every contract.
+# Intended usage: `./sylvia/tests/wasm/build.sh`, from any directory.
+
+set -e
+
+optimizer="cosmwasm/optimizer:0.16.1"
+root=$(cd "$(dirname "$0")/../../.." && pwd)
+
+for contract in "$root"/sylvia/tests/wasm/*/; do
+	contract=${contract%/}
+	echo "Building ${contract#"$root"/}"
+
+	# The whole repository is mounted, as the contracts depend on the local `sylvia`.
+	docker run --rm \
+		-v "$root":/code \
+		-w "/code/${contract#"$root"/}" \
+		--mount type=volume,source="sylvia_vm_$(basename "$contract")_cache",target=/target \
+		--mount type=volume,source=registry_cache,target=/usr/local/cargo/registry \
+		"$optimizer"
+done
@@ -0,0 +1,68 @@
+use cw_storage_plus::{Item, Map};
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx, SudoCtx};
+use sylvia::cw_std::{ensure, Addr, Order, Response, StdError, StdResult};
+use sylvia::{contract, entry_points};
+
+pub struct Counter {
+    count: Item<u64>,
+    admins: Map<Addr, u64>,
+}
+
+#[entry_points]
+#[contract]
+impl Counter {
+    pub const fn new() -> Self {
+        Self {
+            count: Item::new("count"),
+            admins: Map::new("admins"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, count: u64) -> StdResult<Response> {
+        self.count.save(ctx.deps.storage, &count)?;
+        self.admins
+            .save(ctx.deps.storage, ctx.info.sender, &ctx.env.block.height)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+        ensure!(
+            self.admins.has(ctx.deps.storage, ctx.info.sender),
+            StdError::generic_err("Unauthorized")
+        );
+        let count = self.count.update(ctx.deps.storage, |count| {
+            count
+                .checked_add(1)
+                .ok_or_else(|| StdError::generic_err("Overflow"))
+        })?;
+        Ok(Response::new().add_attribute("count", count.to_string()))
+    }
+
+    #[sv::msg(exec)]
+    fn add_admin(&self, ctx: ExecCtx, admin: String) -> StdResult<Response> {
+        let admin = ctx.deps.api.addr_validate(&admin)?;
+        self.admins
+            .save(ctx.deps.storage, admin, &ctx.env.block.height)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(sudo)]
+    fn set_count(&self, ctx: SudoCtx, count: u64) -> StdResult<Response> {
+        self.count.save(ctx.deps.storage, &count)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+        self.count.load(ctx.deps.storage)
+    }
+
+    #[sv::msg(query)]
+    fn admins(&self, ctx: QueryCtx) -> StdResult<Vec<Addr>> {
+        self.admins
+            .keys(ctx.deps.storage, None, None, Order::Ascending)
+            .collect()
+    }
+}